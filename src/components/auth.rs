@@ -0,0 +1,329 @@
+//! Aplicação dos esquemas de autenticação e cálculo do HTTP Digest (RFC 7616)
+
+use super::enums::{ApiKeyLocation, Auth, HttpRequest, KeyValue};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use md5::Md5;
+use sha2::{Digest as _, Sha256};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Máscara exibida no lugar de senhas e tokens
+const MASK: &str = "••••••";
+
+/// Valor do header `Authorization` para Basic Auth
+pub fn basic_authorization(username: &str, password: &str) -> String {
+    let credentials = format!("{}:{}", username, password);
+    format!("Basic {}", BASE64.encode(credentials.as_bytes()))
+}
+
+/// Retorna a requisição com a autenticação convertida em header ou query param.
+///
+/// Digest, AWS Signature e OAuth 2.0 não são aplicados aqui: ficam com o `HttpClient`.
+pub fn apply(request: &HttpRequest) -> HttpRequest {
+    let mut applied = request.clone();
+
+    match &request.auth {
+        Auth::None | Auth::Digest { .. } | Auth::AwsSigV4(_) | Auth::OAuth2(_) => {}
+        Auth::Basic { username, password } => set_header(
+            &mut applied,
+            "Authorization",
+            basic_authorization(username, password),
+        ),
+        Auth::Bearer { token } => {
+            set_header(&mut applied, "Authorization", format!("Bearer {}", token))
+        }
+        Auth::ApiKey {
+            name,
+            value,
+            location,
+        } if !name.trim().is_empty() => match location {
+            ApiKeyLocation::Header => set_header(&mut applied, name.trim(), value.clone()),
+            ApiKeyLocation::Query => {
+                applied
+                    .query_params
+                    .retain(|p| !(p.enabled && p.key == name.trim()));
+                applied
+                    .query_params
+                    .push(KeyValue::new(name.trim().to_string(), value.clone()));
+            }
+        },
+        Auth::ApiKey { .. } => {}
+    }
+
+    applied
+}
+
+/// O esquema da aba Auth substitui um header de mesmo nome digitado manualmente
+pub fn set_header(request: &mut HttpRequest, name: &str, value: String) {
+    request
+        .headers
+        .retain(|h| !(h.enabled && h.key.eq_ignore_ascii_case(name)));
+    request.headers.push(KeyValue::new(name.to_string(), value));
+}
+
+/// Resumo da autenticação com segredos mascarados, para o histórico
+pub fn masked_summary(auth: &Auth) -> Option<String> {
+    match auth {
+        Auth::None => None,
+        Auth::Basic { username, .. } => Some(format!("Basic {} {}", username, MASK)),
+        Auth::Digest { username, .. } => Some(format!("Digest {} {}", username, MASK)),
+        Auth::Bearer { .. } => Some(format!("Bearer {}", MASK)),
+        Auth::AwsSigV4(config) => Some(format!(
+            "AWS SigV4 {} ({}/{}) {}",
+            config.access_key, config.service, config.region, MASK
+        )),
+        Auth::OAuth2(config) => Some(format!("OAuth 2.0 ({}) {}", config.grant, config.client_id)),
+        Auth::ApiKey { name, location, .. } => {
+            Some(format!("API key ({}) {}: {}", location, name, MASK))
+        }
+    }
+}
+
+/// Desafio `WWW-Authenticate: Digest ...` enviado pelo servidor
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DigestChallenge {
+    pub realm: String,
+    pub nonce: String,
+    pub opaque: Option<String>,
+    pub algorithm: String,
+    /// `auth` quando o servidor oferece qop; `None` para o modo RFC 2069
+    pub qop: Option<String>,
+}
+
+impl DigestChallenge {
+    /// Interpreta o header `WWW-Authenticate`; retorna `None` se não for Digest
+    pub fn parse(header: &str) -> Option<Self> {
+        let (scheme, params) = header.trim().split_once(char::is_whitespace)?;
+        if !scheme.eq_ignore_ascii_case("digest") {
+            return None;
+        }
+
+        let params = parse_params(params);
+        let get = |key: &str| {
+            params
+                .iter()
+                .find(|(k, _)| k.eq_ignore_ascii_case(key))
+                .map(|(_, v)| v.clone())
+        };
+
+        let qop = get("qop").and_then(|qop| {
+            qop.split(',')
+                .map(str::trim)
+                .find(|q| q.eq_ignore_ascii_case("auth"))
+                .map(str::to_string)
+        });
+
+        Some(Self {
+            realm: get("realm").unwrap_or_default(),
+            nonce: get("nonce")?,
+            opaque: get("opaque"),
+            algorithm: get("algorithm").unwrap_or_else(|| "MD5".to_string()),
+            qop,
+        })
+    }
+
+    /// Monta o header `Authorization` de resposta ao desafio.
+    ///
+    /// # Errors
+    /// Retorna erro se o algoritmo não for suportado (MD5, SHA-256 e variantes `-sess`).
+    pub fn authorization(
+        &self,
+        username: &str,
+        password: &str,
+        method: &str,
+        uri: &str,
+        cnonce: &str,
+    ) -> Result<String, String> {
+        let algorithm = self.algorithm.to_ascii_uppercase();
+        let (base, session) = match algorithm.strip_suffix("-SESS") {
+            Some(base) => (base, true),
+            None => (algorithm.as_str(), false),
+        };
+
+        let hash: fn(&str) -> String = match base {
+            "MD5" => |data| hex(&Md5::digest(data.as_bytes())),
+            "SHA-256" => |data| hex(&Sha256::digest(data.as_bytes())),
+            other => return Err(format!("Unsupported Digest algorithm: {}", other)),
+        };
+
+        let nc = "00000001";
+        let mut ha1 = hash(&format!("{}:{}:{}", username, self.realm, password));
+        if session {
+            ha1 = hash(&format!("{}:{}:{}", ha1, self.nonce, cnonce));
+        }
+        let ha2 = hash(&format!("{}:{}", method, uri));
+
+        let response = match &self.qop {
+            Some(qop) => hash(&format!(
+                "{}:{}:{}:{}:{}:{}",
+                ha1, self.nonce, nc, cnonce, qop, ha2
+            )),
+            None => hash(&format!("{}:{}:{}", ha1, self.nonce, ha2)),
+        };
+
+        let mut header = format!(
+            r#"Digest username="{}", realm="{}", nonce="{}", uri="{}", algorithm={}, response="{}""#,
+            username, self.realm, self.nonce, uri, self.algorithm, response
+        );
+        if let Some(qop) = &self.qop {
+            header.push_str(&format!(r#", qop={}, nc={}, cnonce="{}""#, qop, nc, cnonce));
+        }
+        if let Some(opaque) = &self.opaque {
+            header.push_str(&format!(r#", opaque="{}""#, opaque));
+        }
+
+        Ok(header)
+    }
+}
+
+/// Gera um cnonce único por requisição
+pub fn new_cnonce() -> String {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    hex(&Md5::digest(nanos.to_string().as_bytes()))[..16].to_string()
+}
+
+/// Lista `chave=valor` separada por vírgulas, com valores opcionalmente entre aspas
+fn parse_params(input: &str) -> Vec<(String, String)> {
+    let mut params = Vec::new();
+    let mut rest = input.trim();
+
+    while let Some((key, after)) = rest.split_once('=') {
+        let key = key.trim().trim_start_matches(',').trim().to_string();
+        let after = after.trim_start();
+
+        let (value, remaining) = match after.strip_prefix('"') {
+            Some(quoted) => {
+                let end = quoted.find('"').unwrap_or(quoted.len());
+                (&quoted[..end], quoted.get(end + 1..).unwrap_or_default())
+            }
+            None => {
+                let end = after.find(',').unwrap_or(after.len());
+                (after[..end].trim(), &after[end..])
+            }
+        };
+
+        params.push((key, value.to_string()));
+        rest = remaining.trim_start().trim_start_matches(',').trim_start();
+    }
+
+    params
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_replaces_manual_header() {
+        let request = HttpRequest {
+            headers: vec![KeyValue::new("authorization".into(), "old".into())],
+            auth: Auth::Basic {
+                username: "user".into(),
+                password: "pass".into(),
+            },
+            ..HttpRequest::default()
+        };
+
+        let applied = apply(&request);
+        assert_eq!(
+            applied.headers,
+            vec![KeyValue::new(
+                "Authorization".into(),
+                "Basic dXNlcjpwYXNz".into()
+            )]
+        );
+        // A requisição editável continua sem o header gerado
+        assert_eq!(request.headers[0].value, "old");
+    }
+
+    #[test]
+    fn test_apply_api_key_in_query() {
+        let request = HttpRequest {
+            auth: Auth::ApiKey {
+                name: "api_key".into(),
+                value: "secret".into(),
+                location: ApiKeyLocation::Query,
+            },
+            ..HttpRequest::default()
+        };
+
+        let applied = apply(&request);
+        assert_eq!(
+            applied.query_params,
+            vec![KeyValue::new("api_key".into(), "secret".into())]
+        );
+        assert_eq!(
+            masked_summary(&request.auth).unwrap(),
+            "API key (Query Param) api_key: ••••••"
+        );
+    }
+
+    /// Exemplo da seção 3.5 da RFC 2617
+    #[test]
+    fn test_digest_rfc2617_example() {
+        let challenge = DigestChallenge::parse(
+            r#"Digest realm="testrealm@host.com", qop="auth,auth-int", nonce="dcd98b7102dd2f0e8b11d0f600bfb0c093", opaque="5ccc069c403ebaf9f0171e9517f40e41""#,
+        )
+        .unwrap();
+
+        let header = challenge
+            .authorization(
+                "Mufasa",
+                "Circle Of Life",
+                "GET",
+                "/dir/index.html",
+                "0a4f113b",
+            )
+            .unwrap();
+
+        assert!(header.contains(r#"response="6629fae49393a05397450978507c4ef1""#));
+        assert!(header.contains("qop=auth, nc=00000001"));
+        assert!(header.ends_with(r#"opaque="5ccc069c403ebaf9f0171e9517f40e41""#));
+    }
+
+    /// Exemplos da seção 3.9.1 da RFC 7616 (SHA-256 e MD5)
+    #[test]
+    fn test_digest_rfc7616_examples() {
+        for (algorithm, expected) in [
+            (
+                "SHA-256",
+                "753927fa0e85d155564e2e272a28d1802ca10daf4496794697cf8db5856cb6c1",
+            ),
+            ("MD5", "8ca523f5e9506fed4657c9700eebdbec"),
+        ] {
+            let challenge = DigestChallenge::parse(&format!(
+                r#"Digest realm="http-auth@example.org", qop="auth, auth-int", algorithm={}, nonce="7ypf/xlj9XXwfDPEoM4URrv/xwf94BcCAzFZH4GiTo0v", opaque="FQhe/qaU925kfnzjCev0ciny7QMkPqMAFRtzCUYo5tdS""#,
+                algorithm
+            ))
+            .unwrap();
+
+            let header = challenge
+                .authorization(
+                    "Mufasa",
+                    "Circle of Life",
+                    "GET",
+                    "/dir/index.html",
+                    "f2/wE4q74E6zIJEtWaHKaf5wv/H5QzzpXusqGemxURZJ",
+                )
+                .unwrap();
+
+            assert!(
+                header.contains(&format!(r#"response="{}""#, expected)),
+                "{}",
+                header
+            );
+        }
+    }
+
+    #[test]
+    fn test_non_digest_challenge() {
+        assert!(DigestChallenge::parse(r#"Basic realm="api""#).is_none());
+    }
+}
@@ -0,0 +1,255 @@
+use super::enums::HttpRequest;
+use super::storage;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+const COLLECTIONS_FILE_NAME: &str = "collections.json";
+
+/// Caminho de um nó na árvore: índices a partir da raiz
+pub type NodePath = Vec<usize>;
+
+/// Nó da árvore de coleções: pasta ou requisição salva
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum CollectionNode {
+    Folder {
+        name: String,
+        #[serde(default)]
+        children: Vec<CollectionNode>,
+        #[serde(default = "default_expanded")]
+        expanded: bool,
+    },
+    Request {
+        name: String,
+        request: Box<HttpRequest>,
+    },
+}
+
+fn default_expanded() -> bool {
+    true
+}
+
+impl CollectionNode {
+    pub fn folder(name: String) -> Self {
+        CollectionNode::Folder {
+            name,
+            children: Vec::new(),
+            expanded: true,
+        }
+    }
+
+    pub fn request(name: String, request: HttpRequest) -> Self {
+        CollectionNode::Request {
+            name,
+            request: Box::new(request),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            CollectionNode::Folder { name, .. } | CollectionNode::Request { name, .. } => name,
+        }
+    }
+
+    pub fn is_folder(&self) -> bool {
+        matches!(self, CollectionNode::Folder { .. })
+    }
+
+    fn set_name(&mut self, new_name: String) {
+        match self {
+            CollectionNode::Folder { name, .. } | CollectionNode::Request { name, .. } => {
+                *name = new_name
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RequestCollections {
+    items: Vec<CollectionNode>,
+    file_path: PathBuf,
+}
+
+impl RequestCollections {
+    pub fn new() -> Self {
+        let file_path = storage::config_file_path(COLLECTIONS_FILE_NAME);
+        let items = storage::load_json(&file_path, "collections").unwrap_or_default();
+
+        Self { items, file_path }
+    }
+
+    fn save_to_file(&self) {
+        if let Err(e) = storage::save_json(&self.file_path, &self.items, "collections") {
+            eprintln!("Warning: Failed to save collections: {}", e);
+        }
+    }
+
+    pub fn get_items(&self) -> &[CollectionNode] {
+        &self.items
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn get(&self, path: &[usize]) -> Option<&CollectionNode> {
+        let (first, rest) = path.split_first()?;
+        rest.iter()
+            .try_fold(self.items.get(*first)?, |node, &index| match node {
+                CollectionNode::Folder { children, .. } => children.get(index),
+                CollectionNode::Request { .. } => None,
+            })
+    }
+
+    fn get_mut(&mut self, path: &[usize]) -> Option<&mut CollectionNode> {
+        let (first, rest) = path.split_first()?;
+        rest.iter()
+            .try_fold(self.items.get_mut(*first)?, |node, &index| match node {
+                CollectionNode::Folder { children, .. } => children.get_mut(index),
+                CollectionNode::Request { .. } => None,
+            })
+    }
+
+    /// Lista de filhos de uma pasta (ou da raiz, para caminho vazio)
+    fn children_mut(&mut self, folder: &[usize]) -> Option<&mut Vec<CollectionNode>> {
+        if folder.is_empty() {
+            return Some(&mut self.items);
+        }
+
+        match self.get_mut(folder)? {
+            CollectionNode::Folder { children, .. } => Some(children),
+            CollectionNode::Request { .. } => None,
+        }
+    }
+
+    /// Adiciona um nó ao final de uma pasta e retorna seu caminho
+    pub fn insert(&mut self, folder: &[usize], node: CollectionNode) -> Option<NodePath> {
+        let children = self.children_mut(folder)?;
+        children.push(node);
+
+        let mut path = folder.to_vec();
+        path.push(children.len() - 1);
+        self.save_to_file();
+        Some(path)
+    }
+
+    /// Substitui a requisição salva em `path` (ação "Save")
+    pub fn update_request(&mut self, path: &[usize], new_request: HttpRequest) -> bool {
+        match self.get_mut(path) {
+            Some(CollectionNode::Request { request, .. }) => {
+                **request = new_request;
+                self.save_to_file();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn rename(&mut self, path: &[usize], name: String) {
+        if let Some(node) = self.get_mut(path) {
+            node.set_name(name);
+            self.save_to_file();
+        }
+    }
+
+    pub fn toggle_expanded(&mut self, path: &[usize]) {
+        if let Some(CollectionNode::Folder { expanded, .. }) = self.get_mut(path) {
+            *expanded = !*expanded;
+            self.save_to_file();
+        }
+    }
+
+    /// Duplica o nó logo abaixo do original e retorna o caminho da cópia
+    pub fn duplicate(&mut self, path: &[usize]) -> Option<NodePath> {
+        let (&index, parent) = path.split_last()?;
+        let mut copy = self.get(path)?.clone();
+        copy.set_name(format!("{} (copy)", copy.name()));
+
+        self.children_mut(parent)?.insert(index + 1, copy);
+        self.save_to_file();
+
+        let mut copy_path = parent.to_vec();
+        copy_path.push(index + 1);
+        Some(copy_path)
+    }
+
+    pub fn remove(&mut self, path: &[usize]) {
+        let Some((&index, parent)) = path.split_last() else {
+            return;
+        };
+
+        if let Some(children) = self.children_mut(parent)
+            && index < children.len()
+        {
+            children.remove(index);
+            self.save_to_file();
+        }
+    }
+}
+
+impl Default for RequestCollections {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Pasta onde "Save as" grava: a própria pasta selecionada ou a pasta da requisição selecionada
+pub fn target_folder(collections: &RequestCollections, selection: Option<&[usize]>) -> NodePath {
+    match selection {
+        Some(path) => match collections.get(path) {
+            Some(node) if node.is_folder() => path.to_vec(),
+            Some(_) => path[..path.len() - 1].to_vec(),
+            None => Vec::new(),
+        },
+        None => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collections() -> RequestCollections {
+        RequestCollections {
+            items: Vec::new(),
+            file_path: std::env::temp_dir().join("http-client-collections-test.json"),
+        }
+    }
+
+    #[test]
+    fn test_nested_folders() {
+        let mut collections = collections();
+        let folder = collections
+            .insert(&[], CollectionNode::folder("API".to_string()))
+            .unwrap();
+        let request = collections
+            .insert(
+                &folder,
+                CollectionNode::request("List users".to_string(), HttpRequest::default()),
+            )
+            .unwrap();
+
+        assert_eq!(request, vec![0, 0]);
+        assert_eq!(collections.get(&request).unwrap().name(), "List users");
+        assert_eq!(target_folder(&collections, Some(&request)), vec![0]);
+    }
+
+    #[test]
+    fn test_duplicate_and_remove() {
+        let mut collections = collections();
+        let first = collections
+            .insert(
+                &[],
+                CollectionNode::request("Login".to_string(), HttpRequest::default()),
+            )
+            .unwrap();
+
+        let copy = collections.duplicate(&first).unwrap();
+        assert_eq!(copy, vec![1]);
+        assert_eq!(collections.get(&copy).unwrap().name(), "Login (copy)");
+
+        collections.remove(&first);
+        assert_eq!(collections.get_items().len(), 1);
+        assert_eq!(collections.get(&[0]).unwrap().name(), "Login (copy)");
+    }
+}
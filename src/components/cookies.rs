@@ -0,0 +1,749 @@
+//! Cookie jar (RFC 6265): `Set-Cookie` recebidos, regras de domínio, caminho,
+//! expiração e `Secure` no envio, persistidos em `cookies.json`
+
+use super::storage;
+use chrono::{DateTime, Local, NaiveDateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use url::Url;
+
+const COOKIES_FILE_NAME: &str = "cookies.json";
+
+/// Formatos de data aceitos no atributo `Expires` além do RFC 1123
+const EXPIRES_FORMATS: [&str; 3] = [
+    "%a, %d-%b-%Y %H:%M:%S GMT",
+    "%A, %d-%b-%y %H:%M:%S GMT",
+    "%a %b %e %H:%M:%S %Y",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cookie {
+    pub name: String,
+    pub value: String,
+    /// Em minúsculas e sem o ponto inicial do atributo `Domain`
+    pub domain: String,
+    /// Sem atributo `Domain`: só o host que definiu o cookie o recebe
+    #[serde(default)]
+    pub host_only: bool,
+    pub path: String,
+    /// Timestamp Unix da expiração; `None` para cookies de sessão
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires: Option<i64>,
+    #[serde(default)]
+    pub secure: bool,
+    #[serde(default)]
+    pub http_only: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub same_site: Option<String>,
+    /// Momento da criação em microssegundos, usado para ordenar o header `Cookie`
+    #[serde(default)]
+    pub created: i64,
+}
+
+impl Cookie {
+    pub fn key(&self) -> CookieKey {
+        CookieKey {
+            domain: self.domain.clone(),
+            path: self.path.clone(),
+            name: self.name.clone(),
+        }
+    }
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires.is_some_and(|expires| expires <= now)
+    }
+
+    /// Validade exibida no gerenciador e na aba Cookies
+    pub fn expiry_label(&self, now: i64) -> String {
+        match self.expires {
+            None => "session".to_string(),
+            Some(_) if self.is_expired(now) => "expired".to_string(),
+            Some(expires) => Local
+                .timestamp_opt(expires, 0)
+                .single()
+                .map(|time| format!("expires {}", time.format("%Y-%m-%d %H:%M")))
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Regras de envio (RFC 6265, seção 5.4)
+    fn matches(&self, url: &Url, now: i64) -> bool {
+        let Some(host) = url.host_str() else {
+            return false;
+        };
+
+        let domain_ok = if self.host_only {
+            host == self.domain
+        } else {
+            domain_matches(host, &self.domain)
+        };
+
+        domain_ok
+            && path_matches(url.path(), &self.path)
+            && (!self.secure || matches!(url.scheme(), "https" | "wss"))
+            && !self.is_expired(now)
+    }
+}
+
+/// Identifica um cookie no jar: um novo `Set-Cookie` com a mesma chave substitui o anterior
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CookieKey {
+    pub domain: String,
+    pub path: String,
+    pub name: String,
+}
+
+/// `Set-Cookie` recebido em uma resposta, exibido na aba Cookies
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReceivedCookie {
+    /// Valor do header como recebido
+    pub header: String,
+    /// Cookie guardado, ou removido do jar quando já chega expirado
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cookie: Option<Cookie>,
+    /// Motivo da recusa
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Campos do formulário de novo cookie no gerenciador
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CookieField {
+    Domain,
+    Name,
+    Value,
+}
+
+/// Cookie sendo adicionado manualmente
+#[derive(Debug, Clone, Default)]
+pub struct CookieDraft {
+    pub domain: String,
+    pub name: String,
+    pub value: String,
+}
+
+impl CookieDraft {
+    pub fn set(&mut self, field: CookieField, value: String) {
+        match field {
+            CookieField::Domain => self.domain = value,
+            CookieField::Name => self.name = value,
+            CookieField::Value => self.value = value,
+        }
+    }
+
+    /// Cookie de sessão para o domínio e subdomínios, no caminho `/`
+    pub fn build(&self) -> Result<Cookie, String> {
+        let domain = self
+            .domain
+            .trim()
+            .trim_start_matches('.')
+            .to_ascii_lowercase();
+        if domain.is_empty() {
+            return Err("Cookie domain cannot be empty".to_string());
+        }
+        if self.name.trim().is_empty() {
+            return Err("Cookie name cannot be empty".to_string());
+        }
+
+        Ok(Cookie {
+            name: self.name.trim().to_string(),
+            value: self.value.clone(),
+            domain,
+            host_only: false,
+            path: "/".to_string(),
+            expires: None,
+            secure: false,
+            http_only: false,
+            same_site: None,
+            created: Utc::now().timestamp_micros(),
+        })
+    }
+}
+
+pub fn now() -> i64 {
+    Utc::now().timestamp()
+}
+
+/// Interpreta um `Set-Cookie` recebido de `url` (RFC 6265, seções 5.2 e 5.3)
+pub fn parse_set_cookie(header: &str, url: &Url, now: i64) -> Result<Cookie, String> {
+    let host = url
+        .host_str()
+        .ok_or_else(|| "The URL has no host".to_string())?;
+    let (pair, attributes) = header.split_once(';').unwrap_or((header, ""));
+    let (name, value) = pair
+        .split_once('=')
+        .ok_or_else(|| "Missing '=' in the cookie".to_string())?;
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Cookie name is empty".to_string());
+    }
+
+    let mut cookie = Cookie {
+        name: name.to_string(),
+        value: value.trim().to_string(),
+        domain: host.to_string(),
+        host_only: true,
+        path: default_path(url.path()),
+        expires: None,
+        secure: false,
+        http_only: false,
+        same_site: None,
+        created: Utc::now().timestamp_micros(),
+    };
+
+    let mut max_age = None;
+    let mut domain = None;
+    for attribute in attributes.split(';') {
+        let (key, value) = attribute.split_once('=').unwrap_or((attribute, ""));
+        let value = value.trim();
+
+        match key.trim().to_ascii_lowercase().as_str() {
+            "expires" => {
+                if let Some(expires) = parse_expires(value) {
+                    cookie.expires = Some(expires);
+                }
+            }
+            "max-age" => {
+                if let Ok(seconds) = value.parse::<i64>() {
+                    // Max-Age tem precedência sobre Expires; zero ou negativo remove o cookie
+                    max_age = Some(if seconds <= 0 {
+                        i64::MIN
+                    } else {
+                        now.saturating_add(seconds)
+                    });
+                }
+            }
+            "domain" if !value.is_empty() => {
+                domain = Some(value.trim_start_matches('.').to_ascii_lowercase());
+            }
+            "path" if value.starts_with('/') => cookie.path = value.to_string(),
+            "secure" => cookie.secure = true,
+            "httponly" => cookie.http_only = true,
+            "samesite" if !value.is_empty() => cookie.same_site = Some(value.to_string()),
+            _ => {}
+        }
+    }
+    if max_age.is_some() {
+        cookie.expires = max_age;
+    }
+
+    if let Some(domain) = domain {
+        if !domain_matches(host, &domain) {
+            return Err(format!(
+                "Domain {} does not match the host {}",
+                domain, host
+            ));
+        }
+        // Sem a lista de sufixos públicos: recusa ao menos domínios de um só rótulo
+        if !domain.contains('.') && domain != host {
+            return Err(format!("Domain {} is too broad", domain));
+        }
+        cookie.host_only = false;
+        cookie.domain = domain;
+    }
+
+    Ok(cookie)
+}
+
+fn parse_expires(value: &str) -> Option<i64> {
+    DateTime::parse_from_rfc2822(value)
+        .map(|date| date.timestamp())
+        .ok()
+        .or_else(|| {
+            EXPIRES_FORMATS.iter().find_map(|format| {
+                NaiveDateTime::parse_from_str(value, format)
+                    .ok()
+                    .map(|date| date.and_utc().timestamp())
+            })
+        })
+}
+
+/// `host` é o domínio ou um subdomínio dele; IPs só casam com eles mesmos
+fn domain_matches(host: &str, domain: &str) -> bool {
+    host == domain
+        || (host.ends_with(domain)
+            && host[..host.len() - domain.len()].ends_with('.')
+            && host.parse::<IpAddr>().is_err())
+}
+
+fn path_matches(request_path: &str, cookie_path: &str) -> bool {
+    request_path == cookie_path
+        || (request_path.starts_with(cookie_path)
+            && (cookie_path.ends_with('/') || request_path[cookie_path.len()..].starts_with('/')))
+}
+
+/// Diretório da URL: caminho até a última `/`, sem ela
+fn default_path(path: &str) -> String {
+    match path.rfind('/') {
+        Some(0) | None => "/".to_string(),
+        Some(index) => path[..index].to_string(),
+    }
+}
+
+/// Jar compartilhado entre as tarefas de envio e a UI, persistido em `cookies.json`.
+/// Cookies de sessão (sem `Expires`/`Max-Age`) ficam só em memória e somem ao fechar o app.
+#[derive(Debug, Clone)]
+pub struct CookieJar {
+    cookies: Arc<Mutex<Vec<Cookie>>>,
+    file_path: Option<PathBuf>,
+    /// Versão da última alteração; gravações atrasadas não sobrescrevem uma mais nova
+    version: Arc<AtomicU64>,
+    written: Arc<Mutex<u64>>,
+}
+
+impl CookieJar {
+    pub fn new() -> Self {
+        Self::load(storage::config_file_path(COOKIES_FILE_NAME))
+    }
+
+    fn load(file_path: PathBuf) -> Self {
+        let mut cookies: Vec<Cookie> =
+            storage::load_json(&file_path, "cookies").unwrap_or_default();
+        // Cookies de sessão não sobrevivem a um reinício
+        cookies.retain(|cookie| cookie.expires.is_some() && !cookie.is_expired(now()));
+
+        Self {
+            cookies: Arc::new(Mutex::new(cookies)),
+            file_path: Some(file_path),
+            version: Arc::default(),
+            written: Arc::default(),
+        }
+    }
+
+    /// Jar sem arquivo, para testes
+    #[cfg(test)]
+    pub fn in_memory() -> Self {
+        Self {
+            cookies: Arc::default(),
+            file_path: None,
+            version: Arc::default(),
+            written: Arc::default(),
+        }
+    }
+
+    /// Valor do header `Cookie` para a URL: caminhos mais longos primeiro, depois
+    /// os mais antigos
+    pub fn header_for(&self, url: &str) -> Option<String> {
+        let url = Url::parse(url).ok()?;
+        let now = now();
+        let cookies = self.lock();
+
+        let mut matching: Vec<&Cookie> = cookies
+            .iter()
+            .filter(|cookie| cookie.matches(&url, now))
+            .collect();
+        if matching.is_empty() {
+            return None;
+        }
+        matching.sort_by(|a, b| {
+            b.path
+                .len()
+                .cmp(&a.path.len())
+                .then(a.created.cmp(&b.created))
+        });
+
+        Some(
+            matching
+                .iter()
+                .map(|cookie| format!("{}={}", cookie.name, cookie.value))
+                .collect::<Vec<_>>()
+                .join("; "),
+        )
+    }
+
+    /// Guarda os `Set-Cookie` de uma resposta de `url`
+    pub fn store<'a>(
+        &self,
+        url: &str,
+        headers: impl IntoIterator<Item = &'a str>,
+    ) -> Vec<ReceivedCookie> {
+        let Ok(url) = Url::parse(url) else {
+            return Vec::new();
+        };
+        let now = now();
+        let mut cookies = self.lock();
+
+        let received: Vec<ReceivedCookie> = headers
+            .into_iter()
+            .map(|header| match parse_set_cookie(header, &url, now) {
+                Ok(mut cookie) => {
+                    let key = cookie.key();
+                    if let Some(index) = cookies.iter().position(|c| c.key() == key) {
+                        cookie.created = cookies.remove(index).created;
+                    }
+                    if !cookie.is_expired(now) {
+                        cookies.push(cookie.clone());
+                    }
+                    ReceivedCookie {
+                        header: header.to_string(),
+                        cookie: Some(cookie),
+                        error: None,
+                    }
+                }
+                Err(error) => ReceivedCookie {
+                    header: header.to_string(),
+                    cookie: None,
+                    error: Some(error),
+                },
+            })
+            .collect();
+
+        if received.iter().any(|cookie| cookie.cookie.is_some()) {
+            cookies.retain(|cookie| !cookie.is_expired(now));
+            self.save(cookies);
+        }
+        received
+    }
+
+    /// Cookies válidos ordenados por domínio, caminho e nome
+    pub fn entries(&self) -> Vec<Cookie> {
+        let now = now();
+        let mut cookies: Vec<Cookie> = self
+            .lock()
+            .iter()
+            .filter(|cookie| !cookie.is_expired(now))
+            .cloned()
+            .collect();
+        cookies.sort_by(|a, b| (&a.domain, &a.path, &a.name).cmp(&(&b.domain, &b.path, &b.name)));
+        cookies
+    }
+
+    /// Adiciona ou substitui um cookie com a mesma chave
+    pub fn insert(&self, cookie: Cookie) {
+        let mut cookies = self.lock();
+        let key = cookie.key();
+        cookies.retain(|c| c.key() != key);
+        cookies.push(cookie);
+        self.save(cookies);
+    }
+
+    pub fn set_value(&self, key: &CookieKey, value: String) {
+        let mut cookies = self.lock();
+        if let Some(cookie) = cookies.iter_mut().find(|c| c.key() == *key) {
+            cookie.value = value;
+            self.save(cookies);
+        }
+    }
+
+    pub fn remove(&self, key: &CookieKey) {
+        let mut cookies = self.lock();
+        cookies.retain(|c| c.key() != *key);
+        self.save(cookies);
+    }
+
+    pub fn clear_domain(&self, domain: &str) {
+        let mut cookies = self.lock();
+        cookies.retain(|c| c.domain != domain);
+        self.save(cookies);
+    }
+
+    pub fn clear(&self) {
+        let mut cookies = self.lock();
+        cookies.clear();
+        self.save(cookies);
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<Cookie>> {
+        self.cookies.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Grava os cookies persistentes fora do lock e, dentro do runtime, fora do executor
+    fn save(&self, cookies: MutexGuard<'_, Vec<Cookie>>) {
+        let Some(path) = self.file_path.clone() else {
+            return;
+        };
+        let persistent: Vec<Cookie> = cookies
+            .iter()
+            .filter(|cookie| cookie.expires.is_some())
+            .cloned()
+            .collect();
+        let version = self.version.fetch_add(1, Ordering::SeqCst) + 1;
+        drop(cookies);
+
+        let written = self.written.clone();
+        let write = move || {
+            let mut last = written.lock().unwrap_or_else(|e| e.into_inner());
+            if *last > version {
+                return;
+            }
+            *last = version;
+            if let Err(e) = storage::save_json(&path, &persistent, "cookies") {
+                eprintln!("Warning: Failed to save cookies: {}", e);
+            }
+        };
+
+        match tokio::runtime::Handle::try_current() {
+            Ok(runtime) => drop(runtime.spawn_blocking(write)),
+            Err(_) => write(),
+        }
+    }
+}
+
+impl Default for CookieJar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url(url: &str) -> Url {
+        Url::parse(url).unwrap()
+    }
+
+    #[test]
+    fn test_parses_set_cookie_attributes() {
+        let now = 1_700_000_000;
+        let cookie = parse_set_cookie(
+            "sid=abc; Domain=.Example.com; Path=/api; Expires=Wed, 21 Oct 2015 07:28:00 GMT; Max-Age=60; Secure; HttpOnly; SameSite=Lax",
+            &url("https://www.example.com/login"),
+            now,
+        )
+        .unwrap();
+
+        assert_eq!(cookie.domain, "example.com");
+        assert!(!cookie.host_only);
+        assert_eq!(cookie.path, "/api");
+        // Max-Age vence o Expires mesmo vindo depois
+        assert_eq!(cookie.expires, Some(now + 60));
+        assert!(cookie.secure && cookie.http_only);
+        assert_eq!(cookie.same_site.as_deref(), Some("Lax"));
+
+        let cookie = parse_set_cookie(
+            "theme=dark; expires=Sat, 06-Nov-2094 08:49:37 GMT",
+            &url("http://example.com/app/settings"),
+            now,
+        )
+        .unwrap();
+        assert!(cookie.host_only);
+        assert_eq!(cookie.domain, "example.com");
+        assert_eq!(cookie.path, "/app");
+        assert_eq!(cookie.expires, Some(3939871777));
+
+        let page = url("https://www.example.com/");
+        assert!(parse_set_cookie("a=1; Domain=other.com", &page, now).is_err());
+        assert!(parse_set_cookie("a=1; Domain=com", &page, now).is_err());
+        assert!(parse_set_cookie("no-value", &page, now).is_err());
+    }
+
+    #[test]
+    fn test_jar_sends_matching_cookies() {
+        let jar = CookieJar::in_memory();
+        let received = jar.store(
+            "https://example.com/account/login",
+            [
+                "sid=1; Path=/",
+                "pref=a; Path=/account",
+                "wide=w; Domain=example.com; Path=/; Secure",
+                "bad; Path=/",
+            ],
+        );
+        assert_eq!(received.iter().filter(|c| c.error.is_some()).count(), 1);
+
+        // Caminho mais longo primeiro; host-only não vale para subdomínios
+        assert_eq!(
+            jar.header_for("https://example.com/account/profile")
+                .as_deref(),
+            Some("pref=a; sid=1; wide=w")
+        );
+        assert_eq!(
+            jar.header_for("https://api.example.com/").as_deref(),
+            Some("wide=w")
+        );
+        assert_eq!(
+            jar.header_for("http://example.com/").as_deref(),
+            Some("sid=1")
+        );
+        assert_eq!(jar.header_for("https://example.org/"), None);
+
+        // Mesmo nome, domínio e caminho substitui; Max-Age=0 remove
+        jar.store(
+            "https://example.com/",
+            ["sid=2; Path=/", "pref=x; Path=/account; Max-Age=0"],
+        );
+        assert_eq!(
+            jar.header_for("http://example.com/account/").as_deref(),
+            Some("sid=2")
+        );
+
+        jar.clear_domain("example.com");
+        assert!(jar.entries().is_empty());
+    }
+
+    #[test]
+    fn test_host_only_and_domain_cookies() {
+        let jar = CookieJar::in_memory();
+        jar.store(
+            "https://example.com/",
+            ["host=1", "shared=2; Domain=example.com"],
+        );
+        // Definido por um subdomínio, vale para o domínio pai indicado
+        jar.store("https://api.example.com/", ["parent=3; Domain=example.com"]);
+
+        assert_eq!(
+            jar.header_for("https://example.com/").as_deref(),
+            Some("host=1; shared=2; parent=3")
+        );
+        assert_eq!(
+            jar.header_for("https://www.example.com/").as_deref(),
+            Some("shared=2; parent=3")
+        );
+        assert_eq!(jar.header_for("https://notexample.com/"), None);
+    }
+
+    #[test]
+    fn test_rejects_domain_not_matching_host() {
+        let now = 1_700_000_000;
+        let page = url("https://www.example.com/");
+
+        for header in [
+            "a=1; Domain=example.org",
+            "a=1; Domain=ample.com",
+            "a=1; Domain=api.www.example.com",
+        ] {
+            assert!(parse_set_cookie(header, &page, now).is_err(), "{}", header);
+        }
+        // IPs só aceitam o próprio endereço
+        let ip = url("http://192.168.0.10/");
+        assert!(parse_set_cookie("a=1; Domain=0.10", &ip, now).is_err());
+        assert!(parse_set_cookie("a=1; Domain=192.168.0.10", &ip, now).is_ok());
+
+        let jar = CookieJar::in_memory();
+        let received = jar.store("https://www.example.com/", ["a=1; Domain=example.org"]);
+        assert!(received[0].error.is_some());
+        assert!(jar.entries().is_empty());
+    }
+
+    #[test]
+    fn test_path_matching_and_default_path() {
+        assert!(path_matches("/foo", "/foo"));
+        assert!(path_matches("/foo/bar", "/foo"));
+        assert!(path_matches("/foo/bar", "/foo/"));
+        assert!(!path_matches("/foobar", "/foo"));
+        assert!(!path_matches("/", "/foo"));
+
+        assert_eq!(default_path(""), "/");
+        assert_eq!(default_path("/"), "/");
+        assert_eq!(default_path("/login"), "/");
+        assert_eq!(default_path("/account/login"), "/account");
+        assert_eq!(default_path("/account/"), "/account");
+
+        let jar = CookieJar::in_memory();
+        jar.store("https://example.com/foo/login", ["a=1", "b=2; Path=/foo"]);
+        assert_eq!(
+            jar.header_for("https://example.com/foo/x").as_deref(),
+            Some("a=1; b=2")
+        );
+        assert_eq!(jar.header_for("https://example.com/foobar"), None);
+    }
+
+    #[test]
+    fn test_max_age_wins_over_expires() {
+        let now = 1_700_000_000;
+        let page = url("https://example.com/");
+        let past = "Wed, 21 Oct 2015 07:28:00 GMT";
+        let future = "Sat, 06 Nov 2094 08:49:37 GMT";
+
+        // A ordem dos atributos não importa
+        let cookie = parse_set_cookie(&format!("a=1; Max-Age=60; Expires={}", past), &page, now);
+        assert_eq!(cookie.unwrap().expires, Some(now + 60));
+        let cookie = parse_set_cookie(&format!("a=1; Expires={}; Max-Age=0", future), &page, now);
+        assert!(cookie.unwrap().is_expired(now));
+        let cookie = parse_set_cookie("a=1; Max-Age=-5", &page, now);
+        assert!(cookie.unwrap().is_expired(now));
+        // Max-Age inválido é ignorado
+        let cookie = parse_set_cookie(
+            &format!("a=1; Max-Age=soon; Expires={}", future),
+            &page,
+            now,
+        );
+        assert_eq!(cookie.unwrap().expires, Some(3939871777));
+
+        let jar = CookieJar::in_memory();
+        jar.store("https://example.com/", ["a=1", "b=2"]);
+        let received = jar.store(
+            "https://example.com/",
+            [format!("a=1; Max-Age=0; Expires={}", future).as_str()],
+        );
+        assert!(received[0].cookie.is_some() && received[0].error.is_none());
+        assert_eq!(
+            jar.header_for("https://example.com/").as_deref(),
+            Some("b=2")
+        );
+    }
+
+    #[test]
+    fn test_secure_cookies_only_over_https() {
+        let jar = CookieJar::in_memory();
+        jar.store("https://example.com/", ["s=1; Secure", "p=2"]);
+
+        assert_eq!(
+            jar.header_for("http://example.com/").as_deref(),
+            Some("p=2")
+        );
+        assert_eq!(
+            jar.header_for("https://example.com/").as_deref(),
+            Some("s=1; p=2")
+        );
+        assert_eq!(
+            jar.header_for("wss://example.com/").as_deref(),
+            Some("s=1; p=2")
+        );
+        assert_eq!(jar.header_for("ws://example.com/").as_deref(), Some("p=2"));
+    }
+
+    #[test]
+    fn test_replaces_cookie_with_same_name_domain_and_path() {
+        let jar = CookieJar::in_memory();
+        jar.store("https://example.com/", ["a=1; Path=/", "b=1; Path=/"]);
+        let created = jar.entries()[0].created;
+
+        jar.store(
+            "https://example.com/",
+            ["a=2; Path=/", "a=3; Path=/docs", "a=4; Domain=other.com"],
+        );
+
+        let entries = jar.entries();
+        let values: Vec<(&str, &str, &str)> = entries
+            .iter()
+            .map(|c| (c.name.as_str(), c.path.as_str(), c.value.as_str()))
+            .collect();
+        assert_eq!(
+            values,
+            [("a", "/", "2"), ("b", "/", "1"), ("a", "/docs", "3")]
+        );
+        // O substituto mantém a posição do original no header `Cookie`
+        assert_eq!(entries[0].created, created);
+        assert_eq!(
+            jar.header_for("https://example.com/").as_deref(),
+            Some("a=2; b=1")
+        );
+    }
+
+    #[test]
+    fn test_session_cookies_are_not_persisted() {
+        let path = std::env::temp_dir().join(format!("cookies-{}.json", std::process::id()));
+        let jar = CookieJar::load(path.clone());
+        jar.store(
+            "https://example.com/",
+            ["session=1", "kept=2; Max-Age=3600"],
+        );
+        assert_eq!(
+            jar.header_for("https://example.com/").as_deref(),
+            Some("session=1; kept=2")
+        );
+
+        // Sem runtime a gravação é síncrona
+        let reloaded = CookieJar::load(path.clone());
+        assert_eq!(
+            reloaded.header_for("https://example.com/").as_deref(),
+            Some("kept=2")
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+}
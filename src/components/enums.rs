@@ -1,18 +1,43 @@
+use super::cookies::{CookieField, CookieKey, ReceivedCookie};
+use super::formats::codegen::CodeTarget;
+use super::formats::har::HarImport;
+use super::formats::http_file::OpenedHttpFile;
+use super::formats::multipart;
+use super::formats::openapi::OpenApiImport;
+use super::formats::postman::PostmanImport;
+use super::graphql::{self, GraphQlSchema};
+use super::http_client::SpillFile;
+use super::markup::Markup;
+use super::sse::{EventStream, SseUpdate};
+use super::utils::{json_formatter, mime, yaml_formatter};
+use super::websocket::{WsEvent, WsFormat, WsMessage};
+use bytes::Bytes;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
 
 /// Timeout padrão para requisições HTTP em milissegundos
 pub const DEFAULT_TIMEOUT_MS: u64 = 30000;
 
+/// Limite padrão do body da resposta mantido em memória, em MB
+pub const DEFAULT_RESPONSE_LIMIT_MB: u64 = 10;
+
+/// Máximo padrão de redirects seguidos por requisição
+pub const DEFAULT_MAX_REDIRECTS: u32 = 10;
+
 #[derive(Debug, Clone)]
 pub enum Message {
     HTTPSelected(HTTPMethod),
+    MethodInputChanged(String),
+    MethodInputClosed,
     UrlChanged(String),
     HeaderKeyChanged(usize, String),
     HeaderValueChanged(usize, String),
     HeaderEnabledToggled(usize),
     AddHeader,
     RemoveHeader(usize),
+    /// Copia o Content-Type implícito para uma linha editável da aba Headers
+    OverrideContentType,
     QueryParamKeyChanged(usize, String),
     QueryParamValueChanged(usize, String),
     QueryParamEnabledToggled(usize),
@@ -22,18 +47,121 @@ pub enum Message {
     BodyChanged(String),
     BodyEditorAction(iced::widget::text_editor::Action),
     BodyTypeChanged(BodyType),
+    FormatBody,
+    MinifyBody,
+    GraphQlQueryAction(iced::widget::text_editor::Action),
+    GraphQlVariablesAction(iced::widget::text_editor::Action),
+    GraphQlOperationSelected(String),
+    GraphQlSchemaFilterChanged(String),
+    FetchGraphQlSchema,
+    /// URL do endpoint e resultado da introspecção
+    GraphQlSchemaFetched(String, Result<GraphQlSchema, String>),
+    FormPartKeyChanged(usize, String),
+    FormPartValueChanged(usize, String),
+    FormPartKindSelected(usize, FormPartKind),
+    FormPartContentTypeChanged(usize, String),
+    FormPartFileNameChanged(usize, String),
+    FormPartToggled(usize),
+    AddFormPart,
+    RemoveFormPart(usize),
+    PickFormPartFile(usize),
+    FormPartFilePicked(usize, Option<String>),
+    UrlEncodedFieldKeyChanged(usize, String),
+    UrlEncodedFieldValueChanged(usize, String),
+    UrlEncodedFieldEnabledToggled(usize),
+    AddUrlEncodedField,
+    RemoveUrlEncodedField(usize),
+    BinaryFileChanged(String),
+    PickBinaryFile,
+    BinaryFilePicked(Option<String>),
+    ForceBodyToggled(bool),
     TimeoutChanged(String),
+    ResponseLimitChanged(String),
+    FollowRedirectsToggled(bool),
+    MaxRedirectsChanged(String),
+    KeepRedirectMethodToggled(bool),
+    StripRedirectAuthToggled(bool),
+    SendCookiesToggled(bool),
+    CookieValueChanged(CookieKey, String),
+    DeleteCookie(CookieKey),
+    ClearCookieDomain(String),
+    ClearCookies,
+    CookieDraftChanged(CookieField, String),
+    AddCookie,
+    ImportCurl,
+    DismissCurlImport,
+    ToggleCodePanel,
+    CodeTargetSelected(CodeTarget),
+    CopyCode,
     Submit,
-    #[allow(dead_code)]
     CancelRequest,
-    RequestCompleted(Result<HttpResponse, String>),
+    ResponseProgress(u64, DownloadProgress),
+    SseUpdate(u64, SseUpdate),
+    ToggleSsePause,
+    StopSse,
+    ReconnectSse,
+    WebSocketEvent(u64, WsEvent),
+    WsFormatSelected(WsFormat),
+    WsMessageAction(iced::widget::text_editor::Action),
+    SendWs,
+    SendWsPing,
+    CloseWebSocket,
+    RequestCompleted(u64, Result<HttpResponse, String>),
+    EnvironmentSelected(String),
+    ToggleEnvironmentEditor,
+    AddEnvironment,
+    RemoveEnvironment,
+    EnvironmentNameChanged(String),
+    EnvironmentVarKeyChanged(usize, String),
+    EnvironmentVarValueChanged(usize, String),
+    EnvironmentVarToggled(usize),
+    AddEnvironmentVar,
+    RemoveEnvironmentVar(usize),
+    SidebarTabChanged(SidebarTab),
+    CollectionNameChanged(String),
+    SelectCollectionNode(Vec<usize>),
+    SaveRequest,
+    SaveRequestAs,
+    AddCollectionFolder,
+    RenameCollectionNode,
+    DuplicateCollectionNode,
+    DeleteCollectionNode,
+    ImportPostman,
+    PostmanImported(Result<Option<PostmanImport>, String>),
+    ImportOpenApi,
+    OpenApiImported(Result<Option<OpenApiImport>, String>),
+    ExportCollections,
+    ExportHistoryPostman,
+    ImportHar,
+    HarImported(Result<Option<HarImport>, String>),
+    ExportHistoryHar,
+    ExportFinished(Result<Option<String>, String>),
+    OpenHttpFile,
+    HttpFileOpened(Result<Option<OpenedHttpFile>, String>),
+    SelectHttpFileEntry(usize),
+    RunHttpFileEntry(usize),
+    SaveHttpFile,
+    AddToHttpFile,
+    CloseHttpFile,
+    HttpFileSaved(Result<String, String>),
     LoadFromHistory(usize),
+    HistorySelectionToggled(usize),
     ClearHistory,
+    AuthKindSelected(AuthKind),
+    AuthFieldChanged(AuthField, String),
+    ApiKeyLocationSelected(ApiKeyLocation),
+    OAuth2GrantSelected(OAuth2Grant),
+    FetchOAuth2Token,
+    OAuth2TokenFetched(Result<(), String>),
+    RemoveOAuth2Token(String),
+    ClearOAuth2Tokens,
     TabChanged(RequestTab),
     ResponseTabChanged(ResponseTab),
+    SaveResponse,
+    ResponseSaved(Result<Option<String>, String>),
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[allow(clippy::upper_case_acronyms)]
 pub enum HTTPMethod {
     #[default]
@@ -42,33 +170,67 @@ pub enum HTTPMethod {
     PUT,
     PATCH,
     DELETE,
+    HEAD,
+    OPTIONS,
+    TRACE,
+    CONNECT,
+    /// Verbo arbitrário (ex.: PROPFIND, MKCOL, PURGE)
+    Custom(String),
 }
 
 impl HTTPMethod {
-    pub fn as_reqwest(self) -> reqwest::Method {
-        match self {
+    pub fn as_reqwest(&self) -> Result<reqwest::Method, String> {
+        Ok(match self {
             HTTPMethod::GET => reqwest::Method::GET,
             HTTPMethod::POST => reqwest::Method::POST,
             HTTPMethod::PUT => reqwest::Method::PUT,
             HTTPMethod::PATCH => reqwest::Method::PATCH,
             HTTPMethod::DELETE => reqwest::Method::DELETE,
+            HTTPMethod::HEAD => reqwest::Method::HEAD,
+            HTTPMethod::OPTIONS => reqwest::Method::OPTIONS,
+            HTTPMethod::TRACE => reqwest::Method::TRACE,
+            HTTPMethod::CONNECT => reqwest::Method::CONNECT,
+            HTTPMethod::Custom(verb) => reqwest::Method::from_bytes(verb.as_bytes())
+                .map_err(|_| format!("Invalid HTTP method: {}", verb))?,
+        })
+    }
+
+    /// Converte o texto digitado no seletor em um método, aceitando verbos customizados
+    pub fn from_input(input: &str) -> Option<HTTPMethod> {
+        let verb = input.trim().to_ascii_uppercase();
+
+        if verb.is_empty() {
+            return None;
+        }
+
+        if let Some(method) = Self::all().into_iter().find(|m| m.to_string() == verb) {
+            return Some(method);
         }
+
+        reqwest::Method::from_bytes(verb.as_bytes())
+            .ok()
+            .map(|_| HTTPMethod::Custom(verb))
     }
 
     /// Retorna true se o método HTTP permite body na requisição
-    pub fn allows_body(self) -> bool {
-        !matches!(self, HTTPMethod::GET)
+    pub fn allows_body(&self) -> bool {
+        matches!(
+            self,
+            HTTPMethod::POST
+                | HTTPMethod::PUT
+                | HTTPMethod::PATCH
+                | HTTPMethod::DELETE
+                | HTTPMethod::Custom(_)
+        )
+    }
+
+    /// Retorna true se o usuário pode optar por enviar body mesmo assim
+    pub fn can_force_body(&self) -> bool {
+        matches!(self, HTTPMethod::GET | HTTPMethod::OPTIONS)
     }
 
-    #[allow(dead_code)]
     pub fn all() -> Vec<HTTPMethod> {
-        vec![
-            HTTPMethod::GET,
-            HTTPMethod::POST,
-            HTTPMethod::PUT,
-            HTTPMethod::PATCH,
-            HTTPMethod::DELETE,
-        ]
+        Self::ALL.to_vec()
     }
 }
 
@@ -101,21 +263,182 @@ impl KeyValue {
 pub enum BodyType {
     #[default]
     None,
+    /// Texto puro, sem validação
     Raw,
     Json,
+    Xml,
+    Yaml,
+    Html,
+    /// multipart/form-data montado a partir de `HttpRequest::form_data`
+    FormData,
+    /// application/x-www-form-urlencoded montado a partir de `HttpRequest::form_urlencoded`
+    FormUrlEncoded,
+    /// Conteúdo de um arquivo (`HttpRequest::binary_file`), lido no envio
+    Binary,
+    /// Query, variáveis e operação de `HttpRequest::graphql`
+    GraphQl,
 }
 
 impl std::fmt::Display for BodyType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             BodyType::None => write!(f, "None"),
-            BodyType::Raw => write!(f, "Raw"),
+            BodyType::Raw => write!(f, "Text"),
             BodyType::Json => write!(f, "JSON"),
+            BodyType::Xml => write!(f, "XML"),
+            BodyType::Yaml => write!(f, "YAML"),
+            BodyType::Html => write!(f, "HTML"),
+            BodyType::FormData => write!(f, "Form Data"),
+            BodyType::FormUrlEncoded => write!(f, "x-www-form-urlencoded"),
+            BodyType::Binary => write!(f, "Binary"),
+            BodyType::GraphQl => write!(f, "GraphQL"),
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl BodyType {
+    /// Retorna true para os tipos editados como texto no editor de body
+    pub fn is_text(self) -> bool {
+        matches!(
+            self,
+            BodyType::Raw | BodyType::Json | BodyType::Xml | BodyType::Yaml | BodyType::Html
+        )
+    }
+
+    /// Retorna true se o tipo tem validação e ações de formatar/minificar
+    pub fn can_format(self) -> bool {
+        self.is_text() && self != BodyType::Raw
+    }
+
+    /// Valida o body conforme o tipo; body vazio e tipos sem sintaxe própria são válidos
+    pub fn validate(self, body: &str) -> Result<(), String> {
+        if body.trim().is_empty() {
+            return Ok(());
+        }
+
+        match self {
+            BodyType::Json => json_formatter::validate(body),
+            BodyType::Xml => Markup::Xml.validate(body),
+            BodyType::Yaml => yaml_formatter::validate(body),
+            BodyType::Html => Markup::Html.validate(body),
+            _ => Ok(()),
+        }
+    }
+
+    /// Pretty print do body; tipos sem formatação retornam o body sem alterações
+    pub fn format(self, body: &str) -> Result<String, String> {
+        match self {
+            BodyType::Json => json_formatter::format(body),
+            BodyType::Xml => Markup::Xml.format(body),
+            BodyType::Yaml => yaml_formatter::format(body),
+            BodyType::Html => Markup::Html.format(body),
+            _ => Ok(body.to_string()),
+        }
+    }
+
+    /// Minifica o body; tipos sem formatação retornam o body sem alterações
+    pub fn minify(self, body: &str) -> Result<String, String> {
+        match self {
+            BodyType::Json => json_formatter::minify(body),
+            BodyType::Xml => Markup::Xml.minify(body),
+            BodyType::Yaml => yaml_formatter::minify(body),
+            BodyType::Html => Markup::Html.minify(body),
+            _ => Ok(body.to_string()),
+        }
+    }
+}
+
+/// Body de uma requisição GraphQL, serializado no envio
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GraphQlBody {
+    pub query: String,
+    /// Objeto JSON com as variáveis; vazio omite `variables`
+    pub variables: String,
+    /// Operação executada quando o documento tem mais de uma
+    pub operation_name: String,
+}
+
+/// Parte de um body multipart/form-data
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FormPart {
+    pub key: String,
+    /// Texto do campo ou caminho do arquivo, conforme `kind`
+    pub value: String,
+    pub kind: FormPartKind,
+    /// Content-Type da parte; vazio usa o padrão do tipo
+    pub content_type: String,
+    /// Nome enviado no lugar do nome real do arquivo
+    pub file_name: String,
+    pub enabled: bool,
+}
+
+impl FormPart {
+    pub fn text(key: String, value: String) -> Self {
+        Self {
+            key,
+            value,
+            ..Self::default()
+        }
+    }
+
+    pub fn file(key: String, path: String) -> Self {
+        Self {
+            key,
+            value: path,
+            kind: FormPartKind::File,
+            ..Self::default()
+        }
+    }
+
+    /// Nome de arquivo enviado: o informado ou o nome do arquivo no disco
+    pub fn upload_file_name(&self) -> String {
+        if !self.file_name.is_empty() {
+            return self.file_name.clone();
+        }
+        std::path::Path::new(&self.value)
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| self.value.clone())
+    }
+}
+
+impl Default for FormPart {
+    fn default() -> Self {
+        Self {
+            key: String::new(),
+            value: String::new(),
+            kind: FormPartKind::Text,
+            content_type: String::new(),
+            file_name: String::new(),
+            enabled: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FormPartKind {
+    #[default]
+    Text,
+    File,
+}
+
+impl FormPartKind {
+    pub const ALL: [FormPartKind; 2] = [FormPartKind::Text, FormPartKind::File];
+}
+
+impl std::fmt::Display for FormPartKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FormPartKind::Text => write!(f, "Text"),
+            FormPartKind::File => write!(f, "File"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct HttpRequest {
     pub method: HTTPMethod,
     pub url: String,
@@ -123,7 +446,124 @@ pub struct HttpRequest {
     pub query_params: Vec<KeyValue>,
     pub body: String,
     pub body_type: BodyType,
+    /// Partes do body quando `body_type` é `FormData`; arquivos ficam como caminhos
+    #[serde(default)]
+    pub form_data: Vec<FormPart>,
+    /// Pares do body quando `body_type` é `FormUrlEncoded`
+    #[serde(default)]
+    pub form_urlencoded: Vec<KeyValue>,
+    /// Caminho do arquivo enviado quando `body_type` é `Binary`
+    #[serde(default)]
+    pub binary_file: String,
+    /// Query e variáveis quando `body_type` é `GraphQl`
+    #[serde(default)]
+    pub graphql: GraphQlBody,
+    /// Envia o body mesmo em métodos que normalmente não o aceitam (GET, OPTIONS)
+    #[serde(default)]
+    pub force_body: bool,
     pub timeout_ms: u64,
+    /// Tamanho do body da resposta mantido em memória (MB); o restante vai para um
+    /// arquivo temporário
+    #[serde(default = "default_response_limit_mb")]
+    pub response_limit_mb: u64,
+    /// Autenticação aplicada no envio, fora da lista de headers editável
+    #[serde(default)]
+    pub auth: Auth,
+    #[serde(default)]
+    pub redirects: RedirectPolicy,
+    /// Envia os cookies do jar que valem para a URL
+    #[serde(default = "default_send_cookies")]
+    pub send_cookies: bool,
+}
+
+impl HttpRequest {
+    /// Retorna true se o body deve ser enviado para o método atual
+    pub fn sends_body(&self) -> bool {
+        self.method.allows_body() || (self.force_body && self.method.can_force_body())
+    }
+
+    /// Retorna true se há um body não vazio que será enviado
+    pub fn has_body(&self) -> bool {
+        self.sends_body()
+            && match self.body_type {
+                BodyType::None => false,
+                BodyType::FormData => self.form_parts().next().is_some(),
+                BodyType::FormUrlEncoded => self.url_encoded_fields().next().is_some(),
+                BodyType::Binary => !self.binary_file.trim().is_empty(),
+                BodyType::GraphQl => !self.graphql.query.trim().is_empty(),
+                BodyType::Raw
+                | BodyType::Json
+                | BodyType::Xml
+                | BodyType::Yaml
+                | BodyType::Html => !self.body.is_empty(),
+            }
+    }
+
+    /// Pares habilitados e com nome do form urlencoded
+    pub fn url_encoded_fields(&self) -> impl Iterator<Item = &KeyValue> {
+        self.form_urlencoded
+            .iter()
+            .filter(|field| field.enabled && !field.key.is_empty())
+    }
+
+    /// Body textual enviado: o do editor, os pares urlencoded codificados
+    /// como os query params (`application/x-www-form-urlencoded`) ou o JSON do GraphQL
+    pub fn encoded_body(&self) -> String {
+        match self.body_type {
+            BodyType::FormUrlEncoded => url::form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(self.url_encoded_fields().map(|f| (&f.key, &f.value)))
+                .finish(),
+            BodyType::FormData | BodyType::Binary => String::new(),
+            BodyType::GraphQl => graphql::payload(&self.graphql)
+                .map(|payload| payload.to_string())
+                .unwrap_or_default(),
+            BodyType::None
+            | BodyType::Raw
+            | BodyType::Json
+            | BodyType::Xml
+            | BodyType::Yaml
+            | BodyType::Html => self.body.clone(),
+        }
+    }
+
+    /// Content-Type derivado do `body_type`, enviado quando o usuário não definiu
+    /// um header próprio (exibido esmaecido na aba Headers).
+    ///
+    /// O boundary do multipart é o estável da exportação; no envio o reqwest gera outro.
+    pub fn implicit_content_type(&self) -> Option<String> {
+        let has_header = self
+            .headers
+            .iter()
+            .any(|h| h.enabled && h.key.eq_ignore_ascii_case("content-type"));
+        if has_header || !self.has_body() {
+            return None;
+        }
+
+        let content_type = match self.body_type {
+            BodyType::None => return None,
+            BodyType::Json | BodyType::GraphQl => "application/json",
+            BodyType::Raw if self.body.trim_start().starts_with("<?xml") => "application/xml",
+            BodyType::Raw => "text/plain",
+            BodyType::Xml => "application/xml",
+            BodyType::Yaml => "application/yaml",
+            BodyType::Html => "text/html",
+            BodyType::FormUrlEncoded => "application/x-www-form-urlencoded",
+            BodyType::FormData => {
+                return Some(multipart::content_type(&multipart::boundary(
+                    self.form_parts(),
+                )));
+            }
+            BodyType::Binary => mime::from_path(&self.binary_file),
+        };
+        Some(content_type.to_string())
+    }
+
+    /// Partes habilitadas e com nome do form-data
+    pub fn form_parts(&self) -> impl Iterator<Item = &FormPart> {
+        self.form_data
+            .iter()
+            .filter(|part| part.enabled && !part.key.is_empty())
+    }
 }
 
 impl Default for HttpRequest {
@@ -131,25 +571,444 @@ impl Default for HttpRequest {
         Self {
             method: HTTPMethod::GET,
             url: String::new(),
-            headers: vec![KeyValue::new(
-                "Content-Type".to_string(),
-                "application/json".to_string(),
-            )],
+            headers: Vec::new(),
             query_params: Vec::new(),
             body: String::new(),
             body_type: BodyType::None,
+            form_data: Vec::new(),
+            form_urlencoded: Vec::new(),
+            binary_file: String::new(),
+            graphql: GraphQlBody::default(),
+            force_body: false,
             timeout_ms: DEFAULT_TIMEOUT_MS,
+            response_limit_mb: DEFAULT_RESPONSE_LIMIT_MB,
+            auth: Auth::None,
+            redirects: RedirectPolicy::default(),
+            send_cookies: true,
+        }
+    }
+}
+
+fn default_response_limit_mb() -> u64 {
+    DEFAULT_RESPONSE_LIMIT_MB
+}
+
+fn default_send_cookies() -> bool {
+    true
+}
+
+/// Como as respostas 3xx com `Location` são seguidas
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RedirectPolicy {
+    pub follow: bool,
+    pub max_hops: u32,
+    /// 307/308 reenviam o mesmo método e body; desligado, viram GET como no 303
+    pub keep_method: bool,
+    /// Remove `Authorization`, `Cookie` e a autenticação da aba Auth ao mudar de origem
+    pub strip_auth_cross_origin: bool,
+}
+
+impl Default for RedirectPolicy {
+    fn default() -> Self {
+        Self {
+            follow: true,
+            max_hops: DEFAULT_MAX_REDIRECTS,
+            keep_method: true,
+            strip_auth_cross_origin: true,
         }
     }
 }
 
+/// Esquema de autenticação da requisição
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Auth {
+    #[default]
+    None,
+    Basic {
+        username: String,
+        password: String,
+    },
+    Bearer {
+        token: String,
+    },
+    ApiKey {
+        name: String,
+        value: String,
+        location: ApiKeyLocation,
+    },
+    /// HTTP Digest: o `HttpClient` responde ao desafio do servidor (401)
+    Digest {
+        username: String,
+        password: String,
+    },
+    /// AWS Signature Version 4, calculada pelo `HttpClient` sobre a requisição final
+    AwsSigV4(Box<AwsSigV4Config>),
+    /// Token obtido e renovado pelo `HttpClient` (ver `oauth2.rs`)
+    #[serde(rename = "oauth2")]
+    OAuth2(Box<OAuth2Config>),
+}
+
+impl Auth {
+    pub fn kind(&self) -> AuthKind {
+        match self {
+            Auth::None => AuthKind::None,
+            Auth::Basic { .. } => AuthKind::Basic,
+            Auth::Bearer { .. } => AuthKind::Bearer,
+            Auth::ApiKey { .. } => AuthKind::ApiKey,
+            Auth::Digest { .. } => AuthKind::Digest,
+            Auth::AwsSigV4(_) => AuthKind::AwsSigV4,
+            Auth::OAuth2(_) => AuthKind::OAuth2,
+        }
+    }
+
+    /// Troca o esquema, reaproveitando usuário/senha entre Basic e Digest
+    pub fn with_kind(&self, kind: AuthKind) -> Auth {
+        let (username, password) = match self {
+            Auth::Basic { username, password } | Auth::Digest { username, password } => {
+                (username.clone(), password.clone())
+            }
+            _ => (String::new(), String::new()),
+        };
+
+        match kind {
+            AuthKind::None => Auth::None,
+            AuthKind::Basic => Auth::Basic { username, password },
+            AuthKind::Bearer => Auth::Bearer {
+                token: String::new(),
+            },
+            AuthKind::ApiKey => Auth::ApiKey {
+                name: "X-API-Key".to_string(),
+                value: String::new(),
+                location: ApiKeyLocation::Header,
+            },
+            AuthKind::Digest => Auth::Digest { username, password },
+            AuthKind::AwsSigV4 => Auth::AwsSigV4(Box::new(AwsSigV4Config {
+                region: "us-east-1".to_string(),
+                service: "execute-api".to_string(),
+                ..AwsSigV4Config::default()
+            })),
+            AuthKind::OAuth2 => Auth::OAuth2(Box::default()),
+        }
+    }
+
+    /// Altera um campo do esquema atual; campos de outros esquemas são ignorados
+    pub fn set_field(&mut self, field: AuthField, new_value: String) {
+        match (self, field) {
+            (Auth::Basic { username, .. } | Auth::Digest { username, .. }, AuthField::Username) => {
+                *username = new_value
+            }
+            (Auth::Basic { password, .. } | Auth::Digest { password, .. }, AuthField::Password) => {
+                *password = new_value
+            }
+            (Auth::Bearer { token }, AuthField::Token) => *token = new_value,
+            (Auth::ApiKey { name, .. }, AuthField::KeyName) => *name = new_value,
+            (Auth::ApiKey { value, .. }, AuthField::KeyValue) => *value = new_value,
+            (Auth::AwsSigV4(config), field) => {
+                let target = match field {
+                    AuthField::AccessKey => &mut config.access_key,
+                    AuthField::SecretKey => &mut config.secret_key,
+                    AuthField::SessionToken => &mut config.session_token,
+                    AuthField::Region => &mut config.region,
+                    AuthField::Service => &mut config.service,
+                    _ => return,
+                };
+                *target = new_value;
+            }
+            (Auth::OAuth2(config), field) => {
+                let target = match field {
+                    AuthField::Username => &mut config.username,
+                    AuthField::Password => &mut config.password,
+                    AuthField::TokenUrl => &mut config.token_url,
+                    AuthField::AuthUrl => &mut config.auth_url,
+                    AuthField::ClientId => &mut config.client_id,
+                    AuthField::ClientSecret => &mut config.client_secret,
+                    AuthField::Scope => &mut config.scope,
+                    AuthField::Audience => &mut config.audience,
+                    _ => return,
+                };
+                *target = new_value;
+            }
+            _ => {}
+        }
+    }
+
+    /// Campos de texto do esquema, para substituição de variáveis `{{nome}}`
+    pub fn fields_mut(&mut self) -> Vec<&mut String> {
+        match self {
+            Auth::None => Vec::new(),
+            Auth::Basic { username, password } | Auth::Digest { username, password } => {
+                vec![username, password]
+            }
+            Auth::Bearer { token } => vec![token],
+            Auth::ApiKey { name, value, .. } => vec![name, value],
+            Auth::AwsSigV4(config) => vec![
+                &mut config.access_key,
+                &mut config.secret_key,
+                &mut config.session_token,
+                &mut config.region,
+                &mut config.service,
+            ],
+            Auth::OAuth2(config) => vec![
+                &mut config.token_url,
+                &mut config.auth_url,
+                &mut config.client_id,
+                &mut config.client_secret,
+                &mut config.scope,
+                &mut config.audience,
+                &mut config.username,
+                &mut config.password,
+            ],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthKind {
+    None,
+    Basic,
+    Bearer,
+    ApiKey,
+    Digest,
+    AwsSigV4,
+    OAuth2,
+}
+
+impl AuthKind {
+    pub const ALL: [AuthKind; 7] = [
+        AuthKind::None,
+        AuthKind::Basic,
+        AuthKind::Bearer,
+        AuthKind::ApiKey,
+        AuthKind::Digest,
+        AuthKind::AwsSigV4,
+        AuthKind::OAuth2,
+    ];
+}
+
+impl std::fmt::Display for AuthKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuthKind::None => write!(f, "No Auth"),
+            AuthKind::Basic => write!(f, "Basic Auth"),
+            AuthKind::Bearer => write!(f, "Bearer Token"),
+            AuthKind::ApiKey => write!(f, "API Key"),
+            AuthKind::Digest => write!(f, "Digest Auth"),
+            AuthKind::AwsSigV4 => write!(f, "AWS Signature"),
+            AuthKind::OAuth2 => write!(f, "OAuth 2.0"),
+        }
+    }
+}
+
+/// Credenciais e escopo da assinatura AWS SigV4
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AwsSigV4Config {
+    pub access_key: String,
+    pub secret_key: String,
+    /// Opcional: credenciais temporárias (STS)
+    pub session_token: String,
+    pub region: String,
+    pub service: String,
+}
+
+/// Configuração OAuth 2.0; campos sem uso no grant escolhido ficam vazios
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OAuth2Config {
+    pub grant: OAuth2Grant,
+    pub token_url: String,
+    /// Endpoint de autorização, usado só no fluxo authorization code
+    pub auth_url: String,
+    pub client_id: String,
+    pub client_secret: String,
+    pub scope: String,
+    pub audience: String,
+    /// Credenciais do usuário no grant password
+    pub username: String,
+    pub password: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OAuth2Grant {
+    #[default]
+    ClientCredentials,
+    Password,
+    /// Login no navegador com PKCE e redirect para um listener local
+    AuthorizationCode,
+}
+
+impl OAuth2Grant {
+    pub const ALL: [OAuth2Grant; 3] = [
+        OAuth2Grant::ClientCredentials,
+        OAuth2Grant::Password,
+        OAuth2Grant::AuthorizationCode,
+    ];
+}
+
+impl std::fmt::Display for OAuth2Grant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OAuth2Grant::ClientCredentials => write!(f, "Client Credentials"),
+            OAuth2Grant::Password => write!(f, "Password"),
+            OAuth2Grant::AuthorizationCode => write!(f, "Authorization Code (PKCE)"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ApiKeyLocation {
+    #[default]
+    Header,
+    Query,
+}
+
+impl ApiKeyLocation {
+    pub const ALL: [ApiKeyLocation; 2] = [ApiKeyLocation::Header, ApiKeyLocation::Query];
+}
+
+impl std::fmt::Display for ApiKeyLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApiKeyLocation::Header => write!(f, "Header"),
+            ApiKeyLocation::Query => write!(f, "Query Param"),
+        }
+    }
+}
+
+/// Campo editável do painel de autenticação
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthField {
+    Username,
+    Password,
+    Token,
+    KeyName,
+    KeyValue,
+    TokenUrl,
+    AuthUrl,
+    ClientId,
+    ClientSecret,
+    Scope,
+    Audience,
+    AccessKey,
+    SecretKey,
+    SessionToken,
+    Region,
+    Service,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HttpResponse {
     pub status: u16,
     pub status_text: String,
-    pub body: String,
+    /// Body decodificado como texto; `None` quando o conteúdo é binário
+    pub body: Option<String>,
+    /// Bytes recebidos, sem decodificação (não são gravados no histórico)
+    #[serde(skip)]
+    pub bytes: Bytes,
     pub headers: HashMap<String, String>,
     pub duration_ms: u128,
+    /// Tamanho total do body quando só o início coube no limite de memória
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub full_size: Option<u64>,
+    /// Arquivo temporário com o body completo de uma resposta truncada
+    #[serde(skip)]
+    pub spill_file: Option<Arc<SpillFile>>,
+    /// Conexão `text/event-stream` ainda aberta, lida pelo viewer de eventos
+    #[serde(skip)]
+    pub event_stream: Option<EventStream>,
+    /// Respostas 3xx seguidas até esta, na ordem
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub redirects: Vec<RedirectHop>,
+    /// `Set-Cookie` recebidos na resposta e nos redirects, com o resultado no jar
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cookies: Vec<ReceivedCookie>,
+}
+
+/// Salto de um redirect seguido: a requisição feita e a resposta 3xx recebida
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RedirectHop {
+    pub method: String,
+    pub url: String,
+    pub status: u16,
+    pub status_text: String,
+    /// `Location` resolvido contra `url`
+    pub location: String,
+    pub headers: HashMap<String, String>,
+    pub duration_ms: u128,
+}
+
+impl HttpResponse {
+    /// Monta a resposta decodificando o body como texto só quando o conteúdo é textual
+    pub fn new(
+        status: u16,
+        status_text: String,
+        headers: HashMap<String, String>,
+        bytes: Bytes,
+        duration_ms: u128,
+    ) -> Self {
+        let content_type = headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case("content-type"))
+            .map(|(_, value)| value.as_str());
+
+        Self {
+            status,
+            status_text,
+            body: mime::decode_text(content_type, &bytes),
+            bytes,
+            headers,
+            duration_ms,
+            full_size: None,
+            spill_file: None,
+            event_stream: None,
+            redirects: Vec::new(),
+            cookies: Vec::new(),
+        }
+    }
+
+    pub fn content_type(&self) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case("content-type"))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Texto do body, ou vazio quando o conteúdo é binário
+    pub fn text(&self) -> &str {
+        self.body.as_deref().unwrap_or_default()
+    }
+
+    /// Bytes do body; respostas do histórico só guardam o texto
+    pub fn raw_body(&self) -> &[u8] {
+        if self.bytes.is_empty() {
+            self.text().as_bytes()
+        } else {
+            &self.bytes
+        }
+    }
+
+    /// Imagem que pode ser exibida no visualizador (SVG é exibido como texto)
+    pub fn is_image(&self) -> bool {
+        self.body.is_none()
+            && !self.bytes.is_empty()
+            && self
+                .content_type()
+                .is_some_and(|value| mime::essence(value).starts_with("image/"))
+    }
+}
+
+/// Andamento do download do body, exibido enquanto a requisição está em andamento
+#[derive(Debug, Clone)]
+pub struct DownloadProgress {
+    pub received: u64,
+    /// `Content-Length` da resposta, quando informado
+    pub total: Option<u64>,
+    /// Início do body decodificado como texto; `None` para conteúdo binário
+    pub preview: Option<String>,
 }
 
 impl std::fmt::Display for HttpResponse {
@@ -157,27 +1016,143 @@ impl std::fmt::Display for HttpResponse {
         write!(
             f,
             "Status: {} {}\nTime: {}ms\n\nBody:\n{}",
-            self.status, self.status_text, self.duration_ms, self.body
+            self.status,
+            self.status_text,
+            self.duration_ms,
+            match &self.body {
+                Some(body) => body.clone(),
+                None => format!("<{} bytes of binary data>", self.raw_body().len()),
+            }
         )
     }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HistoryItem {
+    /// Requisição como editada, com as variáveis `{{nome}}` intactas
     pub request: HttpRequest,
-    pub response: HttpResponse,
+    /// Requisição efetivamente enviada, quando difere do template
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolved_request: Option<HttpRequest>,
+    /// `None` quando a requisição foi cancelada antes de receber resposta
+    pub response: Option<HttpResponse>,
     pub timestamp: i64,
+    /// Mensagens trocadas quando a requisição abriu uma sessão WebSocket
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub websocket: Option<Vec<WsMessage>>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RequestTab {
     QueryParams,
     Headers,
+    Auth,
     Body,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SidebarTab {
+    History,
+    Collections,
+    HttpFile,
+    Cookies,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ResponseTab {
     Body,
     Headers,
+    /// Eventos de uma resposta `text/event-stream`
+    Events,
+    /// Linha do tempo dos redirects seguidos
+    Redirects,
+    /// `Set-Cookie` recebidos
+    Cookies,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_method_from_input() {
+        assert_eq!(HTTPMethod::from_input("head"), Some(HTTPMethod::HEAD));
+        assert_eq!(
+            HTTPMethod::from_input(" propfind "),
+            Some(HTTPMethod::Custom("PROPFIND".to_string()))
+        );
+        assert_eq!(HTTPMethod::from_input(""), None);
+        assert_eq!(HTTPMethod::from_input("BAD VERB"), None);
+    }
+
+    #[test]
+    fn test_force_body() {
+        let mut request = HttpRequest::default();
+        assert!(!request.sends_body());
+
+        request.force_body = true;
+        assert!(request.sends_body());
+
+        request.method = HTTPMethod::HEAD;
+        assert!(!request.sends_body());
+
+        // DELETE sempre enviou body, inclusive em entradas antigas do histórico
+        request.method = HTTPMethod::DELETE;
+        request.force_body = false;
+        assert!(request.sends_body());
+    }
+
+    #[test]
+    fn test_implicit_content_type() {
+        let mut request = HttpRequest {
+            method: HTTPMethod::POST,
+            body: "{}".into(),
+            body_type: BodyType::Json,
+            ..HttpRequest::default()
+        };
+        assert_eq!(
+            request.implicit_content_type().as_deref(),
+            Some("application/json")
+        );
+
+        request.body_type = BodyType::Raw;
+        assert_eq!(
+            request.implicit_content_type().as_deref(),
+            Some("text/plain")
+        );
+
+        request.body_type = BodyType::Yaml;
+        assert_eq!(
+            request.implicit_content_type().as_deref(),
+            Some("application/yaml")
+        );
+
+        request.body_type = BodyType::Binary;
+        request.binary_file = "/tmp/photo.jpeg".into();
+        assert_eq!(
+            request.implicit_content_type().as_deref(),
+            Some("image/jpeg")
+        );
+
+        request.body_type = BodyType::FormData;
+        request.form_data = vec![FormPart::text("a".into(), "1".into())];
+        assert!(
+            request
+                .implicit_content_type()
+                .unwrap()
+                .starts_with("multipart/form-data; boundary=")
+        );
+
+        // Um header do usuário (habilitado) sempre vence
+        request
+            .headers
+            .push(KeyValue::new("content-type".into(), "text/csv".into()));
+        assert_eq!(request.implicit_content_type(), None);
+        request.headers[0].enabled = false;
+        assert!(request.implicit_content_type().is_some());
+
+        // Sem body enviado não há Content-Type
+        request.method = HTTPMethod::GET;
+        assert_eq!(request.implicit_content_type(), None);
+    }
 }
@@ -0,0 +1,246 @@
+use super::enums::{BodyType, HTTPMethod, HttpRequest, KeyValue};
+use super::storage;
+use super::utils::template;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+const ENVIRONMENTS_FILE_NAME: &str = "environments.json";
+
+/// Ambiente nomeado (dev, staging, prod...) com variáveis `{{nome}}`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Environment {
+    pub name: String,
+    pub variables: Vec<KeyValue>,
+}
+
+impl Environment {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            variables: Vec::new(),
+        }
+    }
+
+    fn variable_map(&self) -> HashMap<String, String> {
+        self.variables
+            .iter()
+            .filter(|v| v.enabled && !v.key.is_empty())
+            .map(|v| (v.key.trim().to_string(), v.value.clone()))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct EnvironmentFile {
+    environments: Vec<Environment>,
+    active: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct EnvironmentStore {
+    environments: Vec<Environment>,
+    active: Option<usize>,
+    file_path: PathBuf,
+}
+
+impl EnvironmentStore {
+    pub fn new() -> Self {
+        let file_path = storage::config_file_path(ENVIRONMENTS_FILE_NAME);
+        let file: EnvironmentFile =
+            storage::load_json(&file_path, "environments").unwrap_or_default();
+        let active = file.active.filter(|&i| i < file.environments.len());
+
+        Self {
+            environments: file.environments,
+            active,
+            file_path,
+        }
+    }
+
+    fn save_to_file(&self) {
+        let file = EnvironmentFile {
+            environments: self.environments.clone(),
+            active: self.active,
+        };
+
+        if let Err(e) = storage::save_json(&self.file_path, &file, "environments") {
+            eprintln!("Warning: Failed to save environments: {}", e);
+        }
+    }
+
+    pub fn get_items(&self) -> &[Environment] {
+        &self.environments
+    }
+
+    pub fn active(&self) -> Option<&Environment> {
+        self.active.and_then(|i| self.environments.get(i))
+    }
+
+    pub fn select(&mut self, index: Option<usize>) {
+        self.active = index.filter(|&i| i < self.environments.len());
+        self.save_to_file();
+    }
+
+    /// Cria um novo ambiente com nome único e o torna ativo
+    pub fn add(&mut self) {
+        let mut number = self.environments.len() + 1;
+        let name = loop {
+            let candidate = format!("Environment {}", number);
+            if !self.has_name(&candidate) {
+                break candidate;
+            }
+            number += 1;
+        };
+
+        self.insert(Environment::new(name));
+    }
+
+    /// Adiciona um ambiente pronto (ex.: importado), renomeando se o nome já existir,
+    /// e o torna ativo. Retorna o nome final.
+    pub fn insert(&mut self, mut environment: Environment) -> String {
+        if self.has_name(&environment.name) {
+            let base = environment.name.clone();
+            let mut number = 2;
+            while self.has_name(&format!("{} ({})", base, number)) {
+                number += 1;
+            }
+            environment.name = format!("{} ({})", base, number);
+        }
+
+        let name = environment.name.clone();
+        self.environments.push(environment);
+        self.active = Some(self.environments.len() - 1);
+        self.save_to_file();
+        name
+    }
+
+    fn has_name(&self, name: &str) -> bool {
+        self.environments.iter().any(|e| e.name == name)
+    }
+
+    /// Remove o ambiente ativo
+    pub fn remove_active(&mut self) {
+        if let Some(index) = self.active.take() {
+            self.environments.remove(index);
+            self.save_to_file();
+        }
+    }
+
+    /// Aplica uma alteração ao ambiente ativo e persiste o resultado
+    pub fn update_active(&mut self, update_fn: impl FnOnce(&mut Environment)) {
+        if let Some(env) = self.active.and_then(|i| self.environments.get_mut(i)) {
+            update_fn(env);
+            self.save_to_file();
+        }
+    }
+
+    /// Substitui as variáveis do ambiente ativo em URL, headers, query params e body.
+    /// As variáveis `extra` (ex.: `@var` de um arquivo `.http`) têm precedência sobre
+    /// o ambiente e podem referenciar suas variáveis.
+    ///
+    /// # Errors
+    /// Retorna a lista de variáveis não resolvidas nas partes que serão enviadas.
+    pub fn resolve(
+        &self,
+        request: &HttpRequest,
+        extra: &[KeyValue],
+    ) -> Result<HttpRequest, Vec<String>> {
+        let mut variables = self
+            .active()
+            .map(Environment::variable_map)
+            .unwrap_or_default();
+
+        for variable in extra.iter().filter(|v| v.enabled && !v.key.is_empty()) {
+            let (value, _) = template::substitute(&variable.value, &variables);
+            variables.insert(variable.key.trim().to_string(), value);
+        }
+
+        let mut unresolved = Vec::new();
+        let mut apply = |text: &str, counts: bool| {
+            let (value, missing) = template::substitute(text, &variables);
+            if counts {
+                for name in missing {
+                    if !unresolved.contains(&name) {
+                        unresolved.push(name);
+                    }
+                }
+            }
+            value
+        };
+
+        let mut resolved = request.clone();
+        resolved.url = apply(&request.url, true);
+        resolved.body = apply(&request.body, request.sends_body());
+
+        for item in resolved
+            .headers
+            .iter_mut()
+            .chain(resolved.query_params.iter_mut())
+        {
+            item.key = apply(&item.key, item.enabled);
+            item.value = apply(&item.value, item.enabled);
+        }
+
+        let sends_fields = request.body_type == BodyType::FormUrlEncoded && request.sends_body();
+        for field in resolved.form_urlencoded.iter_mut() {
+            field.key = apply(&field.key, sends_fields && field.enabled);
+            field.value = apply(&field.value, sends_fields && field.enabled);
+        }
+
+        let sends_form = request.body_type == BodyType::FormData && request.sends_body();
+        for part in resolved.form_data.iter_mut() {
+            let counts = sends_form && part.enabled;
+            for field in [
+                &mut part.key,
+                &mut part.value,
+                &mut part.content_type,
+                &mut part.file_name,
+            ] {
+                *field = apply(field, counts);
+            }
+        }
+
+        resolved.binary_file = apply(
+            &request.binary_file,
+            request.body_type == BodyType::Binary && request.sends_body(),
+        );
+
+        let sends_graphql = request.body_type == BodyType::GraphQl
+            && (request.sends_body() || request.method == HTTPMethod::GET);
+        resolved.graphql.query = apply(&request.graphql.query, sends_graphql);
+        resolved.graphql.variables = apply(&request.graphql.variables, sends_graphql);
+
+        for field in resolved.auth.fields_mut() {
+            *field = apply(field, true);
+        }
+
+        if unresolved.is_empty() {
+            Ok(resolved)
+        } else {
+            Err(unresolved)
+        }
+    }
+
+    /// Nomes das variáveis sem valor no ambiente ativo, para destacar na UI
+    pub fn unresolved(&self, request: &HttpRequest, extra: &[KeyValue]) -> Vec<String> {
+        self.resolve(request, extra).err().unwrap_or_default()
+    }
+}
+
+impl Default for EnvironmentStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Formata a mensagem de erro exibida quando há variáveis sem valor
+pub fn unresolved_error(names: &[String]) -> String {
+    let list = names
+        .iter()
+        .map(|n| format!("{{{{{}}}}}", n))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!("Unresolved variables: {}", list)
+}
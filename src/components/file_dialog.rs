@@ -0,0 +1,115 @@
+//! Diálogos nativos de abrir/salvar arquivo usados por importação e exportação
+
+use rfd::AsyncFileDialog;
+use std::path::PathBuf;
+
+/// Filtro de extensões exibido no diálogo: (descrição, extensões)
+pub type FileFilter = (&'static str, &'static [&'static str]);
+
+/// Arquivo escolhido pelo usuário e seu conteúdo
+#[derive(Debug, Clone)]
+pub struct PickedFile {
+    pub path: PathBuf,
+    pub name: String,
+    pub contents: Vec<u8>,
+}
+
+impl PickedFile {
+    pub fn text(&self) -> Result<String, String> {
+        String::from_utf8(self.contents.clone())
+            .map_err(|_| format!("{} is not a valid UTF-8 text file", self.name))
+    }
+}
+
+/// Abre o diálogo de seleção e lê o arquivo escolhido.
+/// Retorna `Ok(None)` se o usuário cancelar.
+pub async fn open_file(title: &str, filter: FileFilter) -> Result<Option<PickedFile>, String> {
+    let Some(handle) = AsyncFileDialog::new()
+        .set_title(title)
+        .add_filter(filter.0, filter.1)
+        .add_filter("All files", &["*"])
+        .pick_file()
+        .await
+    else {
+        return Ok(None);
+    };
+
+    let contents = tokio::fs::read(handle.path())
+        .await
+        .map_err(|e| format!("Failed to read {}: {}", handle.file_name(), e))?;
+
+    Ok(Some(PickedFile {
+        path: handle.path().to_path_buf(),
+        name: handle.file_name(),
+        contents,
+    }))
+}
+
+/// Escolhe um arquivo sem lê-lo (ex.: partes de um multipart, lidas só no envio).
+/// Retorna `None` se o usuário cancelar.
+pub async fn pick_path(title: &str) -> Option<PathBuf> {
+    AsyncFileDialog::new()
+        .set_title(title)
+        .pick_file()
+        .await
+        .map(|handle| handle.path().to_path_buf())
+}
+
+/// Abre o diálogo de salvar e grava o conteúdo no arquivo escolhido.
+/// Retorna o caminho gravado, ou `Ok(None)` se o usuário cancelar.
+pub async fn save_file(
+    title: &str,
+    default_name: &str,
+    filter: FileFilter,
+    contents: Vec<u8>,
+) -> Result<Option<String>, String> {
+    let Some(handle) = save_dialog(title, default_name, filter).await else {
+        return Ok(None);
+    };
+
+    tokio::fs::write(handle.path(), contents)
+        .await
+        .map_err(|e| format!("Failed to write {}: {}", handle.file_name(), e))?;
+
+    Ok(Some(handle.path().display().to_string()))
+}
+
+/// Como `save_file`, mas copia um arquivo existente (ex.: body grande em arquivo temporário)
+pub async fn save_copy(
+    title: &str,
+    default_name: &str,
+    filter: FileFilter,
+    source: PathBuf,
+) -> Result<Option<String>, String> {
+    let Some(handle) = save_dialog(title, default_name, filter).await else {
+        return Ok(None);
+    };
+
+    tokio::fs::copy(&source, handle.path())
+        .await
+        .map_err(|e| format!("Failed to write {}: {}", handle.file_name(), e))?;
+
+    Ok(Some(handle.path().display().to_string()))
+}
+
+async fn save_dialog(
+    title: &str,
+    default_name: &str,
+    filter: FileFilter,
+) -> Option<rfd::FileHandle> {
+    AsyncFileDialog::new()
+        .set_title(title)
+        .set_file_name(default_name)
+        .add_filter(filter.0, filter.1)
+        .save_file()
+        .await
+}
+
+/// Grava o conteúdo em um arquivo já escolhido (ex.: salvar de volta um arquivo aberto)
+pub async fn write_file(path: PathBuf, contents: Vec<u8>) -> Result<String, String> {
+    tokio::fs::write(&path, contents)
+        .await
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+
+    Ok(path.display().to_string())
+}
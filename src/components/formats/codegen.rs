@@ -0,0 +1,1023 @@
+//! Geração de código que reproduz uma requisição em outras ferramentas e linguagens
+
+use super::multipart;
+use crate::components::enums::{
+    BodyType, FormPart, FormPartKind, HTTPMethod, HttpRequest, KeyValue,
+};
+use crate::components::http_client::HttpClient;
+use crate::components::{auth, graphql};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CodeTarget {
+    #[default]
+    Curl,
+    Httpie,
+    Wget,
+    PythonRequests,
+    JavaScriptFetch,
+    NodeAxios,
+    GoNetHttp,
+    RustReqwest,
+    PowerShell,
+}
+
+impl CodeTarget {
+    pub const ALL: [CodeTarget; 9] = [
+        CodeTarget::Curl,
+        CodeTarget::Httpie,
+        CodeTarget::Wget,
+        CodeTarget::PythonRequests,
+        CodeTarget::JavaScriptFetch,
+        CodeTarget::NodeAxios,
+        CodeTarget::GoNetHttp,
+        CodeTarget::RustReqwest,
+        CodeTarget::PowerShell,
+    ];
+}
+
+impl std::fmt::Display for CodeTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                CodeTarget::Curl => "cURL",
+                CodeTarget::Httpie => "HTTPie",
+                CodeTarget::Wget => "wget",
+                CodeTarget::PythonRequests => "Python (requests)",
+                CodeTarget::JavaScriptFetch => "JavaScript (fetch)",
+                CodeTarget::NodeAxios => "Node.js (axios)",
+                CodeTarget::GoNetHttp => "Go (net/http)",
+                CodeTarget::RustReqwest => "Rust (reqwest)",
+                CodeTarget::PowerShell => "PowerShell",
+            }
+        )
+    }
+}
+
+/// Gera o snippet para o alvo escolhido.
+///
+/// # Errors
+/// Retorna erro se a URL da requisição for inválida.
+pub fn generate(target: CodeTarget, request: &HttpRequest) -> Result<String, String> {
+    let request = &auth::apply(&graphql::apply(request)?);
+    let url = HttpClient::full_url(request)?;
+    let form: Vec<&FormPart> = if request.body_type == BodyType::FormData {
+        request.form_parts().collect()
+    } else {
+        Vec::new()
+    };
+    let fields: Vec<&KeyValue> = if request.body_type == BodyType::FormUrlEncoded {
+        request.url_encoded_fields().collect()
+    } else {
+        Vec::new()
+    };
+    let implicit_content_type = request
+        .implicit_content_type()
+        .map(|value| KeyValue::new("Content-Type".to_string(), value.to_string()));
+
+    // O Content-Type do multipart é gerado junto com o boundary
+    let headers: Vec<&KeyValue> = request
+        .headers
+        .iter()
+        .filter(|h| h.enabled && !h.key.is_empty())
+        .chain(&implicit_content_type)
+        .filter(|h| form.is_empty() || !h.key.eq_ignore_ascii_case("content-type"))
+        .collect();
+    let encoded_body = request.encoded_body();
+    let body_file = (request.has_body() && request.body_type == BodyType::Binary)
+        .then(|| request.binary_file.trim());
+    let body = (request.has_body() && form.is_empty() && body_file.is_none())
+        .then_some(encoded_body.as_str());
+
+    let snippet = Snippet {
+        method: &request.method,
+        url: &url,
+        headers: &headers,
+        body,
+        body_file,
+        fields: &fields,
+        form: &form,
+        timeout_ms: request.timeout_ms,
+    };
+
+    Ok(match target {
+        CodeTarget::Curl => snippet.curl(),
+        CodeTarget::Httpie => snippet.httpie(),
+        CodeTarget::Wget => snippet.wget(),
+        CodeTarget::PythonRequests => snippet.python(),
+        CodeTarget::JavaScriptFetch => snippet.fetch(),
+        CodeTarget::NodeAxios => snippet.axios(),
+        CodeTarget::GoNetHttp => snippet.go(),
+        CodeTarget::RustReqwest => snippet.rust(),
+        CodeTarget::PowerShell => snippet.powershell(),
+    })
+}
+
+struct Snippet<'a> {
+    method: &'a HTTPMethod,
+    url: &'a str,
+    headers: &'a [&'a KeyValue],
+    body: Option<&'a str>,
+    /// Arquivo enviado como body (`BodyType::Binary`)
+    body_file: Option<&'a str>,
+    /// Pares do form urlencoded, já incluídos em `body`
+    fields: &'a [&'a KeyValue],
+    /// Partes do multipart/form-data; vazio quando o body não é form-data
+    form: &'a [&'a FormPart],
+    timeout_ms: u64,
+}
+
+impl Snippet<'_> {
+    fn curl(&self) -> String {
+        let mut parts = vec![match self.method {
+            HTTPMethod::HEAD => format!("curl --head {}", shell_quote(self.url)),
+            method => format!("curl -X {} {}", method, shell_quote(self.url)),
+        }];
+
+        for header in self.headers {
+            parts.push(format!(
+                "-H {}",
+                shell_quote(&format!("{}: {}", header.key, header.value))
+            ));
+        }
+
+        if !self.form.is_empty() {
+            let boundary = multipart::boundary(self.form.iter().copied());
+            parts.push(format!(
+                "-H {}",
+                shell_quote(&format!(
+                    "Content-Type: {}",
+                    multipart::content_type(&boundary)
+                ))
+            ));
+        }
+
+        for part in self.form {
+            parts.push(curl_form_part(part));
+        }
+
+        if !self.fields.is_empty() {
+            // O curl codifica só o valor; o nome vai já codificado
+            for field in self.fields {
+                let key: String =
+                    url::form_urlencoded::byte_serialize(field.key.as_bytes()).collect();
+                parts.push(format!(
+                    "--data-urlencode {}",
+                    shell_quote(&format!("{}={}", key, field.value))
+                ));
+            }
+        } else if let Some(body) = self.body {
+            parts.push(format!("--data-raw {}", shell_quote(body)));
+        } else if let Some(path) = self.body_file {
+            parts.push(format!(
+                "--data-binary {}",
+                shell_quote(&format!("@{}", path))
+            ));
+        }
+
+        parts.join(" \\\n  ")
+    }
+
+    fn httpie(&self) -> String {
+        let multipart = if !self.form.is_empty() {
+            " --multipart"
+        } else if !self.fields.is_empty() {
+            " --form"
+        } else {
+            ""
+        };
+        // O arquivo binário chega pelo stdin
+        let stdin = if self.body_file.is_some() {
+            ""
+        } else {
+            " --ignore-stdin"
+        };
+        let mut parts = vec![format!(
+            "http{}{} {} {}",
+            stdin,
+            multipart,
+            self.method,
+            shell_quote(self.url)
+        )];
+
+        for header in self.headers {
+            parts.push(shell_quote(&format!("{}:{}", header.key, header.value)));
+        }
+
+        for field in self.fields {
+            parts.push(shell_quote(&format!("{}={}", field.key, field.value)));
+        }
+
+        for part in self.form {
+            parts.push(shell_quote(&match part.kind {
+                FormPartKind::Text => format!("{}={}", part.key, part.value),
+                FormPartKind::File if part.content_type.is_empty() => {
+                    format!("{}@{}", part.key, part.value)
+                }
+                FormPartKind::File => {
+                    format!("{}@{};type={}", part.key, part.value, part.content_type)
+                }
+            }));
+        }
+
+        if let Some(body) = self.body.filter(|_| self.fields.is_empty()) {
+            parts.push(format!("--raw {}", shell_quote(body)));
+        }
+
+        if let Some(path) = self.body_file {
+            parts.push(format!("< {}", shell_quote(path)));
+        }
+
+        parts.join(" \\\n  ")
+    }
+
+    fn wget(&self) -> String {
+        let mut parts = vec![
+            "wget --quiet".to_string(),
+            format!("--method={}", self.method),
+        ];
+
+        for header in self.headers {
+            parts.push(format!(
+                "--header={}",
+                shell_quote(&format!("{}: {}", header.key, header.value))
+            ));
+        }
+
+        if let Some(body) = self.body {
+            parts.push(format!("--body-data={}", shell_quote(body)));
+        }
+
+        if let Some(path) = self.body_file {
+            parts.push(format!("--body-file={}", shell_quote(path)));
+        }
+
+        parts.push("--output-document -".to_string());
+        parts.push(shell_quote(self.url));
+
+        let command = parts.join(" \\\n  ");
+        if self.form.is_empty() {
+            command
+        } else {
+            format!(
+                "# Note: wget cannot send multipart/form-data; the form fields are omitted\n{}",
+                command
+            )
+        }
+    }
+
+    fn python(&self) -> String {
+        let mut code = String::from("import requests\n\n");
+        code.push_str(&format!("url = {}\n", json_quote(self.url)));
+
+        let mut args = vec![json_quote(&self.method.to_string()), "url".to_string()];
+
+        if !self.headers.is_empty() {
+            code.push_str("headers = {\n");
+            for header in self.headers {
+                code.push_str(&format!(
+                    "    {}: {},\n",
+                    json_quote(&header.key),
+                    json_quote(&header.value)
+                ));
+            }
+            code.push_str("}\n");
+            args.push("headers=headers".to_string());
+        }
+
+        if let Some(body) = self.body {
+            code.push_str(&format!("data = {}\n", json_quote(body)));
+            args.push("data=data.encode(\"utf-8\")".to_string());
+        }
+
+        if let Some(path) = self.body_file {
+            code.push_str(&format!("data = open({}, \"rb\")\n", json_quote(path)));
+            args.push("data=data".to_string());
+        }
+
+        if !self.form.is_empty() {
+            code.push_str("files = [\n");
+            for part in self.form {
+                let content_type = match part.content_type.as_str() {
+                    "" => String::new(),
+                    content_type => format!(", {}", json_quote(content_type)),
+                };
+                let field = match part.kind {
+                    FormPartKind::Text => {
+                        format!("(None, {}{})", json_quote(&part.value), content_type)
+                    }
+                    FormPartKind::File => format!(
+                        "({}, open({}, \"rb\"){})",
+                        json_quote(&part.upload_file_name()),
+                        json_quote(&part.value),
+                        content_type
+                    ),
+                };
+                code.push_str(&format!("    ({}, {}),\n", json_quote(&part.key), field));
+            }
+            code.push_str("]\n");
+            args.push("files=files".to_string());
+        }
+
+        args.push(format!("timeout={}", seconds(self.timeout_ms)));
+
+        code.push_str(&format!(
+            "\nresponse = requests.request({})\n\nprint(response.status_code)\nprint(response.text)\n",
+            args.join(", ")
+        ));
+        code
+    }
+
+    fn fetch(&self) -> String {
+        let mut options = vec![format!(
+            "  method: {},",
+            json_quote(&self.method.to_string())
+        )];
+
+        if !self.headers.is_empty() {
+            options.push(format!("  headers: {{\n{}\n  }},", self.js_headers("    ")));
+        }
+
+        if let Some(body) = self.body {
+            options.push(format!("  body: {},", json_quote(body)));
+        }
+
+        if let Some(path) = self.body_file {
+            options.push(format!("  body: fs.readFileSync({}),", json_quote(path)));
+        }
+
+        if !self.form.is_empty() {
+            options.push("  body: form,".to_string());
+        }
+
+        let mut code = String::new();
+        if self.reads_files() {
+            code.push_str("const fs = require(\"fs\");\n\n");
+        }
+        if !self.form.is_empty() {
+            code.push_str(&self.js_form_data());
+            code.push('\n');
+        }
+
+        code.push_str(&format!(
+            "fetch({}, {{\n{}\n}})\n  .then(async (response) => {{\n    console.log(response.status);\n    console.log(await response.text());\n  }})\n  .catch(console.error);\n",
+            json_quote(self.url),
+            options.join("\n")
+        ));
+        code
+    }
+
+    fn axios(&self) -> String {
+        let mut code = String::from("const axios = require(\"axios\");\n");
+        if self.reads_files() {
+            code.push_str("const fs = require(\"fs\");\n");
+        }
+
+        let mut options = vec![
+            format!(
+                "    method: {},",
+                json_quote(&self.method.to_string().to_ascii_lowercase())
+            ),
+            format!("    url: {},", json_quote(self.url)),
+        ];
+
+        if !self.headers.is_empty() {
+            options.push(format!(
+                "    headers: {{\n{}\n    }},",
+                self.js_headers("      ")
+            ));
+        }
+
+        if let Some(body) = self.body {
+            options.push(format!("    data: {},", json_quote(body)));
+        }
+
+        if let Some(path) = self.body_file {
+            options.push(format!("    data: fs.readFileSync({}),", json_quote(path)));
+        }
+
+        if !self.form.is_empty() {
+            options.push("    data: form,".to_string());
+        }
+
+        options.push(format!("    timeout: {},", self.timeout_ms));
+        options.push("    transformResponse: (data) => data,".to_string());
+
+        if !self.form.is_empty() {
+            code.push('\n');
+            code.push_str(&self.js_form_data());
+        }
+
+        code.push_str(&format!(
+            "\naxios\n  .request({{\n{}\n  }})\n  .then((response) => {{\n    console.log(response.status);\n    console.log(response.data);\n  }})\n  .catch(console.error);\n",
+            options.join("\n")
+        ));
+        code
+    }
+
+    /// Monta um `FormData`; arquivos são lidos com o `fs` do Node.js
+    fn js_form_data(&self) -> String {
+        let mut code = String::from("const form = new FormData();\n");
+        for part in self.form {
+            match part.kind {
+                FormPartKind::Text => code.push_str(&format!(
+                    "form.append({}, {});\n",
+                    json_quote(&part.key),
+                    json_quote(&part.value)
+                )),
+                FormPartKind::File => {
+                    let options = match part.content_type.as_str() {
+                        "" => String::new(),
+                        content_type => format!(", {{ type: {} }}", json_quote(content_type)),
+                    };
+                    code.push_str(&format!(
+                        "form.append(\n  {},\n  new Blob([fs.readFileSync({})]{}),\n  {}\n);\n",
+                        json_quote(&part.key),
+                        json_quote(&part.value),
+                        options,
+                        json_quote(&part.upload_file_name())
+                    ));
+                }
+            }
+        }
+        code
+    }
+
+    fn reads_files(&self) -> bool {
+        self.body_file.is_some() || self.form.iter().any(|p| p.kind == FormPartKind::File)
+    }
+
+    fn js_headers(&self, indent: &str) -> String {
+        self.headers
+            .iter()
+            .map(|h| {
+                format!(
+                    "{}{}: {},",
+                    indent,
+                    json_quote(&h.key),
+                    json_quote(&h.value)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn go(&self) -> String {
+        let has_form = !self.form.is_empty();
+
+        let mut imports = vec![];
+        if has_form {
+            imports.push("\"bytes\"");
+        }
+        imports.extend(["\"fmt\"", "\"io\""]);
+        if has_form {
+            imports.push("\"mime/multipart\"");
+        }
+        imports.push("\"net/http\"");
+        if self.form.iter().any(|p| p.kind == FormPartKind::File) {
+            imports.push("\"net/textproto\"");
+        }
+        if self.reads_files() {
+            imports.push("\"os\"");
+        }
+        if self.body.is_some() {
+            imports.push("\"strings\"");
+        }
+        imports.push("\"time\"");
+
+        let mut code = format!(
+            "package main\n\nimport (\n{}\n)\n\nfunc main() {{\n",
+            imports
+                .iter()
+                .map(|i| format!("\t{}", i))
+                .collect::<Vec<_>>()
+                .join("\n")
+        );
+
+        let body_arg = match (self.body, self.body_file) {
+            (Some(body), _) => {
+                code.push_str(&format!(
+                    "\tbody := strings.NewReader({})\n",
+                    json_quote(body)
+                ));
+                "body"
+            }
+            (None, Some(path)) => {
+                code.push_str(&format!(
+                    "\tbody, err := os.Open({})\n\tif err != nil {{\n\t\tpanic(err)\n\t}}\n\tdefer body.Close()\n\n",
+                    json_quote(path)
+                ));
+                "body"
+            }
+            (None, None) if has_form => {
+                code.push_str(&self.go_form_data());
+                "body"
+            }
+            (None, None) => "nil",
+        };
+
+        code.push_str(&format!(
+            "\treq, err := http.NewRequest({}, {}, {})\n\tif err != nil {{\n\t\tpanic(err)\n\t}}\n",
+            json_quote(&self.method.to_string()),
+            json_quote(self.url),
+            body_arg
+        ));
+
+        for header in self.headers {
+            code.push_str(&format!(
+                "\treq.Header.Add({}, {})\n",
+                json_quote(&header.key),
+                json_quote(&header.value)
+            ));
+        }
+
+        if has_form {
+            code.push_str("\treq.Header.Set(\"Content-Type\", writer.FormDataContentType())\n");
+        }
+
+        code.push_str(&format!(
+            "\n\tclient := &http.Client{{Timeout: {} * time.Millisecond}}\n",
+            self.timeout_ms
+        ));
+
+        code.push_str(
+            "\tres, err := client.Do(req)\n\tif err != nil {\n\t\tpanic(err)\n\t}\n\tdefer res.Body.Close()\n\n\tdata, err := io.ReadAll(res.Body)\n\tif err != nil {\n\t\tpanic(err)\n\t}\n\n\tfmt.Println(res.Status)\n\tfmt.Println(string(data))\n}\n",
+        );
+        code
+    }
+
+    fn go_form_data(&self) -> String {
+        let mut code =
+            String::from("\tbody := &bytes.Buffer{}\n\twriter := multipart.NewWriter(body)\n");
+
+        for part in self.form {
+            match part.kind {
+                FormPartKind::Text => code.push_str(&format!(
+                    "\twriter.WriteField({}, {})\n",
+                    json_quote(&part.key),
+                    json_quote(&part.value)
+                )),
+                FormPartKind::File => {
+                    let disposition = format!(
+                        "form-data; name=\"{}\"; filename=\"{}\"",
+                        part.key,
+                        part.upload_file_name()
+                    );
+                    let content_type = match part.content_type.as_str() {
+                        "" => "application/octet-stream",
+                        content_type => content_type,
+                    };
+                    code.push_str(&format!(
+                        "\t{{\n\t\tpart, err := writer.CreatePart(textproto.MIMEHeader{{\n\t\t\t\"Content-Disposition\": {{{}}},\n\t\t\t\"Content-Type\":        {{{}}},\n\t\t}})\n\t\tif err != nil {{\n\t\t\tpanic(err)\n\t\t}}\n\t\tdata, err := os.ReadFile({})\n\t\tif err != nil {{\n\t\t\tpanic(err)\n\t\t}}\n\t\tpart.Write(data)\n\t}}\n",
+                        json_quote(&disposition),
+                        json_quote(content_type),
+                        json_quote(&part.value)
+                    ));
+                }
+            }
+        }
+
+        code.push_str("\twriter.Close()\n\n");
+        code
+    }
+
+    fn rust(&self) -> String {
+        let method = match self.method {
+            HTTPMethod::Custom(verb) => format!(
+                "reqwest::Method::from_bytes({:?}.as_bytes()).expect(\"valid method\")",
+                verb
+            ),
+            method => format!("reqwest::Method::{}", method),
+        };
+
+        let mut code = String::from(
+            "#[tokio::main]\nasync fn main() -> Result<(), reqwest::Error> {\n    let client = reqwest::Client::builder()\n",
+        );
+        code.push_str(&format!(
+            "        .timeout(std::time::Duration::from_millis({}))\n",
+            self.timeout_ms
+        ));
+        code.push_str("        .build()?;\n\n");
+
+        if !self.form.is_empty() {
+            code.push_str("    let form = reqwest::multipart::Form::new()");
+            for part in self.form {
+                let mut field = match part.kind {
+                    FormPartKind::Text if part.content_type.is_empty() => {
+                        code.push_str(&format!(
+                            "\n        .text({:?}, {:?})",
+                            part.key, part.value
+                        ));
+                        continue;
+                    }
+                    FormPartKind::Text => {
+                        format!("reqwest::multipart::Part::text({:?})", part.value)
+                    }
+                    FormPartKind::File => format!(
+                        "reqwest::multipart::Part::bytes(std::fs::read({:?}).expect(\"readable file\"))\n                .file_name({:?})",
+                        part.value,
+                        part.upload_file_name()
+                    ),
+                };
+                if !part.content_type.is_empty() {
+                    field.push_str(&format!(
+                        "\n                .mime_str({:?})?",
+                        part.content_type
+                    ));
+                }
+                code.push_str(&format!(
+                    "\n        .part(\n            {:?},\n            {},\n        )",
+                    part.key, field
+                ));
+            }
+            code.push_str(";\n\n");
+        }
+
+        code.push_str("    let response = client\n");
+        code.push_str(&format!("        .request({}, {:?})\n", method, self.url));
+
+        for header in self.headers {
+            code.push_str(&format!(
+                "        .header({:?}, {:?})\n",
+                header.key, header.value
+            ));
+        }
+
+        if let Some(body) = self.body {
+            code.push_str(&format!("        .body({:?})\n", body));
+        }
+
+        if let Some(path) = self.body_file {
+            code.push_str(&format!(
+                "        .body(std::fs::read({:?}).expect(\"readable file\"))\n",
+                path
+            ));
+        }
+
+        if !self.form.is_empty() {
+            code.push_str("        .multipart(form)\n");
+        }
+
+        code.push_str(
+            "        .send()\n        .await?;\n\n    println!(\"{}\", response.status());\n    println!(\"{}\", response.text().await?);\n    Ok(())\n}\n",
+        );
+        code
+    }
+
+    fn powershell(&self) -> String {
+        let mut code = String::new();
+        let mut args = vec![
+            format!("-Uri {}", powershell_quote(self.url)),
+            format!("-Method {}", powershell_quote(&self.method.to_string())),
+        ];
+
+        let (content_type, headers): (Vec<&&KeyValue>, Vec<&&KeyValue>) = self
+            .headers
+            .iter()
+            .partition(|h| h.key.eq_ignore_ascii_case("content-type"));
+
+        if !headers.is_empty() {
+            code.push_str("$headers = @{\n");
+            for header in headers {
+                code.push_str(&format!(
+                    "    {} = {}\n",
+                    powershell_quote(&header.key),
+                    powershell_quote(&header.value)
+                ));
+            }
+            code.push_str("}\n");
+            args.push("-Headers $headers".to_string());
+        }
+
+        if let Some(header) = content_type.last() {
+            args.push(format!("-ContentType {}", powershell_quote(&header.value)));
+        }
+
+        if let Some(body) = self.body {
+            code.push_str(&format!("$body = {}\n", powershell_quote(body)));
+            args.push("-Body $body".to_string());
+        }
+
+        if let Some(path) = self.body_file {
+            args.push(format!("-InFile {}", powershell_quote(path)));
+        }
+
+        if !self.form.is_empty() {
+            code.push_str("$form = @{\n");
+            for part in self.form {
+                let value = match part.kind {
+                    FormPartKind::Text => powershell_quote(&part.value),
+                    FormPartKind::File => {
+                        format!("Get-Item -Path {}", powershell_quote(&part.value))
+                    }
+                };
+                code.push_str(&format!(
+                    "    {} = {}\n",
+                    powershell_quote(&part.key),
+                    value
+                ));
+            }
+            code.push_str("}\n");
+            args.push("-Form $form".to_string());
+        }
+
+        args.push(format!("-TimeoutSec {}", self.timeout_ms.div_ceil(1000)));
+
+        if !code.is_empty() {
+            code.push('\n');
+        }
+
+        code.push_str(&format!(
+            "$response = Invoke-WebRequest {}\n$response.StatusCode\n$response.Content\n",
+            args.join(" `\n  ")
+        ));
+        code
+    }
+}
+
+/// Argumento `-F` do curl; valores com `;` ou iniciados por `@`/`<` usam `--form-string`
+fn curl_form_part(part: &FormPart) -> String {
+    match part.kind {
+        FormPartKind::Text if part.value.starts_with(['@', '<']) || part.value.contains(';') => {
+            format!(
+                "--form-string {}",
+                shell_quote(&format!("{}={}", part.key, part.value))
+            )
+        }
+        FormPartKind::Text => {
+            let mut field = format!("{}={}", part.key, part.value);
+            if !part.content_type.is_empty() {
+                field.push_str(&format!(";type={}", part.content_type));
+            }
+            format!("-F {}", shell_quote(&field))
+        }
+        FormPartKind::File => {
+            let mut field = format!("{}=@{}", part.key, curl_form_quote(&part.value));
+            if !part.content_type.is_empty() {
+                field.push_str(&format!(";type={}", part.content_type));
+            }
+            if !part.file_name.is_empty() {
+                field.push_str(&format!(";filename={}", curl_form_quote(&part.file_name)));
+            }
+            format!("-F {}", shell_quote(&field))
+        }
+    }
+}
+
+/// Aspas duplas do `-F` do curl, necessárias quando o valor contém `;`, `,` ou espaços
+fn curl_form_quote(value: &str) -> String {
+    if value.contains([';', ',', '"', ' ']) {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Aspas simples de shell POSIX: `'` vira `'\''`
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+/// Aspas simples do PowerShell: `'` vira `''`
+fn powershell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// String JSON, também válida como literal em Python, JavaScript e Go
+fn json_quote(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| format!("\"{}\"", value))
+}
+
+fn seconds(ms: u64) -> String {
+    format!("{}", ms as f64 / 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::enums::BodyType;
+
+    /// Requisição com aspas, quebras de linha, unicode e um query param desabilitado
+    fn fixture_request() -> HttpRequest {
+        let mut disabled = KeyValue::new("debug".to_string(), "1".to_string());
+        disabled.enabled = false;
+
+        HttpRequest {
+            method: HTTPMethod::POST,
+            url: "api.example.com/users".to_string(),
+            headers: vec![
+                KeyValue::new("Content-Type".to_string(), "application/json".to_string()),
+                KeyValue::new("X-Note".to_string(), "it's \"quoted\"".to_string()),
+            ],
+            query_params: vec![
+                KeyValue::new("q".to_string(), "a b&c".to_string()),
+                disabled,
+            ],
+            body: "{\"name\": \"O'Brien\",\n \"city\": \"São Paulo\"}".to_string(),
+            body_type: BodyType::Json,
+            ..HttpRequest::default()
+        }
+    }
+
+    fn assert_fixture(target: CodeTarget, expected: &str) {
+        let generated = generate(target, &fixture_request()).unwrap();
+        assert_eq!(
+            generated.trim_end(),
+            expected.trim_end(),
+            "target: {}",
+            target
+        );
+    }
+
+    #[test]
+    fn test_curl() {
+        assert_fixture(
+            CodeTarget::Curl,
+            include_str!("../../../tests/fixtures/codegen/curl.sh"),
+        );
+    }
+
+    #[test]
+    fn test_httpie() {
+        assert_fixture(
+            CodeTarget::Httpie,
+            include_str!("../../../tests/fixtures/codegen/httpie.sh"),
+        );
+    }
+
+    #[test]
+    fn test_wget() {
+        assert_fixture(
+            CodeTarget::Wget,
+            include_str!("../../../tests/fixtures/codegen/wget.sh"),
+        );
+    }
+
+    #[test]
+    fn test_python() {
+        assert_fixture(
+            CodeTarget::PythonRequests,
+            include_str!("../../../tests/fixtures/codegen/python_requests.py"),
+        );
+    }
+
+    #[test]
+    fn test_fetch() {
+        assert_fixture(
+            CodeTarget::JavaScriptFetch,
+            include_str!("../../../tests/fixtures/codegen/fetch.js"),
+        );
+    }
+
+    #[test]
+    fn test_axios() {
+        assert_fixture(
+            CodeTarget::NodeAxios,
+            include_str!("../../../tests/fixtures/codegen/axios.js"),
+        );
+    }
+
+    #[test]
+    fn test_go() {
+        assert_fixture(
+            CodeTarget::GoNetHttp,
+            include_str!("../../../tests/fixtures/codegen/net_http.go"),
+        );
+    }
+
+    #[test]
+    fn test_rust() {
+        assert_fixture(
+            CodeTarget::RustReqwest,
+            include_str!("../../../tests/fixtures/codegen/reqwest.rs.txt"),
+        );
+    }
+
+    #[test]
+    fn test_powershell() {
+        assert_fixture(
+            CodeTarget::PowerShell,
+            include_str!("../../../tests/fixtures/codegen/invoke_webrequest.ps1"),
+        );
+    }
+
+    #[test]
+    fn test_get_without_body_or_headers() {
+        let request = HttpRequest {
+            url: "https://example.com".to_string(),
+            headers: Vec::new(),
+            body: "ignored".to_string(),
+            body_type: BodyType::Raw,
+            ..HttpRequest::default()
+        };
+
+        assert_eq!(
+            generate(CodeTarget::Curl, &request).unwrap(),
+            "curl -X GET 'https://example.com/'"
+        );
+    }
+
+    #[test]
+    fn test_form_data() {
+        let mut file = FormPart::file("doc".to_string(), "/tmp/my report.pdf".to_string());
+        file.content_type = "application/pdf".to_string();
+        let request = HttpRequest {
+            method: HTTPMethod::POST,
+            url: "https://example.com/upload".to_string(),
+            body_type: BodyType::FormData,
+            form_data: vec![
+                FormPart::text("title".to_string(), "Q3; final".to_string()),
+                file,
+            ],
+            ..HttpRequest::default()
+        };
+        let boundary = multipart::boundary(&request.form_data);
+
+        // O Content-Type implícito do multipart leva o boundary estável
+        assert_eq!(
+            generate(CodeTarget::Curl, &request).unwrap(),
+            format!(
+                "curl -X POST 'https://example.com/upload' \\\n  -H 'Content-Type: multipart/form-data; boundary={}' \\\n  --form-string 'title=Q3; final' \\\n  -F 'doc=@\"/tmp/my report.pdf\";type=application/pdf'",
+                boundary
+            )
+        );
+
+        let python = generate(CodeTarget::PythonRequests, &request).unwrap();
+        assert!(python.contains(
+            r#"("doc", ("my report.pdf", open("/tmp/my report.pdf", "rb"), "application/pdf")),"#
+        ));
+
+        let axios = generate(CodeTarget::NodeAxios, &request).unwrap();
+        assert!(axios.starts_with(
+            "const axios = require(\"axios\");\nconst fs = require(\"fs\");\n\nconst form = new FormData();\n"
+        ));
+        assert!(axios.contains("    data: form,\n"));
+
+        let go = generate(CodeTarget::GoNetHttp, &request).unwrap();
+        assert!(go.contains("req.Header.Set(\"Content-Type\", writer.FormDataContentType())"));
+        assert!(!go.contains("application/json"));
+    }
+
+    #[test]
+    fn test_form_url_encoded() {
+        let mut disabled = KeyValue::new("debug".to_string(), "1".to_string());
+        disabled.enabled = false;
+        let request = HttpRequest {
+            method: HTTPMethod::POST,
+            url: "https://example.com/login".to_string(),
+            headers: Vec::new(),
+            body_type: BodyType::FormUrlEncoded,
+            form_urlencoded: vec![
+                KeyValue::new("user name".to_string(), "Ana & Bia".to_string()),
+                KeyValue::new("scope".to_string(), "read write".to_string()),
+                disabled,
+            ],
+            ..HttpRequest::default()
+        };
+
+        let curl = generate(CodeTarget::Curl, &request).unwrap();
+        assert_eq!(
+            curl,
+            "curl -X POST 'https://example.com/login' \\\n  -H 'Content-Type: application/x-www-form-urlencoded' \\\n  --data-urlencode 'user+name=Ana & Bia' \\\n  --data-urlencode 'scope=read write'"
+        );
+        // O comando gerado volta a ser importado como os mesmos pares
+        let imported = crate::components::formats::curl::parse(&curl)
+            .unwrap()
+            .request;
+        assert_eq!(imported.body_type, BodyType::FormUrlEncoded);
+        assert_eq!(imported.encoded_body(), request.encoded_body());
+
+        let python = generate(CodeTarget::PythonRequests, &request).unwrap();
+        assert!(python.contains("user+name=Ana+%26+Bia&scope=read+write"));
+    }
+
+    #[test]
+    fn test_binary_file_body() {
+        let request = HttpRequest {
+            method: HTTPMethod::PUT,
+            url: "https://example.com/blob".to_string(),
+            headers: Vec::new(),
+            body_type: BodyType::Binary,
+            binary_file: "/tmp/logo.png".to_string(),
+            ..HttpRequest::default()
+        };
+
+        assert_eq!(
+            generate(CodeTarget::Curl, &request).unwrap(),
+            "curl -X PUT 'https://example.com/blob' \\\n  -H 'Content-Type: image/png' \\\n  --data-binary '@/tmp/logo.png'"
+        );
+
+        let httpie = generate(CodeTarget::Httpie, &request).unwrap();
+        assert!(httpie.starts_with("http PUT "));
+        assert!(httpie.ends_with("< '/tmp/logo.png'"));
+
+        let go = generate(CodeTarget::GoNetHttp, &request).unwrap();
+        assert!(go.contains("\t\"os\"\n"));
+        assert!(!go.contains("textproto"));
+        assert!(go.contains("body, err := os.Open(\"/tmp/logo.png\")"));
+
+        let powershell = generate(CodeTarget::PowerShell, &request).unwrap();
+        assert!(powershell.contains("-InFile '/tmp/logo.png'"));
+    }
+}
@@ -0,0 +1,712 @@
+//! Importação de comandos curl (incluindo "Copy as cURL" dos navegadores)
+
+use super::{ImportedRequest, detect_body_type, import_url_encoded, split_query_params};
+use crate::components::enums::{Auth, BodyType, FormPart, HTTPMethod, HttpRequest, KeyValue};
+
+/// Retorna true se o texto parece ser um comando curl colado
+pub fn looks_like_curl(input: &str) -> bool {
+    let trimmed = input.trim_start();
+    trimmed.starts_with("curl ") || trimmed.starts_with("curl.exe ")
+}
+
+/// Converte um comando curl em uma requisição.
+///
+/// # Errors
+/// Retorna erro se o comando não puder ser tokenizado ou não tiver URL.
+pub fn parse(command: &str) -> Result<ImportedRequest, String> {
+    let tokens = tokenize(command)?;
+    let mut args = tokens.into_iter();
+
+    match args.next() {
+        Some(program)
+            if program == "curl" || program.ends_with("/curl") || program == "curl.exe" => {}
+        _ => return Err("Not a curl command".to_string()),
+    }
+
+    let mut parser = CurlParser::default();
+    let args: Vec<String> = args.collect();
+    let mut index = 0;
+
+    while index < args.len() {
+        let arg = &args[index];
+        index += 1;
+
+        if arg == "--" {
+            parser.urls.extend(args[index..].iter().cloned());
+            break;
+        }
+
+        if let Some(long) = arg.strip_prefix("--") {
+            let (name, inline_value) = match long.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_string())),
+                None => (long, None),
+            };
+
+            if takes_value(&format!("--{}", name)) {
+                let value = match inline_value {
+                    Some(value) => value,
+                    None => next_value(&args, &mut index, arg)?,
+                };
+                parser.apply(&format!("--{}", name), value);
+            } else {
+                parser.apply_flag(&format!("--{}", name));
+            }
+        } else if arg.starts_with('-') && arg.len() > 1 {
+            let flags: Vec<char> = arg[1..].chars().collect();
+
+            for (position, flag) in flags.iter().enumerate() {
+                let option = format!("-{}", flag);
+
+                if takes_value(&option) {
+                    let rest: String = flags[position + 1..].iter().collect();
+                    let value = if rest.is_empty() {
+                        next_value(&args, &mut index, arg)?
+                    } else {
+                        rest
+                    };
+                    parser.apply(&option, value);
+                    break;
+                }
+
+                parser.apply_flag(&option);
+            }
+        } else {
+            parser.urls.push(arg.clone());
+        }
+    }
+
+    parser.finish()
+}
+
+fn next_value(args: &[String], index: &mut usize, option: &str) -> Result<String, String> {
+    let value = args
+        .get(*index)
+        .cloned()
+        .ok_or_else(|| format!("Missing value for curl option {}", option))?;
+    *index += 1;
+    Ok(value)
+}
+
+fn takes_value(option: &str) -> bool {
+    matches!(
+        option,
+        "-X" | "--request"
+            | "-H"
+            | "--header"
+            | "-d"
+            | "--data"
+            | "--data-ascii"
+            | "--data-raw"
+            | "--data-binary"
+            | "--data-urlencode"
+            | "--json"
+            | "-F"
+            | "--form"
+            | "--form-string"
+            | "-u"
+            | "--user"
+            | "-A"
+            | "--user-agent"
+            | "-e"
+            | "--referer"
+            | "-b"
+            | "--cookie"
+            | "-m"
+            | "--max-time"
+            | "--url"
+            | "-o"
+            | "--output"
+            | "--connect-timeout"
+            | "-x"
+            | "--proxy"
+            | "-w"
+            | "--write-out"
+            | "--retry"
+            | "-c"
+            | "--cookie-jar"
+            | "--cacert"
+            | "-E"
+            | "--cert"
+            | "--key"
+            | "-r"
+            | "--range"
+    )
+}
+
+#[derive(Default)]
+struct CurlParser {
+    method: Option<String>,
+    urls: Vec<String>,
+    headers: Vec<KeyValue>,
+    data: Vec<String>,
+    json_data: bool,
+    /// Partes de `-F`/`--form-string`, na ordem do comando
+    form: Vec<FormPart>,
+    /// Arquivo de `--data-binary @arquivo`, enviado como body binário
+    data_file: Option<String>,
+    get: bool,
+    head: bool,
+    /// Credenciais `usuario:senha` de `-u`, aplicadas na aba Auth
+    user: Option<String>,
+    digest: bool,
+    timeout_ms: Option<u64>,
+    warnings: Vec<String>,
+}
+
+impl CurlParser {
+    fn apply(&mut self, option: &str, value: String) {
+        match option {
+            "-X" | "--request" => self.method = Some(value),
+            "-H" | "--header" => self.add_header(&value),
+            "-d" | "--data" | "--data-ascii" => self.add_data(value),
+            "--data-raw" => self.data.push(value),
+            "--data-binary" => match value.strip_prefix('@') {
+                Some(path) if self.data_file.is_none() => self.data_file = Some(path.to_string()),
+                _ => self.add_data(value),
+            },
+            "--json" => {
+                self.json_data = true;
+                self.add_data(value);
+            }
+            "--data-urlencode" => self.add_urlencoded(&value),
+            "-F" | "--form" => self.add_form(&value, false),
+            "--form-string" => self.add_form(&value, true),
+            "-u" | "--user" => self.user = Some(value),
+            "-A" | "--user-agent" => self.set_header("User-Agent", value),
+            "-e" | "--referer" => self.set_header("Referer", value),
+            "-b" | "--cookie" => {
+                if value.contains('=') {
+                    self.set_header("Cookie", value);
+                } else {
+                    self.warnings
+                        .push(format!("Cookie file ignored: {}", value));
+                }
+            }
+            "-m" | "--max-time" => match value.parse::<f64>() {
+                Ok(seconds) if seconds > 0.0 => self.timeout_ms = Some((seconds * 1000.0) as u64),
+                _ => self.warnings.push(format!("Invalid --max-time: {}", value)),
+            },
+            "--url" => self.urls.push(value),
+            _ => self
+                .warnings
+                .push(format!("Ignored curl option: {} {}", option, value)),
+        }
+    }
+
+    fn apply_flag(&mut self, option: &str) {
+        match option {
+            "-G" | "--get" => self.get = true,
+            "-I" | "--head" => self.head = true,
+            "-k" | "--insecure" => self.warnings.push(format!(
+                "Ignored curl option: {} (certificates are always verified)",
+                option
+            )),
+            "--digest" => self.digest = true,
+            "--basic" => self.digest = false,
+            // Aceitas sem efeito: descompressão, redirects e saída do terminal
+            "--compressed" | "-L" | "--location" | "-s" | "--silent" | "-S" | "--show-error"
+            | "-v" | "--verbose" | "-i" | "--include" | "-f" | "--fail" | "--http1.1"
+            | "--http2" | "-N" | "--no-buffer" | "-g" | "--globoff" => {}
+            _ => self
+                .warnings
+                .push(format!("Ignored curl option: {}", option)),
+        }
+    }
+
+    fn add_header(&mut self, raw: &str) {
+        match raw.split_once(':') {
+            Some((key, value)) => self.headers.push(KeyValue::new(
+                key.trim().to_string(),
+                value.trim().to_string(),
+            )),
+            None => self
+                .warnings
+                .push(format!("Invalid header ignored: {}", raw)),
+        }
+    }
+
+    fn set_header(&mut self, key: &str, value: String) {
+        self.headers.retain(|h| !h.key.eq_ignore_ascii_case(key));
+        self.headers.push(KeyValue::new(key.to_string(), value));
+    }
+
+    fn add_data(&mut self, value: String) {
+        if value.starts_with('@') {
+            self.warnings
+                .push(format!("File reference not loaded: {}", value));
+        } else {
+            self.data.push(value);
+        }
+    }
+
+    /// `nome=valor`, `nome=@arquivo` ou `nome=<arquivo`, com `;type=` e `;filename=`
+    fn add_form(&mut self, value: &str, literal: bool) {
+        let Some((key, content)) = value.split_once('=') else {
+            self.warnings
+                .push(format!("Invalid form field ignored: {}", value));
+            return;
+        };
+
+        if literal {
+            self.form
+                .push(FormPart::text(key.to_string(), content.to_string()));
+            return;
+        }
+
+        let (mut part, params) = match content.strip_prefix(['@', '<']) {
+            Some(file) => {
+                let (path, params) = split_form_value(file);
+                (FormPart::file(key.to_string(), path), params)
+            }
+            None => {
+                let (text, params) = split_form_value(content);
+                (FormPart::text(key.to_string(), text), params)
+            }
+        };
+
+        for (name, option) in params {
+            match name.as_str() {
+                "type" => part.content_type = option,
+                "filename" => part.file_name = option,
+                _ => self
+                    .warnings
+                    .push(format!("Form field option ignored: {}={}", name, option)),
+            }
+        }
+
+        if content.starts_with('<') {
+            self.warnings.push(format!(
+                "Form field '{}' reads a file as text; imported as a file upload",
+                key
+            ));
+        }
+        self.form.push(part);
+    }
+
+    /// Segue as formas de `--data-urlencode`: `content`, `=content` e `name=content`
+    fn add_urlencoded(&mut self, value: &str) {
+        let encode = |text: &str| -> String {
+            url::form_urlencoded::byte_serialize(text.as_bytes()).collect()
+        };
+
+        if let Some(content) = value.strip_prefix('=') {
+            self.data.push(encode(content));
+        } else if let Some((name, content)) = value.split_once('=') {
+            self.data.push(format!("{}={}", name, encode(content)));
+        } else if value.contains('@') {
+            self.warnings
+                .push(format!("File reference not loaded: {}", value));
+        } else {
+            self.data.push(encode(value));
+        }
+    }
+
+    fn finish(mut self) -> Result<ImportedRequest, String> {
+        let url = self
+            .urls
+            .first()
+            .cloned()
+            .ok_or_else(|| "curl command has no URL".to_string())?;
+
+        if self.urls.len() > 1 {
+            self.warnings
+                .push(format!("Only the first URL was imported: {}", url));
+        }
+
+        let mut request = HttpRequest {
+            url,
+            headers: Vec::new(),
+            ..HttpRequest::default()
+        };
+
+        if let Some(timeout_ms) = self.timeout_ms {
+            request.timeout_ms = timeout_ms;
+        }
+
+        if let Some(user) = &self.user {
+            let (username, password) = user.split_once(':').unwrap_or((user, ""));
+            let (username, password) = (username.to_string(), password.to_string());
+            request.auth = if self.digest {
+                Auth::Digest { username, password }
+            } else {
+                Auth::Basic { username, password }
+            };
+        }
+
+        let data = self.data.join("&");
+
+        if let Some(path) = self.data_file.take() {
+            if data.is_empty() && self.form.is_empty() && !self.get {
+                request.body_type = BodyType::Binary;
+                request.binary_file = path;
+            } else {
+                self.warnings
+                    .push(format!("File reference not loaded: @{}", path));
+            }
+        }
+
+        if !self.form.is_empty() {
+            if !data.is_empty() {
+                self.warnings
+                    .push("Data ignored: -F and -d cannot be combined".to_string());
+            }
+            // O Content-Type com boundary é gerado no envio
+            self.headers
+                .retain(|h| !h.key.eq_ignore_ascii_case("content-type"));
+            request.body_type = BodyType::FormData;
+            request.form_data = std::mem::take(&mut self.form);
+        } else if self.get && !data.is_empty() {
+            let separator = if request.url.contains('?') { '&' } else { '?' };
+            request.url = format!("{}{}{}", request.url, separator, data);
+        } else if !data.is_empty() {
+            let has_content_type = self
+                .headers
+                .iter()
+                .any(|h| h.key.eq_ignore_ascii_case("content-type"));
+
+            if !has_content_type {
+                let content_type = if self.json_data {
+                    "application/json"
+                } else {
+                    "application/x-www-form-urlencoded"
+                };
+                self.set_header("Content-Type", content_type.to_string());
+            }
+
+            request.body_type = detect_body_type(&self.headers, &data);
+            request.body = data;
+        }
+
+        let has_body = !request.body.is_empty()
+            || !request.form_data.is_empty()
+            || !request.binary_file.is_empty();
+        request.method = match self.method {
+            Some(method) => HTTPMethod::from_input(&method)
+                .ok_or_else(|| format!("Invalid HTTP method: {}", method))?,
+            None if self.head => HTTPMethod::HEAD,
+            None if has_body => HTTPMethod::POST,
+            None => HTTPMethod::GET,
+        };
+        request.force_body = has_body && !request.method.allows_body();
+        request.headers = self.headers;
+
+        import_url_encoded(&mut request);
+
+        split_query_params(&mut request);
+
+        Ok(ImportedRequest {
+            request,
+            warnings: self.warnings,
+        })
+    }
+}
+
+/// Separa o valor (opcionalmente entre aspas) das opções `;chave=valor` do `-F`
+fn split_form_value(input: &str) -> (String, Vec<(String, String)>) {
+    let (value, mut rest) = unquote_form_value(input);
+    let mut params = Vec::new();
+
+    while let Some(param) = rest.strip_prefix(';') {
+        let param = param.trim_start();
+        let (name, option) = param.split_once('=').unwrap_or((param, ""));
+        let (option, remaining) = unquote_form_value(option.trim_start());
+        if !name.trim().is_empty() {
+            params.push((name.trim().to_string(), option));
+        }
+        rest = remaining;
+    }
+
+    (value, params)
+}
+
+/// Lê um valor do `-F` até o primeiro `;`, ou entre aspas duplas com `\` de escape
+fn unquote_form_value(input: &str) -> (String, &str) {
+    let Some(quoted) = input.strip_prefix('"') else {
+        let end = input.find(';').unwrap_or(input.len());
+        return (input[..end].to_string(), &input[end..]);
+    };
+
+    let mut value = String::new();
+    let mut chars = quoted.char_indices();
+    while let Some((index, ch)) = chars.next() {
+        match ch {
+            '\\' => {
+                if let Some((_, escaped)) = chars.next() {
+                    value.push(escaped);
+                }
+            }
+            '"' => return (value, &quoted[index + 1..]),
+            ch => value.push(ch),
+        }
+    }
+    (value, "")
+}
+
+/// Divide o comando em argumentos seguindo as regras de aspas do shell POSIX,
+/// incluindo continuações de linha e `$'...'`
+fn tokenize(input: &str) -> Result<Vec<String>, String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_token = false;
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {
+                if in_token {
+                    tokens.push(std::mem::take(&mut current));
+                    in_token = false;
+                }
+            }
+            '\\' => match chars.next() {
+                Some('\n') => {}
+                Some('\r') if chars.peek() == Some(&'\n') => {
+                    chars.next();
+                }
+                Some(escaped) => {
+                    current.push(escaped);
+                    in_token = true;
+                }
+                None => {}
+            },
+            '\'' => {
+                in_token = true;
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(ch) => current.push(ch),
+                        None => return Err("Unterminated quote in curl command".to_string()),
+                    }
+                }
+            }
+            '"' => {
+                in_token = true;
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(ch @ ('"' | '\\' | '$' | '`')) => current.push(ch),
+                            Some('\n') => {}
+                            Some(ch) => {
+                                current.push('\\');
+                                current.push(ch);
+                            }
+                            None => {
+                                return Err("Unterminated quote in curl command".to_string());
+                            }
+                        },
+                        Some(ch) => current.push(ch),
+                        None => return Err("Unterminated quote in curl command".to_string()),
+                    }
+                }
+            }
+            '$' if chars.peek() == Some(&'\'') => {
+                chars.next();
+                in_token = true;
+                read_ansi_c_quoted(&mut chars, &mut current)?;
+            }
+            _ => {
+                current.push(c);
+                in_token = true;
+            }
+        }
+    }
+
+    if in_token {
+        tokens.push(current);
+    }
+
+    Ok(tokens)
+}
+
+fn read_ansi_c_quoted(
+    chars: &mut std::iter::Peekable<std::str::Chars<'_>>,
+    output: &mut String,
+) -> Result<(), String> {
+    loop {
+        match chars.next() {
+            Some('\'') => return Ok(()),
+            Some('\\') => match chars.next() {
+                Some('n') => output.push('\n'),
+                Some('t') => output.push('\t'),
+                Some('r') => output.push('\r'),
+                Some('0') => output.push('\0'),
+                Some(kind @ ('x' | 'u')) => {
+                    let max_digits = if kind == 'x' { 2 } else { 4 };
+                    let mut digits = String::new();
+                    while digits.len() < max_digits
+                        && chars.peek().is_some_and(|ch| ch.is_ascii_hexdigit())
+                    {
+                        digits.push(chars.next().unwrap_or_default());
+                    }
+                    match u32::from_str_radix(&digits, 16)
+                        .ok()
+                        .and_then(char::from_u32)
+                    {
+                        Some(ch) => output.push(ch),
+                        None => {
+                            output.push('\\');
+                            output.push(kind);
+                            output.push_str(&digits);
+                        }
+                    }
+                }
+                Some(ch) => output.push(ch),
+                None => return Err("Unterminated quote in curl command".to_string()),
+            },
+            Some(ch) => output.push(ch),
+            None => return Err("Unterminated quote in curl command".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_post_with_headers_and_continuations() {
+        let command = "curl -X POST 'https://api.example.com/users?page=2' \\\n  -H 'Content-Type: application/json' \\\n  -H \"Authorization: Bearer abc\" \\\n  --data-raw '{\"name\":\"O'\\''Brien\"}'";
+
+        let imported = parse(command).unwrap();
+        let request = imported.request;
+
+        assert_eq!(request.method, HTTPMethod::POST);
+        assert_eq!(request.url, "https://api.example.com/users");
+        assert_eq!(
+            request.query_params,
+            vec![KeyValue::new("page".to_string(), "2".to_string())]
+        );
+        assert_eq!(request.headers.len(), 2);
+        assert_eq!(request.body, r#"{"name":"O'Brien"}"#);
+        assert_eq!(request.body_type, BodyType::Json);
+        assert!(imported.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_parse_get_with_data_and_basic_auth() {
+        let imported = parse(
+            "curl -G https://example.com/search -d q=rust --data-urlencode 'tag=a b' -u user:pass -k --compressed",
+        )
+        .unwrap();
+        let request = imported.request;
+
+        assert_eq!(request.method, HTTPMethod::GET);
+        assert_eq!(request.url, "https://example.com/search");
+        assert_eq!(request.query_params.len(), 2);
+        assert_eq!(request.query_params[1].value, "a b");
+        assert!(request.body.is_empty());
+        assert_eq!(
+            imported.warnings,
+            vec!["Ignored curl option: -k (certificates are always verified)"]
+        );
+        assert!(request.headers.is_empty());
+        assert_eq!(
+            request.auth,
+            Auth::Basic {
+                username: "user".into(),
+                password: "pass".into()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_combined_short_flags_and_ansi_quotes() {
+        let imported = parse("curl -sSLXPUT $'https://example.com/a' -d $'line1\\nline2'").unwrap();
+
+        assert_eq!(imported.request.method, HTTPMethod::PUT);
+        assert_eq!(imported.request.body, "line1\nline2");
+        assert_eq!(imported.request.body_type, BodyType::Raw);
+    }
+
+    #[test]
+    fn test_parse_body_type_from_content_type() {
+        for (content_type, body_type) in [
+            ("text/xml; charset=utf-8", BodyType::Xml),
+            ("application/soap+xml", BodyType::Xml),
+            ("application/yaml", BodyType::Yaml),
+            ("text/html", BodyType::Html),
+        ] {
+            let command = format!(
+                "curl https://example.com -H 'Content-Type: {}' -d 'a: <b/>'",
+                content_type
+            );
+            assert_eq!(parse(&command).unwrap().request.body_type, body_type);
+        }
+    }
+
+    #[test]
+    fn test_parse_multipart_form() {
+        let imported = parse(
+            r#"curl https://example.com/upload -H 'Content-Type: multipart/form-data' -F 'title=Q3;type=text/plain' -F 'doc=@"/tmp/my report.pdf";type=application/pdf;filename="r;1.pdf"' --form-string 'note=@literal'"#,
+        )
+        .unwrap();
+        let request = imported.request;
+
+        assert_eq!(request.method, HTTPMethod::POST);
+        assert_eq!(request.body_type, BodyType::FormData);
+        assert!(request.headers.is_empty());
+        assert!(imported.warnings.is_empty(), "{:?}", imported.warnings);
+
+        let mut title = FormPart::text("title".into(), "Q3".into());
+        title.content_type = "text/plain".into();
+        let mut doc = FormPart::file("doc".into(), "/tmp/my report.pdf".into());
+        doc.content_type = "application/pdf".into();
+        doc.file_name = "r;1.pdf".into();
+        let note = FormPart::text("note".into(), "@literal".into());
+        assert_eq!(request.form_data, vec![title, doc, note]);
+    }
+
+    #[test]
+    fn test_parse_url_encoded_fields() {
+        let imported = parse(
+            "curl https://example.com/login --data-urlencode 'user=Ana Souza' --data-urlencode 'note=a&b=c' -d remember=1",
+        )
+        .unwrap();
+        let request = imported.request;
+
+        assert_eq!(request.method, HTTPMethod::POST);
+        assert_eq!(request.body_type, BodyType::FormUrlEncoded);
+        assert!(request.body.is_empty());
+        assert!(request.headers.is_empty());
+        assert_eq!(
+            request.form_urlencoded,
+            vec![
+                KeyValue::new("user".into(), "Ana Souza".into()),
+                KeyValue::new("note".into(), "a&b=c".into()),
+                KeyValue::new("remember".into(), "1".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_binary_file_body() {
+        let imported = parse(
+            "curl -X PUT https://example.com/blob -H 'Content-Type: image/png' --data-binary '@/tmp/logo.png'",
+        )
+        .unwrap();
+        let request = imported.request;
+
+        assert_eq!(request.method, HTTPMethod::PUT);
+        assert_eq!(request.body_type, BodyType::Binary);
+        assert_eq!(request.binary_file, "/tmp/logo.png");
+        assert!(request.body.is_empty());
+        assert!(imported.warnings.is_empty());
+
+        // Misturado com outros dados, o arquivo não é carregado
+        let imported = parse("curl https://example.com -d a=1 --data-binary @blob.bin").unwrap();
+        assert_eq!(imported.request.encoded_body(), "a=1");
+        assert_eq!(
+            imported.warnings,
+            vec!["File reference not loaded: @blob.bin"]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("curl -H 'X: 1'").is_err());
+        assert!(parse("curl 'https://example.com").is_err());
+        assert!(parse("wget https://example.com").is_err());
+        assert!(looks_like_curl("  curl https://example.com"));
+    }
+}
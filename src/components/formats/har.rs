@@ -0,0 +1,668 @@
+//! Importação e exportação do histórico no formato HAR 1.2 (HTTP Archive)
+
+use super::{detect_body_type, import_url_encoded, multipart, split_query_params};
+use crate::components::enums::{
+    BodyType, FormPart, FormPartKind, HTTPMethod, HistoryItem, HttpRequest, HttpResponse, KeyValue,
+};
+use crate::components::file_dialog::FileFilter;
+use crate::components::http_client::HttpClient;
+use crate::components::{auth, graphql};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use bytes::Bytes;
+use chrono::{DateTime, SecondsFormat, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Filtro dos diálogos de importação/exportação
+pub const FILE_FILTER: FileFilter = ("HTTP Archive", &["har"]);
+
+const HAR_VERSION: &str = "1.2";
+const HTTP_VERSION: &str = "HTTP/1.1";
+const CANCELLED_COMMENT: &str = "Cancelled before a response was received";
+
+/// Headers que o cliente recalcula ao reenviar; importá-los quebraria o replay
+const SKIPPED_REQUEST_HEADERS: [&str; 2] = ["content-length", "host"];
+
+/// Resultado da importação de um arquivo HAR
+#[derive(Debug, Clone)]
+pub struct HarImport {
+    /// Entradas convertidas, na ordem do arquivo
+    pub items: Vec<HistoryItem>,
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Har {
+    log: Log,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Log {
+    version: String,
+    creator: Creator,
+    #[serde(default)]
+    entries: Vec<Entry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Creator {
+    name: String,
+    version: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Entry {
+    started_date_time: String,
+    #[serde(default)]
+    time: f64,
+    request: Request,
+    response: Response,
+    #[serde(default)]
+    cache: serde_json::Map<String, serde_json::Value>,
+    #[serde(default)]
+    timings: Timings,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    comment: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Request {
+    method: String,
+    url: String,
+    #[serde(default)]
+    http_version: String,
+    #[serde(default)]
+    cookies: Vec<serde_json::Value>,
+    #[serde(default)]
+    headers: Vec<NameValue>,
+    #[serde(default)]
+    query_string: Vec<NameValue>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    post_data: Option<PostData>,
+    #[serde(default = "unknown_size")]
+    headers_size: i64,
+    #[serde(default = "unknown_size")]
+    body_size: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Response {
+    status: i64,
+    #[serde(default)]
+    status_text: String,
+    #[serde(default)]
+    http_version: String,
+    #[serde(default)]
+    cookies: Vec<serde_json::Value>,
+    #[serde(default)]
+    headers: Vec<NameValue>,
+    #[serde(default)]
+    content: Content,
+    #[serde(default, rename = "redirectURL")]
+    redirect_url: String,
+    #[serde(default = "unknown_size")]
+    headers_size: i64,
+    #[serde(default = "unknown_size")]
+    body_size: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct NameValue {
+    name: String,
+    #[serde(default)]
+    value: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PostData {
+    #[serde(default)]
+    mime_type: String,
+    #[serde(default)]
+    text: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    params: Vec<Param>,
+}
+
+/// Campo de formulário do `postData`; arquivos têm `fileName`
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Param {
+    name: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    file_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    content_type: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Content {
+    #[serde(default)]
+    size: i64,
+    #[serde(default)]
+    mime_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    encoding: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Timings {
+    #[serde(default)]
+    send: f64,
+    #[serde(default)]
+    wait: f64,
+    #[serde(default)]
+    receive: f64,
+}
+
+fn unknown_size() -> i64 {
+    -1
+}
+
+/// Importa um arquivo HAR (ex.: exportado pelo DevTools do navegador).
+///
+/// # Errors
+/// Retorna erro se o JSON não for um HAR válido.
+pub fn parse(json: &str) -> Result<HarImport, String> {
+    let har: Har = serde_json::from_str(json).map_err(|e| format!("Invalid HAR file: {}", e))?;
+
+    let mut warnings = Vec::new();
+    let items = har
+        .log
+        .entries
+        .into_iter()
+        .map(|entry| import_entry(entry, &mut warnings))
+        .collect();
+
+    Ok(HarImport { items, warnings })
+}
+
+fn import_entry(entry: Entry, warnings: &mut Vec<String>) -> HistoryItem {
+    let timestamp = DateTime::parse_from_rfc3339(&entry.started_date_time)
+        .map(|date| date.timestamp())
+        .unwrap_or_else(|_| {
+            warnings.push(format!(
+                "Invalid startedDateTime for {}: {}",
+                entry.request.url, entry.started_date_time
+            ));
+            0
+        });
+
+    let request = import_request(&entry.request);
+
+    let response = (entry.response.status > 0).then(|| {
+        HttpResponse::new(
+            entry.response.status as u16,
+            entry.response.status_text.clone(),
+            import_headers(&entry.response.headers),
+            import_content(&entry.response.content, &entry.request.url, warnings),
+            entry.time.max(0.0).round() as u128,
+        )
+    });
+
+    HistoryItem {
+        request,
+        resolved_request: None,
+        response,
+        timestamp,
+        websocket: None,
+    }
+}
+
+fn import_request(har_request: &Request) -> HttpRequest {
+    let mut request = HttpRequest {
+        method: HTTPMethod::from_input(&har_request.method).unwrap_or_default(),
+        url: har_request.url.clone(),
+        headers: har_request
+            .headers
+            .iter()
+            .filter(|h| !h.name.starts_with(':'))
+            .filter(|h| {
+                !SKIPPED_REQUEST_HEADERS
+                    .iter()
+                    .any(|skipped| h.name.eq_ignore_ascii_case(skipped))
+            })
+            .map(|h| KeyValue::new(h.name.clone(), h.value.clone()))
+            .collect(),
+        ..HttpRequest::default()
+    };
+    split_query_params(&mut request);
+
+    if let Some(post_data) = &har_request.post_data
+        && post_data.text.is_empty()
+        && !post_data.params.is_empty()
+        && post_data
+            .mime_type
+            .to_ascii_lowercase()
+            .starts_with("multipart/form-data")
+    {
+        // O boundary original não vale mais: o Content-Type é gerado no envio
+        request
+            .headers
+            .retain(|h| !h.key.eq_ignore_ascii_case("content-type"));
+        request.body_type = BodyType::FormData;
+        request.form_data = post_data.params.iter().map(import_param).collect();
+        request.force_body = request.method.can_force_body();
+    } else if let Some(post_data) = &har_request.post_data {
+        request.body = if post_data.text.is_empty() && !post_data.params.is_empty() {
+            let mut form = url::form_urlencoded::Serializer::new(String::new());
+            for param in &post_data.params {
+                form.append_pair(&param.name, &param.value);
+            }
+            form.finish()
+        } else {
+            post_data.text.clone()
+        };
+        request.body_type = detect_body_type(&request.headers, &request.body);
+        request.force_body = !request.body.is_empty() && request.method.can_force_body();
+        import_url_encoded(&mut request);
+    }
+
+    request
+}
+
+fn import_param(param: &Param) -> FormPart {
+    let mut part = match &param.file_name {
+        Some(file_name) => FormPart::file(param.name.clone(), file_name.clone()),
+        None => FormPart::text(param.name.clone(), param.value.clone()),
+    };
+    part.content_type = param.content_type.clone().unwrap_or_default();
+    part
+}
+
+/// Decodifica o conteúdo da resposta; bodies binários não cabem no histórico de texto
+fn import_content(content: &Content, url: &str, warnings: &mut Vec<String>) -> Bytes {
+    let text = content.text.clone().unwrap_or_default();
+
+    if content.encoding.as_deref() != Some("base64") {
+        return Bytes::from(text);
+    }
+
+    match BASE64.decode(text.trim()) {
+        Ok(decoded) => Bytes::from(decoded),
+        Err(_) => {
+            warnings.push(format!(
+                "Invalid base64 response body not imported: {}",
+                url
+            ));
+            Bytes::new()
+        }
+    }
+}
+
+fn import_headers(headers: &[NameValue]) -> HashMap<String, String> {
+    let mut result: HashMap<String, String> = HashMap::new();
+
+    for header in headers.iter().filter(|h| !h.name.starts_with(':')) {
+        result
+            .entry(header.name.to_ascii_lowercase())
+            .and_modify(|value| {
+                value.push_str(", ");
+                value.push_str(&header.value);
+            })
+            .or_insert_with(|| header.value.clone());
+    }
+
+    result
+}
+
+/// Exporta itens do histórico como HAR 1.2, usando a requisição efetivamente enviada.
+///
+/// # Errors
+/// Retorna erro se a serialização falhar.
+pub fn export(items: &[HistoryItem]) -> Result<String, String> {
+    let har = Har {
+        log: Log {
+            version: HAR_VERSION.to_string(),
+            creator: Creator {
+                name: env!("CARGO_PKG_NAME").to_string(),
+                version: env!("CARGO_PKG_VERSION").to_string(),
+            },
+            entries: items.iter().map(export_entry).collect(),
+        },
+    };
+
+    serde_json::to_string_pretty(&har).map_err(|e| format!("Failed to serialize HAR: {}", e))
+}
+
+fn export_entry(item: &HistoryItem) -> Entry {
+    let request = item.resolved_request.as_ref().unwrap_or(&item.request);
+    let request = &auth::apply(&graphql::apply(request).unwrap_or_else(|_| request.clone()));
+    let duration = item
+        .response
+        .as_ref()
+        .map_or(0.0, |response| response.duration_ms as f64);
+
+    Entry {
+        started_date_time: Utc
+            .timestamp_opt(item.timestamp, 0)
+            .single()
+            .unwrap_or_default()
+            .to_rfc3339_opts(SecondsFormat::Millis, true),
+        time: duration,
+        request: export_request(request),
+        response: item
+            .response
+            .as_ref()
+            .map_or_else(cancelled_response, export_response),
+        cache: serde_json::Map::new(),
+        timings: Timings {
+            send: 0.0,
+            wait: duration,
+            receive: 0.0,
+        },
+        comment: item
+            .response
+            .is_none()
+            .then(|| CANCELLED_COMMENT.to_string()),
+    }
+}
+
+fn export_request(request: &HttpRequest) -> Request {
+    let enabled = |items: &[KeyValue]| -> Vec<NameValue> {
+        items
+            .iter()
+            .filter(|item| item.enabled && !item.key.is_empty())
+            .map(|item| NameValue {
+                name: item.key.clone(),
+                value: item.value.clone(),
+            })
+            .collect()
+    };
+
+    let mut headers = enabled(&request.headers);
+    if let Some(content_type) = request.implicit_content_type() {
+        headers.push(NameValue {
+            name: "Content-Type".to_string(),
+            value: content_type.to_string(),
+        });
+    }
+
+    let post_data = request.has_body().then(|| match request.body_type {
+        BodyType::FormData => export_form_data(request),
+        _ => PostData {
+            mime_type: header_value(&headers, "content-type")
+                .unwrap_or(match request.body_type {
+                    BodyType::Json => "application/json",
+                    _ => "text/plain",
+                })
+                .to_string(),
+            text: request.encoded_body(),
+            params: request
+                .url_encoded_fields()
+                .map(|field| Param {
+                    name: field.key.clone(),
+                    value: field.value.clone(),
+                    file_name: None,
+                    content_type: None,
+                })
+                .collect(),
+        },
+    });
+
+    Request {
+        method: request.method.to_string(),
+        url: HttpClient::full_url(request).unwrap_or_else(|_| request.url.clone()),
+        http_version: HTTP_VERSION.to_string(),
+        cookies: Vec::new(),
+        query_string: enabled(&request.query_params),
+        headers,
+        body_size: post_data.as_ref().map_or(0, |data| data.text.len() as i64),
+        post_data,
+        headers_size: -1,
+    }
+}
+
+fn export_response(response: &HttpResponse) -> Response {
+    let mut headers: Vec<NameValue> = response
+        .headers
+        .iter()
+        .map(|(name, value)| NameValue {
+            name: name.clone(),
+            value: value.clone(),
+        })
+        .collect();
+    headers.sort_by(|a, b| a.name.cmp(&b.name));
+
+    // Bodies binários vão em base64, como fazem os navegadores
+    let size = response.raw_body().len() as i64;
+    let (text, encoding) = match &response.body {
+        Some(body) => (body.clone(), None),
+        None => (
+            BASE64.encode(response.raw_body()),
+            Some("base64".to_string()),
+        ),
+    };
+
+    Response {
+        status: i64::from(response.status),
+        status_text: response.status_text.clone(),
+        http_version: HTTP_VERSION.to_string(),
+        cookies: Vec::new(),
+        content: Content {
+            size,
+            mime_type: header_value(&headers, "content-type")
+                .unwrap_or_default()
+                .to_string(),
+            text: Some(text),
+            encoding,
+        },
+        redirect_url: header_value(&headers, "location")
+            .unwrap_or_default()
+            .to_string(),
+        headers,
+        headers_size: -1,
+        body_size: size,
+    }
+}
+
+/// Resposta vazia (status 0), como os navegadores registram requisições abortadas
+fn cancelled_response() -> Response {
+    Response {
+        status: 0,
+        status_text: String::new(),
+        http_version: String::new(),
+        cookies: Vec::new(),
+        headers: Vec::new(),
+        content: Content {
+            size: 0,
+            mime_type: "x-unknown".to_string(),
+            text: None,
+            encoding: None,
+        },
+        redirect_url: String::new(),
+        headers_size: -1,
+        body_size: -1,
+    }
+}
+
+/// Partes do multipart como `params`; arquivos levam o caminho em `fileName`
+fn export_form_data(request: &HttpRequest) -> PostData {
+    let boundary = multipart::boundary(request.form_parts());
+
+    PostData {
+        mime_type: multipart::content_type(&boundary),
+        text: String::new(),
+        params: request
+            .form_parts()
+            .map(|part| Param {
+                name: part.key.clone(),
+                value: match part.kind {
+                    FormPartKind::Text => part.value.clone(),
+                    FormPartKind::File => String::new(),
+                },
+                file_name: (part.kind == FormPartKind::File).then(|| part.value.clone()),
+                content_type: (!part.content_type.is_empty()).then(|| part.content_type.clone()),
+            })
+            .collect(),
+    }
+}
+
+fn header_value<'a>(headers: &'a [NameValue], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|h| h.name.eq_ignore_ascii_case(name))
+        .map(|h| h.value.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BROWSER_HAR: &str = include_str!("../../../tests/fixtures/har/browser.har");
+
+    fn history_item() -> HistoryItem {
+        HistoryItem {
+            request: HttpRequest {
+                method: HTTPMethod::POST,
+                url: "https://api.example.com/users".to_string(),
+                headers: vec![KeyValue::new(
+                    "Content-Type".to_string(),
+                    "application/json".to_string(),
+                )],
+                query_params: vec![KeyValue::new("notify".to_string(), "true".to_string())],
+                body: r#"{"name":"Alice"}"#.to_string(),
+                body_type: BodyType::Json,
+                ..HttpRequest::default()
+            },
+            resolved_request: None,
+            response: Some(HttpResponse::new(
+                201,
+                "Created".to_string(),
+                HashMap::from([
+                    ("content-type".to_string(), "application/json".to_string()),
+                    ("location".to_string(), "/users/1".to_string()),
+                ]),
+                Bytes::from_static(br#"{"id":1}"#),
+                87,
+            )),
+            timestamp: 1_760_000_000,
+            websocket: None,
+        }
+    }
+
+    #[test]
+    fn test_import_browser_har() {
+        let imported = parse(BROWSER_HAR).unwrap();
+        assert_eq!(imported.items.len(), 4);
+
+        let search = &imported.items[0];
+        assert_eq!(search.request.url, "https://shop.example.com/api/search");
+        assert_eq!(
+            search.request.query_params,
+            vec![KeyValue::new("q".to_string(), "red shoes".to_string())]
+        );
+        assert!(
+            search
+                .request
+                .headers
+                .iter()
+                .all(|h| !h.key.starts_with(':') && h.key != "content-length")
+        );
+        let response = search.response.as_ref().unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.duration_ms, 143);
+        assert_eq!(response.text(), r#"{"results":[]}"#);
+        assert_eq!(search.timestamp, 1_760_349_600);
+
+        let login = &imported.items[1];
+        assert_eq!(login.request.method, HTTPMethod::POST);
+        assert_eq!(login.request.body_type, BodyType::FormUrlEncoded);
+        assert_eq!(login.request.encoded_body(), "user=alice&remember=1");
+
+        assert_eq!(
+            login.response.as_ref().unwrap().headers["location"],
+            "/account"
+        );
+
+        // Body binário mantém os bytes; requisição bloqueada (status 0) fica sem resposta
+        let logo = imported.items[2].response.as_ref().unwrap();
+        assert_eq!(logo.body, None);
+        assert!(logo.is_image());
+        assert!(logo.raw_body().starts_with(b"\x89PNG"));
+        assert!(imported.items[3].response.is_none());
+        assert!(imported.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_form_data_round_trip() {
+        let mut file = FormPart::file("doc".to_string(), "/tmp/report.pdf".to_string());
+        file.content_type = "application/pdf".to_string();
+        let mut item = history_item();
+        item.request.headers.clear();
+        item.request.body_type = BodyType::FormData;
+        item.request.form_data = vec![FormPart::text("title".to_string(), "Q3".to_string()), file];
+
+        let exported = export(std::slice::from_ref(&item)).unwrap();
+        assert!(exported.contains(r#""fileName": "/tmp/report.pdf""#));
+
+        let imported = parse(&exported).unwrap();
+        let request = &imported.items[0].request;
+        assert_eq!(request.body_type, BodyType::FormData);
+        assert_eq!(request.form_data, item.request.form_data);
+    }
+
+    #[test]
+    fn test_binary_response_round_trip() {
+        let mut item = history_item();
+        item.response = Some(HttpResponse::new(
+            200,
+            "OK".to_string(),
+            HashMap::from([("content-type".to_string(), "image/png".to_string())]),
+            Bytes::from_static(b"\x89PNG\r\n\x1a\n\0\xff"),
+            12,
+        ));
+
+        let exported = export(std::slice::from_ref(&item)).unwrap();
+        assert!(exported.contains(r#""encoding": "base64""#));
+
+        let imported = parse(&exported).unwrap();
+        let response = imported.items[0].response.as_ref().unwrap();
+        assert_eq!(
+            response.raw_body(),
+            item.response.as_ref().unwrap().raw_body()
+        );
+    }
+
+    #[test]
+    fn test_export_round_trip() {
+        let item = history_item();
+        let exported = export(std::slice::from_ref(&item)).unwrap();
+        assert!(exported.contains(r#""url": "https://api.example.com/users?notify=true""#));
+        assert!(exported.contains(r#""redirectURL": "/users/1""#));
+
+        let imported = parse(&exported).unwrap();
+        let entry = &imported.items[0];
+        assert_eq!(entry.request, item.request);
+        assert_eq!(entry.timestamp, item.timestamp);
+
+        let response = entry.response.as_ref().unwrap();
+        let original = item.response.as_ref().unwrap();
+        assert_eq!(response.status, original.status);
+        assert_eq!(response.body, original.body);
+        assert_eq!(response.headers, original.headers);
+        assert_eq!(response.duration_ms, original.duration_ms);
+    }
+
+    #[test]
+    fn test_export_cancelled_and_resolved() {
+        let mut item = history_item();
+        item.request.url = "{{baseUrl}}/users".to_string();
+        item.resolved_request = Some(history_item().request);
+        item.response = None;
+
+        let exported = export(&[item]).unwrap();
+        assert!(exported.contains("https://api.example.com/users"));
+        assert!(exported.contains(CANCELLED_COMMENT));
+        assert!(parse(&exported).unwrap().items[0].response.is_none());
+    }
+}
@@ -0,0 +1,807 @@
+//! Arquivos `.http` / `.rest` (JetBrains HTTP Client / VS Code REST Client)
+//!
+//! O documento guarda as linhas originais: requisições não editadas são gravadas
+//! exatamente como foram lidas, e as editadas mantêm comentários e variáveis ao redor.
+
+use super::{detect_body_type, import_url_encoded, multipart};
+use crate::components::enums::{Auth, AuthKind, BodyType, HTTPMethod, HttpRequest, KeyValue};
+use crate::components::file_dialog::FileFilter;
+use crate::components::utils::mime;
+use crate::components::{auth, graphql};
+use std::path::PathBuf;
+
+/// Filtro dos diálogos de abrir/salvar
+pub const FILE_FILTER: FileFilter = ("HTTP requests", &["http", "rest"]);
+
+const SEPARATOR: &str = "###";
+
+/// Arquivo `.http` aberto: seções separadas por `###`, na ordem original
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpFile {
+    sections: Vec<Section>,
+    line_ending: &'static str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Section {
+    /// Separador `###`, comentários, declarações `@var` e linhas em branco antes da requisição
+    preamble: Vec<String>,
+    request: Option<FileRequest>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FileRequest {
+    /// Requisição como lida do arquivo, para detectar edições
+    parsed: HttpRequest,
+    request: HttpRequest,
+    /// Sufixo `HTTP/1.1` da linha da requisição
+    http_version: Option<String>,
+    /// Comentários entre os headers, com o número de headers que os precedem
+    comments: Vec<(usize, String)>,
+    /// Handlers de resposta (`> {% ... %}`) e redirecionamentos (`>> arquivo`)
+    trailer: Vec<String>,
+    /// Linhas originais da requisição, reutilizadas enquanto não houver edição
+    original: Vec<String>,
+    /// Linhas em branco após a requisição, antes do próximo separador
+    blank_lines: usize,
+}
+
+/// Arquivo aberto no painel lateral, com o caminho para salvar de volta
+#[derive(Debug, Clone)]
+pub struct OpenedHttpFile {
+    pub path: PathBuf,
+    pub file: HttpFile,
+}
+
+impl OpenedHttpFile {
+    pub fn file_name(&self) -> String {
+        self.path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| self.path.display().to_string())
+    }
+}
+
+/// Requisição do arquivo exibida como entrada executável
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpFileEntry {
+    pub name: String,
+    pub request: HttpRequest,
+}
+
+impl HttpFile {
+    pub fn parse(text: &str) -> Self {
+        let line_ending = if text.contains("\r\n") { "\r\n" } else { "\n" };
+        let mut sections = Vec::new();
+        let mut current: Vec<&str> = Vec::new();
+
+        for line in text.lines() {
+            if line.starts_with(SEPARATOR) && !current.is_empty() {
+                sections.push(parse_section(&current));
+                current.clear();
+            }
+            current.push(line);
+        }
+
+        if !current.is_empty() {
+            sections.push(parse_section(&current));
+        }
+
+        Self {
+            sections,
+            line_ending,
+        }
+    }
+
+    /// Requisições do arquivo, na ordem em que aparecem
+    pub fn entries(&self) -> Vec<HttpFileEntry> {
+        self.sections
+            .iter()
+            .filter_map(|section| {
+                let file_request = section.request.as_ref()?;
+                let request = file_request.request.clone();
+                let name = section
+                    .name()
+                    .unwrap_or_else(|| format!("{} {}", request.method, request.url));
+
+                Some(HttpFileEntry { name, request })
+            })
+            .collect()
+    }
+
+    /// Declarações `@nome = valor`, na ordem do arquivo
+    pub fn variables(&self) -> Vec<KeyValue> {
+        self.sections
+            .iter()
+            .flat_map(|section| &section.preamble)
+            .filter_map(|line| parse_variable(line))
+            .collect()
+    }
+
+    /// Substitui a requisição de índice `index` (na ordem de `entries`)
+    pub fn update_request(&mut self, index: usize, request: HttpRequest) -> bool {
+        match self
+            .sections
+            .iter_mut()
+            .filter_map(|section| section.request.as_mut())
+            .nth(index)
+        {
+            Some(file_request) => {
+                file_request.request = request;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Acrescenta uma nova requisição ao final do arquivo, sob `### nome`
+    pub fn push_request(&mut self, name: &str, request: HttpRequest) {
+        if let Some(last) = self
+            .sections
+            .last_mut()
+            .and_then(|section| section.request.as_mut())
+            && last.blank_lines == 0
+        {
+            last.blank_lines = 1;
+        }
+
+        let mut file_request = FileRequest {
+            parsed: request.clone(),
+            request,
+            http_version: None,
+            comments: Vec::new(),
+            trailer: Vec::new(),
+            original: Vec::new(),
+            blank_lines: 0,
+        };
+        file_request.original = file_request.render();
+
+        self.sections.push(Section {
+            preamble: vec![format!("{} {}", SEPARATOR, name)],
+            request: Some(file_request),
+        });
+    }
+
+    pub fn len(&self) -> usize {
+        self.sections.iter().filter(|s| s.request.is_some()).count()
+    }
+}
+
+impl std::fmt::Display for HttpFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut lines: Vec<String> = Vec::new();
+
+        for section in &self.sections {
+            lines.extend(section.preamble.iter().cloned());
+
+            if let Some(file_request) = &section.request {
+                if file_request.request == file_request.parsed {
+                    lines.extend(file_request.original.iter().cloned());
+                } else {
+                    lines.extend(file_request.render());
+                }
+                lines.extend(std::iter::repeat_n(String::new(), file_request.blank_lines));
+            }
+        }
+
+        for line in lines {
+            write!(f, "{}{}", line, self.line_ending)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Section {
+    /// Nome vindo de `### nome` ou de `# @name nome`
+    fn name(&self) -> Option<String> {
+        self.preamble.iter().find_map(|line| {
+            let name = line
+                .strip_prefix(SEPARATOR)
+                .or_else(|| comment_text(line)?.trim().strip_prefix("@name"))?
+                .trim()
+                .trim_start_matches('=')
+                .trim();
+
+            (!name.is_empty()).then(|| name.to_string())
+        })
+    }
+}
+
+/// Esquema da aba Auth que o formato não guarda: só Basic tem forma em `.http`,
+/// e segredos (tokens, chaves, credenciais) não são gravados no arquivo
+pub fn unsaved_auth(auth: &Auth) -> Option<AuthKind> {
+    match auth {
+        Auth::None | Auth::Basic { .. } => None,
+        _ => Some(auth.kind()),
+    }
+}
+
+impl FileRequest {
+    fn render(&self) -> Vec<String> {
+        // GraphQL vira o body JSON padrão (ou query params, no GET)
+        let mut request = graphql::apply(&self.request).unwrap_or_else(|_| self.request.clone());
+        // Basic usa a forma do REST Client, que codifica usuário e senha no envio
+        if let Auth::Basic { username, password } = &request.auth {
+            let value = format!("Basic {} {}", username, password);
+            auth::set_header(&mut request, "Authorization", value);
+        }
+        let request = &request;
+        let params: Vec<&KeyValue> = request
+            .query_params
+            .iter()
+            .filter(|p| !p.key.is_empty())
+            .collect();
+        // Com parâmetros desativados, a query vai em linhas indentadas para comentá-los
+        let multiline = params.iter().any(|p| !p.enabled);
+
+        let mut url = request.url.clone();
+        let mut query_lines = Vec::new();
+        let mut separator = '?';
+        for param in params {
+            let pair = format!("{}={}", param.key, param.value);
+            if !param.enabled {
+                query_lines.push(format!("    # &{}", pair));
+            } else if multiline {
+                query_lines.push(format!("    {}{}", separator, pair));
+                separator = '&';
+            } else {
+                url = format!("{}{}{}", url, separator, pair);
+                separator = '&';
+            }
+        }
+
+        let mut lines = vec![match &self.http_version {
+            Some(version) => format!("{} {} {}", request.method, url, version),
+            None => format!("{} {}", request.method, url),
+        }];
+        lines.extend(query_lines);
+
+        let form_boundary = (request.has_body() && request.body_type == BodyType::FormData)
+            .then(|| multipart::boundary(request.form_parts()));
+
+        let comments_at = |position: usize| {
+            self.comments
+                .iter()
+                .filter(move |(at, _)| *at == position)
+                .map(|(_, comment)| comment.clone())
+        };
+        for (position, header) in request.headers.iter().enumerate() {
+            lines.extend(comments_at(position));
+            if header.key.is_empty()
+                || (form_boundary.is_some() && header.key.eq_ignore_ascii_case("content-type"))
+            {
+                continue;
+            }
+            lines.push(match header.enabled {
+                true => format!("{}: {}", header.key, header.value),
+                false => format!("# {}: {}", header.key, header.value),
+            });
+        }
+        lines.extend(
+            self.comments
+                .iter()
+                .filter(|(at, _)| *at >= request.headers.len())
+                .map(|(_, comment)| comment.clone()),
+        );
+        if let Some(content_type) = request
+            .implicit_content_type()
+            .filter(|_| form_boundary.is_none())
+        {
+            lines.push(format!("Content-Type: {}", content_type));
+        }
+
+        if let Some(boundary) = &form_boundary {
+            lines.push(format!(
+                "Content-Type: {}",
+                multipart::content_type(boundary)
+            ));
+            lines.push(String::new());
+            lines.extend(
+                multipart::render(request.form_parts(), boundary)
+                    .lines()
+                    .map(str::to_string),
+            );
+        } else if request.has_body() && request.body_type == BodyType::Binary {
+            lines.push(String::new());
+            lines.push(format!("< {}", request.binary_file.trim()));
+        } else if request.has_body() {
+            lines.push(String::new());
+            lines.extend(request.encoded_body().lines().map(str::to_string));
+        }
+
+        if !self.trailer.is_empty() {
+            lines.push(String::new());
+            lines.extend(self.trailer.iter().cloned());
+        }
+
+        lines
+    }
+}
+
+/// `Authorization: Basic usuário senha` (forma do REST Client) vira a aba Auth
+fn parse_basic_auth(request: &mut HttpRequest) {
+    let Some(index) = request
+        .headers
+        .iter()
+        .position(|h| h.enabled && h.key.eq_ignore_ascii_case("authorization"))
+    else {
+        return;
+    };
+
+    let credentials = request.headers[index].value.strip_prefix("Basic ");
+    if let Some((username, password)) = credentials.and_then(|c| c.trim().split_once(' ')) {
+        request.auth = Auth::Basic {
+            username: username.to_string(),
+            password: password.trim().to_string(),
+        };
+        request.headers.remove(index);
+    }
+}
+
+/// Body multipart com partes `< arquivo` vira form-data editável
+fn parse_form_data(request: &mut HttpRequest) {
+    let Some(boundary) = request
+        .headers
+        .iter()
+        .find(|h| h.enabled && h.key.eq_ignore_ascii_case("content-type"))
+        .and_then(|h| multipart::boundary_from_content_type(&h.value))
+    else {
+        return;
+    };
+
+    if let Some(parts) = multipart::parse(&request.body, &boundary) {
+        request
+            .headers
+            .retain(|h| !(h.enabled && h.key.eq_ignore_ascii_case("content-type")));
+        request.body.clear();
+        request.body_type = BodyType::FormData;
+        request.form_data = parts;
+    }
+}
+
+/// Body formado só por `< caminho` envia o arquivo como body binário
+fn parse_binary_file(request: &mut HttpRequest) {
+    let Some(path) = request
+        .body
+        .strip_prefix("< ")
+        .map(str::trim)
+        .filter(|path| !path.is_empty() && !path.contains('\n'))
+    else {
+        return;
+    };
+
+    let implicit = mime::from_path(path);
+    request.binary_file = path.to_string();
+    request.body.clear();
+    request.body_type = BodyType::Binary;
+    request.headers.retain(|h| {
+        !(h.enabled && h.key.eq_ignore_ascii_case("content-type") && h.value == implicit)
+    });
+}
+
+fn parse_section(lines: &[&str]) -> Section {
+    let request_start = lines.iter().position(|line| {
+        let trimmed = line.trim();
+        !trimmed.is_empty()
+            && !line.starts_with(SEPARATOR)
+            && comment_text(line).is_none()
+            && parse_variable(line).is_none()
+    });
+
+    let Some(start) = request_start else {
+        return Section {
+            preamble: lines.iter().map(|l| l.to_string()).collect(),
+            request: None,
+        };
+    };
+
+    Section {
+        preamble: lines[..start].iter().map(|l| l.to_string()).collect(),
+        request: Some(parse_request(&lines[start..])),
+    }
+}
+
+fn parse_request(lines: &[&str]) -> FileRequest {
+    let content_end = lines
+        .iter()
+        .rposition(|line| !line.trim().is_empty())
+        .map_or(0, |i| i + 1);
+    let blank_lines = lines.len() - content_end;
+    let lines = &lines[..content_end];
+
+    let (method, url, http_version) = parse_request_line(lines[0]);
+    let (base, query) = url.split_once('?').unwrap_or((&url, ""));
+    let mut query_params: Vec<KeyValue> = query
+        .split('&')
+        .filter_map(|pair| parse_query_pair(pair, true))
+        .collect();
+
+    let mut index = 1;
+    // Continuação da query em linhas indentadas: `    ?page=1` / `    &limit=10`,
+    // com `    # &debug=1` para parâmetros desativados
+    while let Some(line) = lines.get(index)
+        && line.starts_with(char::is_whitespace)
+    {
+        let (pair, enabled) = match comment_text(line) {
+            Some(text) => (text.trim(), false),
+            None => (line.trim(), true),
+        };
+        let Some(pair) = pair.strip_prefix(['?', '&']) else {
+            break;
+        };
+        query_params.extend(parse_query_pair(pair, enabled));
+        index += 1;
+    }
+
+    let mut headers = Vec::new();
+    let mut comments = Vec::new();
+    while let Some(line) = lines.get(index)
+        && !line.trim().is_empty()
+    {
+        if let Some(header) = parse_disabled_header(line) {
+            headers.push(header);
+        } else if comment_text(line).is_some() {
+            comments.push((headers.len(), line.to_string()));
+        } else if let Some((key, value)) = line.split_once(':') {
+            headers.push(KeyValue::new(
+                key.trim().to_string(),
+                value.trim().to_string(),
+            ));
+        }
+        index += 1;
+    }
+
+    let rest = lines.get(index + 1..).unwrap_or_default();
+    let trailer_start = rest
+        .iter()
+        .position(|line| {
+            line.starts_with("> ") || line.starts_with(">> ") || line.starts_with("<> ")
+        })
+        .unwrap_or(rest.len());
+    let body_lines = &rest[..trailer_start];
+    let body_end = body_lines
+        .iter()
+        .rposition(|line| !line.trim().is_empty())
+        .map_or(0, |i| i + 1);
+    let body = body_lines[..body_end].join("\n");
+
+    let mut request = HttpRequest {
+        method,
+        url: base.to_string(),
+        query_params,
+        body_type: detect_body_type(&headers, &body),
+        headers,
+        ..HttpRequest::default()
+    };
+    request.force_body = !body.is_empty() && request.method.can_force_body();
+    request.body = body;
+
+    let parsed_headers = request.headers.clone();
+    parse_basic_auth(&mut request);
+    parse_form_data(&mut request);
+    parse_binary_file(&mut request);
+    import_url_encoded(&mut request);
+    shift_comments(&mut comments, &parsed_headers, &request.headers);
+
+    FileRequest {
+        parsed: request.clone(),
+        request,
+        http_version,
+        comments,
+        trailer: rest[trailer_start..]
+            .iter()
+            .map(|l| l.to_string())
+            .collect(),
+        original: lines.iter().map(|l| l.to_string()).collect(),
+        blank_lines,
+    }
+}
+
+/// Ajusta a posição dos comentários aos headers que viraram campos (auth, form-data, binário)
+fn shift_comments(comments: &mut [(usize, String)], before: &[KeyValue], after: &[KeyValue]) {
+    // Headers de `after` antes de cada header de `before`, na ordem
+    let mut kept = Vec::with_capacity(before.len() + 1);
+    let mut index = 0;
+    for header in before {
+        kept.push(index);
+        if after.get(index) == Some(header) {
+            index += 1;
+        }
+    }
+    kept.push(index);
+
+    for (position, _) in comments {
+        *position = kept[*position];
+    }
+}
+
+/// Par `chave=valor` da query; vazio é ignorado
+fn parse_query_pair(pair: &str, enabled: bool) -> Option<KeyValue> {
+    if pair.is_empty() {
+        return None;
+    }
+
+    let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+    let mut param = KeyValue::new(key.to_string(), value.to_string());
+    param.enabled = enabled;
+    Some(param)
+}
+
+/// Header comentado (`# Nome: valor`) entre os headers é uma linha desativada
+fn parse_disabled_header(line: &str) -> Option<KeyValue> {
+    let (key, value) = line.trim_start().strip_prefix("# ")?.split_once(':')?;
+    let is_token = !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(c));
+    if !is_token {
+        return None;
+    }
+
+    let mut header = KeyValue::new(key.to_string(), value.trim().to_string());
+    header.enabled = false;
+    Some(header)
+}
+
+/// `METHOD URL [HTTP/versão]`; sem método, a requisição é um GET
+fn parse_request_line(line: &str) -> (HTTPMethod, String, Option<String>) {
+    let mut parts: Vec<&str> = line.split_whitespace().collect();
+
+    let http_version = parts
+        .last()
+        .filter(|last| parts.len() > 1 && last.starts_with("HTTP/"))
+        .map(|version| version.to_string());
+    if http_version.is_some() {
+        parts.pop();
+    }
+
+    let method = match parts.first() {
+        Some(first) if parts.len() > 1 => HTTPMethod::from_input(first),
+        _ => None,
+    };
+
+    match method {
+        Some(method) => (method, parts[1..].join(" "), http_version),
+        None => (HTTPMethod::GET, parts.join(" "), http_version),
+    }
+}
+
+/// Texto de um comentário `#` ou `//` (exceto o separador `###`)
+fn comment_text(line: &str) -> Option<&str> {
+    let trimmed = line.trim_start();
+    if trimmed.starts_with(SEPARATOR) {
+        return None;
+    }
+    trimmed
+        .strip_prefix('#')
+        .or_else(|| trimmed.strip_prefix("//"))
+}
+
+/// Declaração `@nome = valor`
+fn parse_variable(line: &str) -> Option<KeyValue> {
+    let (name, value) = line.trim().strip_prefix('@')?.split_once('=')?;
+    let name = name.trim();
+
+    (!name.is_empty() && !name.contains(char::is_whitespace))
+        .then(|| KeyValue::new(name.to_string(), value.trim().to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::enums::FormPart;
+
+    const FIXTURE: &str = include_str!("../../../tests/fixtures/http_file/users.http");
+
+    #[test]
+    fn test_parse_entries_and_variables() {
+        let file = HttpFile::parse(FIXTURE);
+
+        let variables = file.variables();
+        assert_eq!(
+            variables[0],
+            KeyValue::new("host".into(), "https://api.example.com".into())
+        );
+        assert_eq!(variables[1].key, "token");
+
+        let entries = file.entries();
+        assert_eq!(entries.len(), 4);
+        assert_eq!(entries[0].name, "List users");
+        assert_eq!(entries[0].request.url, "{{host}}/users");
+        assert_eq!(
+            entries[0].request.query_params,
+            vec![
+                KeyValue::new("page".into(), "1".into()),
+                KeyValue::new("limit".into(), "20".into())
+            ]
+        );
+
+        let create = &entries[1].request;
+        assert_eq!(create.method, HTTPMethod::POST);
+        assert_eq!(create.body_type, BodyType::Json);
+        assert_eq!(create.body, "{\n  \"name\": \"Alice\"\n}");
+        assert_eq!(create.headers.len(), 2);
+
+        assert_eq!(entries[2].name, "deleteUser");
+        assert_eq!(entries[3].name, "GET https://example.com/health");
+    }
+
+    #[test]
+    fn test_unchanged_file_is_written_verbatim() {
+        assert_eq!(HttpFile::parse(FIXTURE).to_string(), FIXTURE);
+    }
+
+    #[test]
+    fn test_edit_keeps_comments_and_order() {
+        let mut file = HttpFile::parse(FIXTURE);
+        let mut request = file.entries()[1].request.clone();
+        request.body = "{\"name\": \"Bob\"}".to_string();
+        request
+            .headers
+            .push(KeyValue::new("X-Trace".into(), "1".into()));
+        assert!(file.update_request(1, request));
+
+        let written = file.to_string();
+        let expected = FIXTURE.replace(
+            "Authorization: Bearer {{token}}\n\n{\n  \"name\": \"Alice\"\n}\n",
+            "Authorization: Bearer {{token}}\nX-Trace: 1\n\n{\"name\": \"Bob\"}\n",
+        );
+        assert_eq!(written, expected);
+
+        let reparsed = HttpFile::parse(&written);
+        assert_eq!(reparsed.entries()[1].request.body, "{\"name\": \"Bob\"}");
+    }
+
+    #[test]
+    fn test_edit_keeps_comment_positions_and_disabled_rows() {
+        let text = "GET https://example.com/items\n    ?page=1\n    # &debug=1\nAccept: application/json\n# Tracing headers\n# X-Trace: off\nX-Env: dev\n";
+        let mut file = HttpFile::parse(text);
+        let mut request = file.entries()[0].request.clone();
+        assert!(!request.query_params[1].enabled);
+        assert_eq!(request.headers.len(), 3);
+        assert!(!request.headers[1].enabled);
+
+        request.headers[2].value = "prod".to_string();
+        request.query_params[0].enabled = false;
+        request
+            .query_params
+            .push(KeyValue::new("limit".into(), "5".into()));
+        assert!(file.update_request(0, request.clone()));
+
+        let written = file.to_string();
+        assert_eq!(
+            written,
+            "GET https://example.com/items\n    # &page=1\n    # &debug=1\n    ?limit=5\nAccept: application/json\n# Tracing headers\n# X-Trace: off\nX-Env: prod\n"
+        );
+        assert_eq!(HttpFile::parse(&written).entries()[0].request, request);
+    }
+
+    #[test]
+    fn test_push_request() {
+        let mut file = HttpFile::parse("GET https://example.com\n");
+        file.push_request(
+            "Ping",
+            HttpRequest {
+                url: "https://example.com/ping".to_string(),
+                headers: Vec::new(),
+                ..HttpRequest::default()
+            },
+        );
+
+        assert_eq!(
+            file.to_string(),
+            "GET https://example.com\n\n### Ping\nGET https://example.com/ping\n"
+        );
+        assert_eq!(file.len(), 2);
+    }
+
+    #[test]
+    fn test_only_basic_auth_is_written() {
+        let mut file = HttpFile::parse("GET https://example.com\n");
+        let basic = HttpRequest {
+            url: "https://example.com/me".to_string(),
+            headers: Vec::new(),
+            auth: Auth::Basic {
+                username: "{{user}}".to_string(),
+                password: "{{pass}}".to_string(),
+            },
+            ..HttpRequest::default()
+        };
+        let bearer = HttpRequest {
+            auth: Auth::Bearer {
+                token: "s3cr3t".to_string(),
+            },
+            ..basic.clone()
+        };
+        file.push_request("Basic", basic.clone());
+        file.push_request("Bearer", bearer.clone());
+
+        let written = file.to_string();
+        assert!(
+            written
+                .contains("GET https://example.com/me\nAuthorization: Basic {{user}} {{pass}}\n")
+        );
+        assert!(!written.contains("s3cr3t"));
+        assert_eq!(unsaved_auth(&basic.auth), None);
+        assert_eq!(unsaved_auth(&bearer.auth), Some(AuthKind::Bearer));
+
+        let reparsed = HttpFile::parse(&written);
+        assert_eq!(reparsed.entries()[1].request, basic);
+        assert_eq!(reparsed.entries()[2].request.auth, Auth::None);
+    }
+
+    #[test]
+    fn test_form_data_round_trip() {
+        let mut file = HttpFile::parse("GET https://example.com\n");
+        let request = HttpRequest {
+            method: HTTPMethod::POST,
+            url: "https://example.com/upload".to_string(),
+            body_type: BodyType::FormData,
+            form_data: vec![
+                FormPart::text("title".into(), "Q3".into()),
+                FormPart::file("doc".into(), "./report.pdf".into()),
+            ],
+            ..HttpRequest::default()
+        };
+        file.push_request("Upload", request.clone());
+
+        let written = file.to_string();
+        assert!(written.contains("filename=\"report.pdf\"\n\n< ./report.pdf\n"));
+        assert!(!written.contains("application/json"));
+
+        let reparsed = HttpFile::parse(&written);
+        let upload = &reparsed.entries()[1].request;
+        assert_eq!(upload.body_type, BodyType::FormData);
+        assert_eq!(upload.form_data, request.form_data);
+        assert!(upload.headers.is_empty());
+    }
+
+    #[test]
+    fn test_binary_file_round_trip() {
+        let mut file = HttpFile::parse("GET https://example.com\n");
+        let request = HttpRequest {
+            method: HTTPMethod::PUT,
+            url: "https://example.com/blob".to_string(),
+            headers: Vec::new(),
+            body_type: BodyType::Binary,
+            binary_file: "./logo.png".to_string(),
+            ..HttpRequest::default()
+        };
+        file.push_request("Upload", request.clone());
+
+        let written = file.to_string();
+        assert!(written.contains("Content-Type: image/png\n\n< ./logo.png"));
+
+        let reparsed = HttpFile::parse(&written);
+        assert_eq!(reparsed.entries()[1].request, request);
+    }
+
+    #[test]
+    fn test_form_url_encoded_round_trip() {
+        let mut file = HttpFile::parse("GET https://example.com\n");
+        let request = HttpRequest {
+            method: HTTPMethod::POST,
+            url: "https://example.com/login".to_string(),
+            headers: Vec::new(),
+            body_type: BodyType::FormUrlEncoded,
+            form_urlencoded: vec![
+                KeyValue::new("user".into(), "Ana Souza".into()),
+                KeyValue::new("next".into(), "/a?b=c".into()),
+            ],
+            ..HttpRequest::default()
+        };
+        file.push_request("Login", request.clone());
+
+        let written = file.to_string();
+        assert!(written.contains(
+            "Content-Type: application/x-www-form-urlencoded\n\nuser=Ana+Souza&next=%2Fa%3Fb%3Dc"
+        ));
+
+        let reparsed = HttpFile::parse(&written);
+        let login = &reparsed.entries()[1].request;
+        assert_eq!(login.body_type, BodyType::FormUrlEncoded);
+        assert_eq!(login.form_urlencoded, request.form_urlencoded);
+        assert!(login.headers.is_empty());
+    }
+}
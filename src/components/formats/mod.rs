@@ -0,0 +1,101 @@
+//! Importação e exportação de requisições em formatos de outras ferramentas
+
+pub mod codegen;
+pub mod curl;
+pub mod har;
+pub mod http_file;
+pub mod multipart;
+pub mod openapi;
+pub mod postman;
+
+use super::enums::{BodyType, HttpRequest, KeyValue};
+use super::utils::{json_formatter, mime};
+use url::Url;
+
+/// Resultado de uma importação: a requisição e avisos sobre o que foi ignorado
+#[derive(Debug, Clone)]
+pub struct ImportedRequest {
+    pub request: HttpRequest,
+    pub warnings: Vec<String>,
+}
+
+/// Move a query string da URL para a lista de query params
+pub fn split_query_params(request: &mut HttpRequest) {
+    let Ok(mut url) = Url::parse(&request.url) else {
+        return;
+    };
+
+    if url.query().is_none() {
+        return;
+    }
+
+    request.query_params.extend(
+        url.query_pairs()
+            .map(|(k, v)| KeyValue::new(k.into_owned(), v.into_owned())),
+    );
+    url.set_query(None);
+    request.url = url.to_string();
+}
+
+/// Escolhe o tipo de body a partir do Content-Type e do conteúdo
+pub fn detect_body_type(headers: &[KeyValue], body: &str) -> BodyType {
+    if body.is_empty() {
+        return BodyType::None;
+    }
+
+    let content_type = headers
+        .iter()
+        .find(|h| h.enabled && h.key.eq_ignore_ascii_case("content-type"))
+        .map(|h| mime::essence(&h.value))
+        .unwrap_or_default();
+
+    if content_type.contains("json") || json_formatter::is_valid_json(body) {
+        BodyType::Json
+    } else if content_type.ends_with("xml") || body.trim_start().starts_with("<?xml") {
+        BodyType::Xml
+    } else if content_type.ends_with("yaml") {
+        BodyType::Yaml
+    } else if content_type == "text/html" {
+        BodyType::Html
+    } else {
+        BodyType::Raw
+    }
+}
+
+/// Converte um body `application/x-www-form-urlencoded` em pares editáveis.
+///
+/// Só converte se os pares, codificados de novo, reproduzem o body exatamente;
+/// caso contrário o body continua como texto. Um `Content-Type` igual ao implícito
+/// é removido, já que volta a ser gerado no envio.
+pub fn import_url_encoded(request: &mut HttpRequest) -> bool {
+    let is_form = request.headers.iter().any(|h| {
+        h.enabled
+            && h.key.eq_ignore_ascii_case("content-type")
+            && h.value
+                .to_ascii_lowercase()
+                .starts_with("application/x-www-form-urlencoded")
+    });
+    if !is_form || request.body.is_empty() {
+        return false;
+    }
+
+    let candidate = HttpRequest {
+        body_type: BodyType::FormUrlEncoded,
+        form_urlencoded: url::form_urlencoded::parse(request.body.as_bytes())
+            .map(|(k, v)| KeyValue::new(k.into_owned(), v.into_owned()))
+            .collect(),
+        ..HttpRequest::default()
+    };
+    if candidate.encoded_body() != request.body {
+        return false;
+    }
+
+    request.body.clear();
+    request.body_type = BodyType::FormUrlEncoded;
+    request.form_urlencoded = candidate.form_urlencoded;
+    request.headers.retain(|h| {
+        !(h.key.eq_ignore_ascii_case("content-type")
+            && h.value == "application/x-www-form-urlencoded")
+    });
+    true
+}
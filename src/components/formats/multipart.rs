@@ -0,0 +1,187 @@
+//! Representação textual de bodies multipart/form-data (exportação e arquivos `.http`)
+
+use crate::components::enums::{FormPart, FormPartKind};
+use sha2::{Digest, Sha256};
+
+const BOUNDARY_PREFIX: &str = "----HttpClientFormBoundary";
+
+/// Boundary estável para as partes: o mesmo form gera sempre o mesmo texto exportado
+pub fn boundary<'a>(parts: impl IntoIterator<Item = &'a FormPart>) -> String {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        for field in [
+            &part.key,
+            &part.kind.to_string(),
+            &part.value,
+            &part.content_type,
+            &part.file_name,
+        ] {
+            hasher.update(field.as_bytes());
+            hasher.update([0]);
+        }
+    }
+
+    let digest = hasher.finalize();
+    let suffix: String = digest[..8].iter().map(|b| format!("{:02x}", b)).collect();
+    format!("{}{}", BOUNDARY_PREFIX, suffix)
+}
+
+/// Valor do header `Content-Type` para o boundary
+pub fn content_type(boundary: &str) -> String {
+    format!("multipart/form-data; boundary={}", boundary)
+}
+
+/// Boundary declarado em um `Content-Type: multipart/form-data`
+pub fn boundary_from_content_type(value: &str) -> Option<String> {
+    let (media_type, params) = value.split_once(';')?;
+    if !media_type
+        .trim()
+        .eq_ignore_ascii_case("multipart/form-data")
+    {
+        return None;
+    }
+
+    params.split(';').find_map(|param| {
+        let (key, value) = param.split_once('=')?;
+        key.trim()
+            .eq_ignore_ascii_case("boundary")
+            .then(|| value.trim().trim_matches('"').to_string())
+    })
+}
+
+/// Body no formato dos arquivos `.http`: arquivos são referenciados com `< caminho`
+pub fn render<'a>(parts: impl IntoIterator<Item = &'a FormPart>, boundary: &str) -> String {
+    let mut body = String::new();
+
+    for part in parts {
+        body.push_str(&format!("--{}\n", boundary));
+        match part.kind {
+            FormPartKind::Text => {
+                body.push_str(&format!(
+                    "Content-Disposition: form-data; name=\"{}\"\n",
+                    part.key
+                ));
+            }
+            FormPartKind::File => {
+                body.push_str(&format!(
+                    "Content-Disposition: form-data; name=\"{}\"; filename=\"{}\"\n",
+                    part.key,
+                    part.upload_file_name()
+                ));
+            }
+        }
+        if !part.content_type.is_empty() {
+            body.push_str(&format!("Content-Type: {}\n", part.content_type));
+        }
+        body.push('\n');
+        match part.kind {
+            FormPartKind::Text => body.push_str(&part.value),
+            FormPartKind::File => body.push_str(&format!("< {}", part.value)),
+        }
+        body.push('\n');
+    }
+
+    body.push_str(&format!("--{}--", boundary));
+    body
+}
+
+/// Interpreta um body gerado por `render` (ou escrito à mão no mesmo formato).
+///
+/// Retorna `None` se o texto não for um multipart com o boundary informado.
+pub fn parse(body: &str, boundary: &str) -> Option<Vec<FormPart>> {
+    let delimiter = format!("--{}", boundary);
+    let mut sections: Vec<Vec<&str>> = Vec::new();
+    let mut closed = false;
+
+    for line in body.lines().map(|line| line.trim_end_matches('\r')) {
+        if line == delimiter {
+            sections.push(Vec::new());
+        } else if line == format!("{}--", delimiter) {
+            closed = true;
+            break;
+        } else if let Some(section) = sections.last_mut() {
+            section.push(line);
+        }
+    }
+
+    if !closed {
+        return None;
+    }
+
+    sections.iter().map(|lines| parse_part(lines)).collect()
+}
+
+fn parse_part(lines: &[&str]) -> Option<FormPart> {
+    let blank = lines.iter().position(|line| line.is_empty())?;
+    let mut part = FormPart::default();
+    let mut file_name = None;
+
+    for header in &lines[..blank] {
+        let (name, value) = header.split_once(':')?;
+        if name.trim().eq_ignore_ascii_case("content-disposition") {
+            part.key = disposition_param(value, "name")?;
+            file_name = disposition_param(value, "filename");
+        } else if name.trim().eq_ignore_ascii_case("content-type") {
+            part.content_type = value.trim().to_string();
+        }
+    }
+
+    let content = lines[blank + 1..].join("\n");
+    match (content.strip_prefix("< "), file_name) {
+        (Some(path), file_name) => {
+            part.kind = FormPartKind::File;
+            part.value = path.trim().to_string();
+            if let Some(file_name) = file_name
+                && file_name != part.upload_file_name()
+            {
+                part.file_name = file_name;
+            }
+        }
+        (None, _) => part.value = content,
+    }
+
+    Some(part)
+}
+
+/// Parâmetro `chave="valor"` de um `Content-Disposition`
+fn disposition_param(header: &str, key: &str) -> Option<String> {
+    header.split(';').skip(1).find_map(|param| {
+        let (name, value) = param.split_once('=')?;
+        name.trim()
+            .eq_ignore_ascii_case(key)
+            .then(|| value.trim().trim_matches('"').to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_and_parse_round_trip() {
+        let mut file = FormPart::file("upload".into(), "/tmp/report.pdf".into());
+        file.content_type = "application/pdf".into();
+        file.file_name = "Q3 report.pdf".into();
+        let parts = vec![FormPart::text("title".into(), "Report".into()), file];
+
+        let boundary = boundary(&parts);
+        assert_eq!(boundary, super::boundary(&parts));
+        assert!(boundary.starts_with(BOUNDARY_PREFIX));
+
+        let body = render(&parts, &boundary);
+        assert!(body.contains(
+            "filename=\"Q3 report.pdf\"\nContent-Type: application/pdf\n\n< /tmp/report.pdf\n"
+        ));
+        assert_eq!(parse(&body, &boundary), Some(parts));
+    }
+
+    #[test]
+    fn test_boundary_from_content_type() {
+        assert_eq!(
+            boundary_from_content_type("multipart/form-data; boundary=\"abc\""),
+            Some("abc".to_string())
+        );
+        assert_eq!(boundary_from_content_type("application/json"), None);
+        assert_eq!(parse("--abc\nno end", "abc"), None);
+    }
+}
@@ -0,0 +1,716 @@
+//! Geração de coleções a partir de especificações OpenAPI 3 e Swagger 2 (YAML ou JSON)
+
+use crate::components::collections::CollectionNode;
+use crate::components::enums::{BodyType, HTTPMethod, HttpRequest, KeyValue};
+use crate::components::environment::Environment;
+use crate::components::file_dialog::FileFilter;
+use serde_json::{Map, Value, json};
+
+/// Filtro do diálogo de importação
+pub const FILE_FILTER: FileFilter = ("OpenAPI / Swagger spec", &["yaml", "yml", "json"]);
+
+/// Variável de ambiente com a URL base de cada servidor
+const BASE_URL_VARIABLE: &str = "baseUrl";
+
+const METHODS: [&str; 8] = [
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+/// Profundidade máxima ao sintetizar exemplos de schemas aninhados
+const MAX_SCHEMA_DEPTH: usize = 8;
+
+/// Resultado da importação de uma especificação
+#[derive(Debug, Clone)]
+pub struct OpenApiImport {
+    /// Pasta com o título da API, com uma subpasta por tag
+    pub collection: CollectionNode,
+    /// Um ambiente por servidor, com a variável `baseUrl`
+    pub environments: Vec<Environment>,
+    pub request_count: usize,
+    pub warnings: Vec<String>,
+}
+
+impl OpenApiImport {
+    /// Texto exibido ao usuário ao final da importação
+    pub fn summary(&self) -> String {
+        let mut lines = vec![format!(
+            "Generated {} requests from '{}'",
+            self.request_count,
+            self.collection.name()
+        )];
+
+        match self.environments.len() {
+            0 => {}
+            1 => lines.push(format!(
+                "Server saved as environment '{}'",
+                self.environments[0].name
+            )),
+            count => lines.push(format!(
+                "{} servers saved as environments; pick one in the environment selector",
+                count
+            )),
+        }
+
+        lines.extend(self.warnings.iter().map(|w| format!("• {}", w)));
+        lines.join("\n")
+    }
+}
+
+/// Importa uma especificação OpenAPI 3.x ou Swagger 2.0.
+///
+/// # Errors
+/// Retorna erro se o arquivo não for YAML/JSON válido ou não for uma especificação suportada.
+pub fn parse(text: &str) -> Result<OpenApiImport, String> {
+    let yaml: serde_norway::Value =
+        serde_norway::from_str(text).map_err(|e| format!("Invalid OpenAPI document: {}", e))?;
+    let spec = yaml_to_json(yaml);
+
+    let is_swagger = spec.get("swagger").and_then(Value::as_str) == Some("2.0");
+    let is_openapi = spec
+        .get("openapi")
+        .and_then(Value::as_str)
+        .is_some_and(|v| v.starts_with("3."));
+
+    if !is_swagger && !is_openapi {
+        return Err("Unsupported spec: expected 'openapi: 3.x' or 'swagger: 2.0'".to_string());
+    }
+
+    let title = spec
+        .pointer("/info/title")
+        .and_then(Value::as_str)
+        .filter(|t| !t.is_empty())
+        .unwrap_or("OpenAPI")
+        .to_string();
+
+    let mut importer = Importer {
+        spec: &spec,
+        is_swagger,
+        warnings: Vec::new(),
+        request_count: 0,
+    };
+
+    let servers = if is_swagger {
+        swagger_servers(&spec)
+    } else {
+        openapi_servers(&spec)
+    };
+    if servers.is_empty() {
+        importer.warnings.push(format!(
+            "The spec lists no servers; define {{{{{}}}}} in an environment",
+            BASE_URL_VARIABLE
+        ));
+    }
+
+    let children = importer.operations();
+
+    let environments = servers
+        .into_iter()
+        .map(|(label, url)| Environment {
+            name: format!("{} ({})", title, label),
+            variables: vec![KeyValue::new(BASE_URL_VARIABLE.to_string(), url)],
+        })
+        .collect();
+
+    Ok(OpenApiImport {
+        collection: CollectionNode::Folder {
+            name: title,
+            children,
+            expanded: true,
+        },
+        environments,
+        request_count: importer.request_count,
+        warnings: importer.warnings,
+    })
+}
+
+/// Converte YAML em JSON; chaves não textuais (ex.: códigos `200:`) viram strings
+fn yaml_to_json(value: serde_norway::Value) -> Value {
+    use serde_norway::Value as Yaml;
+
+    match value {
+        Yaml::Null => Value::Null,
+        Yaml::Bool(b) => Value::Bool(b),
+        Yaml::Number(n) => serde_json::to_value(&n).unwrap_or(Value::Null),
+        Yaml::String(s) => Value::String(s),
+        Yaml::Sequence(items) => Value::Array(items.into_iter().map(yaml_to_json).collect()),
+        Yaml::Mapping(mapping) => Value::Object(
+            mapping
+                .into_iter()
+                .map(|(key, value)| {
+                    let key = match key {
+                        Yaml::String(s) => s,
+                        other => serde_norway::to_string(&other)
+                            .unwrap_or_default()
+                            .trim()
+                            .to_string(),
+                    };
+                    (key, yaml_to_json(value))
+                })
+                .collect(),
+        ),
+        Yaml::Tagged(tagged) => yaml_to_json(tagged.value),
+    }
+}
+
+/// Servidores OpenAPI 3, com variáveis `{porta}` substituídas pelo valor padrão
+fn openapi_servers(spec: &Value) -> Vec<(String, String)> {
+    spec.get("servers")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|server| {
+            let mut url = server.get("url")?.as_str()?.to_string();
+
+            if let Some(variables) = server.get("variables").and_then(Value::as_object) {
+                for (name, variable) in variables {
+                    let default = variable
+                        .get("default")
+                        .and_then(Value::as_str)
+                        .unwrap_or_default();
+                    url = url.replace(&format!("{{{}}}", name), default);
+                }
+            }
+
+            let url = url.trim_end_matches('/').to_string();
+            let label = server
+                .get("description")
+                .and_then(Value::as_str)
+                .filter(|d| !d.is_empty())
+                .map_or_else(|| url.clone(), str::to_string);
+
+            Some((label, url))
+        })
+        .collect()
+}
+
+/// Swagger 2: `schemes` × `host` + `basePath`
+fn swagger_servers(spec: &Value) -> Vec<(String, String)> {
+    let Some(host) = spec.get("host").and_then(Value::as_str) else {
+        return Vec::new();
+    };
+    let base_path = spec
+        .get("basePath")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .trim_end_matches('/');
+
+    let schemes: Vec<&str> = spec
+        .get("schemes")
+        .and_then(Value::as_array)
+        .map(|s| s.iter().filter_map(Value::as_str).collect())
+        .filter(|s: &Vec<&str>| !s.is_empty())
+        .unwrap_or_else(|| vec!["https"]);
+
+    schemes
+        .into_iter()
+        .map(|scheme| {
+            let url = format!("{}://{}{}", scheme, host, base_path);
+            (url.clone(), url)
+        })
+        .collect()
+}
+
+struct Importer<'a> {
+    spec: &'a Value,
+    is_swagger: bool,
+    warnings: Vec<String>,
+    request_count: usize,
+}
+
+impl<'a> Importer<'a> {
+    /// Uma requisição por operação, agrupadas em pastas pela primeira tag
+    fn operations(&mut self) -> Vec<CollectionNode> {
+        let mut root: Vec<CollectionNode> = Vec::new();
+        let mut folders: Vec<(String, Vec<CollectionNode>)> = self
+            .spec
+            .get("tags")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|tag| Some((tag.get("name")?.as_str()?.to_string(), Vec::new())))
+            .collect();
+
+        let Some(paths) = self.spec.get("paths").and_then(Value::as_object) else {
+            self.warnings.push("The spec has no paths".to_string());
+            return root;
+        };
+
+        for (path, path_item) in paths {
+            let path_item = self.resolve(path_item);
+            let shared_parameters = path_item.get("parameters");
+
+            for method in METHODS {
+                let Some(operation) = path_item.get(method) else {
+                    continue;
+                };
+
+                let node = self.operation(path, method, operation, shared_parameters);
+                self.request_count += 1;
+
+                match operation.pointer("/tags/0").and_then(Value::as_str) {
+                    Some(tag) => match folders.iter_mut().find(|(name, _)| name == tag) {
+                        Some((_, children)) => children.push(node),
+                        None => folders.push((tag.to_string(), vec![node])),
+                    },
+                    None => root.push(node),
+                }
+            }
+        }
+
+        let mut nodes: Vec<CollectionNode> = folders
+            .into_iter()
+            .filter(|(_, children)| !children.is_empty())
+            .map(|(name, children)| CollectionNode::Folder {
+                name,
+                children,
+                expanded: false,
+            })
+            .collect();
+        nodes.extend(root);
+        nodes
+    }
+
+    fn operation(
+        &mut self,
+        path: &str,
+        method: &str,
+        operation: &Value,
+        shared_parameters: Option<&Value>,
+    ) -> CollectionNode {
+        let method = HTTPMethod::from_input(method).unwrap_or_default();
+        let name = ["summary", "operationId"]
+            .iter()
+            .find_map(|key| operation.get(*key).and_then(Value::as_str))
+            .filter(|name| !name.trim().is_empty())
+            .map_or_else(
+                || format!("{} {}", method, path),
+                |name| name.trim().to_string(),
+            );
+
+        let mut request = HttpRequest {
+            method,
+            url: format!("{{{{{}}}}}{}", BASE_URL_VARIABLE, path_placeholders(path)),
+            headers: Vec::new(),
+            ..HttpRequest::default()
+        };
+
+        for parameter in self.parameters(shared_parameters, operation.get("parameters")) {
+            self.apply_parameter(&mut request, &parameter, &name);
+        }
+
+        let body = if self.is_swagger {
+            self.swagger_body(operation)
+        } else {
+            self.openapi_body(operation)
+        };
+
+        if let Some((content_type, body)) = body {
+            if !request
+                .headers
+                .iter()
+                .any(|h| h.key.eq_ignore_ascii_case("content-type"))
+            {
+                request
+                    .headers
+                    .push(KeyValue::new("Content-Type".to_string(), content_type));
+            }
+            request.body_type = BodyType::Json;
+            request.body = serde_json::to_string_pretty(&body).unwrap_or_default();
+            request.force_body = request.method.can_force_body();
+        }
+
+        CollectionNode::request(name, request)
+    }
+
+    /// Parâmetros do caminho e da operação; os da operação substituem os de mesmo nome
+    fn parameters(&self, shared: Option<&Value>, own: Option<&Value>) -> Vec<Value> {
+        let mut result: Vec<Value> = Vec::new();
+
+        for parameter in [shared, own]
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_array)
+            .flatten()
+        {
+            let parameter = self.resolve(parameter).clone();
+            let key = |p: &Value| (p.get("name").cloned(), p.get("in").cloned());
+            result.retain(|existing| key(existing) != key(&parameter));
+            result.push(parameter);
+        }
+
+        result
+    }
+
+    fn apply_parameter(&mut self, request: &mut HttpRequest, parameter: &Value, operation: &str) {
+        let Some(name) = parameter.get("name").and_then(Value::as_str) else {
+            return;
+        };
+        let required = parameter.get("required").and_then(Value::as_bool) == Some(true);
+        let value = self.parameter_example(parameter);
+
+        match parameter.get("in").and_then(Value::as_str) {
+            Some("query") => request.query_params.push(KeyValue {
+                key: name.to_string(),
+                value,
+                enabled: false,
+            }),
+            Some("header") => request.headers.push(KeyValue {
+                key: name.to_string(),
+                value,
+                enabled: required,
+            }),
+            Some("formData") => self.warnings.push(format!(
+                "{}: form parameter '{}' not imported",
+                operation, name
+            )),
+            _ => {}
+        }
+    }
+
+    /// Valor de exemplo de um parâmetro: `example`, `examples`, `default` ou `enum`
+    fn parameter_example(&self, parameter: &Value) -> String {
+        let schema = parameter
+            .get("schema")
+            .map(|s| self.resolve(s))
+            .unwrap_or(parameter);
+
+        let example = parameter
+            .get("example")
+            .or_else(|| {
+                parameter
+                    .get("examples")
+                    .and_then(|examples| self.first_example(examples))
+            })
+            .or_else(|| schema.get("example"))
+            .or_else(|| schema.get("default"))
+            .or_else(|| schema.pointer("/enum/0"));
+
+        match example {
+            Some(Value::String(s)) => s.clone(),
+            Some(Value::Null) | None => String::new(),
+            Some(other) => other.to_string(),
+        }
+    }
+
+    /// Body JSON de `requestBody` (OpenAPI 3): exemplo declarado ou sintetizado do schema
+    fn openapi_body(&mut self, operation: &Value) -> Option<(String, Value)> {
+        let request_body = self.resolve(operation.get("requestBody")?);
+        let content = request_body.get("content")?.as_object()?;
+
+        let Some((content_type, media)) = content
+            .iter()
+            .find(|(content_type, _)| is_json(content_type))
+        else {
+            if let Some(content_type) = content.keys().next() {
+                self.warnings.push(format!(
+                    "{}: '{}' request body not generated",
+                    operation_label(operation),
+                    content_type
+                ));
+            }
+            return None;
+        };
+
+        let example = media
+            .get("example")
+            .cloned()
+            .or_else(|| {
+                media
+                    .get("examples")
+                    .and_then(|examples| self.first_example(examples))
+                    .cloned()
+            })
+            .or_else(|| {
+                media
+                    .get("schema")
+                    .map(|schema| self.example_from_schema(schema, 0))
+            })?;
+
+        Some((content_type.clone(), example))
+    }
+
+    /// Body de um parâmetro `in: body` (Swagger 2)
+    fn swagger_body(&self, operation: &Value) -> Option<(String, Value)> {
+        let parameters = operation.get("parameters")?.as_array()?;
+        let body = parameters
+            .iter()
+            .map(|p| self.resolve(p))
+            .find(|p| p.get("in").and_then(Value::as_str) == Some("body"))?;
+
+        let content_type = operation
+            .get("consumes")
+            .or_else(|| self.spec.get("consumes"))
+            .and_then(Value::as_array)
+            .and_then(|types| types.iter().filter_map(Value::as_str).find(|t| is_json(t)))
+            .unwrap_or("application/json")
+            .to_string();
+
+        let schema = body.get("schema")?;
+        Some((content_type, self.example_from_schema(schema, 0)))
+    }
+
+    /// Sintetiza um valor de exemplo a partir de um JSON Schema
+    fn example_from_schema(&self, schema: &Value, depth: usize) -> Value {
+        if depth > MAX_SCHEMA_DEPTH {
+            return Value::Null;
+        }
+        let schema = self.resolve(schema);
+
+        if let Some(example) = schema.get("example").or_else(|| schema.get("default")) {
+            return example.clone();
+        }
+        if let Some(first) = schema.pointer("/enum/0") {
+            return first.clone();
+        }
+
+        if let Some(parts) = schema.get("allOf").and_then(Value::as_array) {
+            let mut merged = Map::new();
+            for part in parts {
+                if let Value::Object(fields) = self.example_from_schema(part, depth + 1) {
+                    merged.extend(fields);
+                }
+            }
+            return Value::Object(merged);
+        }
+
+        if let Some(first) = ["oneOf", "anyOf"]
+            .iter()
+            .find_map(|key| schema.get(*key).and_then(|v| v.get(0)))
+        {
+            return self.example_from_schema(first, depth + 1);
+        }
+
+        let schema_type = match schema.get("type") {
+            Some(Value::String(t)) => t.as_str(),
+            // OpenAPI 3.1: `type: [string, "null"]`
+            Some(Value::Array(types)) => types
+                .iter()
+                .filter_map(Value::as_str)
+                .find(|t| *t != "null")
+                .unwrap_or("null"),
+            _ if schema.get("properties").is_some() => "object",
+            _ => "",
+        };
+
+        match schema_type {
+            "object" => Value::Object(
+                schema
+                    .get("properties")
+                    .and_then(Value::as_object)
+                    .into_iter()
+                    .flatten()
+                    .filter(|(_, property)| {
+                        self.resolve(property)
+                            .get("readOnly")
+                            .and_then(Value::as_bool)
+                            != Some(true)
+                    })
+                    .map(|(name, property)| {
+                        (name.clone(), self.example_from_schema(property, depth + 1))
+                    })
+                    .collect(),
+            ),
+            "array" => match schema.get("items") {
+                Some(items) => json!([self.example_from_schema(items, depth + 1)]),
+                None => json!([]),
+            },
+            "string" => json!(string_example(schema.get("format").and_then(Value::as_str))),
+            "integer" => json!(0),
+            "number" => json!(0.0),
+            "boolean" => json!(false),
+            _ => Value::Null,
+        }
+    }
+
+    /// Segue `$ref` locais (`#/components/...`, `#/definitions/...`)
+    /// `value` do primeiro Example Object de `examples`, resolvendo `$ref`
+    fn first_example<'v>(&self, examples: &'v Value) -> Option<&'v Value>
+    where
+        'a: 'v,
+    {
+        let example = examples.as_object()?.values().next()?;
+        Some(self.resolve(example).get("value").unwrap_or(example))
+    }
+
+    fn resolve<'v>(&self, value: &'v Value) -> &'v Value
+    where
+        'a: 'v,
+    {
+        let mut current = value;
+
+        // Limite simples contra referências circulares
+        for _ in 0..MAX_SCHEMA_DEPTH {
+            let Some(reference) = current.get("$ref").and_then(Value::as_str) else {
+                return current;
+            };
+            match reference
+                .strip_prefix('#')
+                .and_then(|pointer| self.spec.pointer(pointer))
+            {
+                Some(target) => current = target,
+                None => return current,
+            }
+        }
+
+        current
+    }
+}
+
+fn operation_label(operation: &Value) -> String {
+    ["operationId", "summary"]
+        .iter()
+        .find_map(|key| operation.get(*key).and_then(Value::as_str))
+        .unwrap_or("operation")
+        .to_string()
+}
+
+fn is_json(content_type: &str) -> bool {
+    let content_type = content_type.to_ascii_lowercase();
+    content_type.starts_with("application/json") || content_type.contains("+json")
+}
+
+/// Troca `{id}` por `{{id}}`, para que o parâmetro apareça como variável não resolvida
+fn path_placeholders(path: &str) -> String {
+    path.replace('{', "{{").replace('}', "}}")
+}
+
+fn string_example(format: Option<&str>) -> &'static str {
+    match format {
+        Some("date-time") => "2024-01-01T00:00:00Z",
+        Some("date") => "2024-01-01",
+        Some("email") => "user@example.com",
+        Some("uuid") => "00000000-0000-0000-0000-000000000000",
+        Some("uri" | "url") => "https://example.com",
+        Some("ipv4") => "127.0.0.1",
+        _ => "string",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OPENAPI_YAML: &str = include_str!("../../../tests/fixtures/openapi/petstore.yaml");
+    const SWAGGER_JSON: &str = include_str!("../../../tests/fixtures/openapi/swagger.json");
+
+    fn folder(node: &CollectionNode) -> &[CollectionNode] {
+        match node {
+            CollectionNode::Folder { children, .. } => children,
+            CollectionNode::Request { .. } => panic!("expected folder"),
+        }
+    }
+
+    fn request(node: &CollectionNode) -> (&str, &HttpRequest) {
+        match node {
+            CollectionNode::Request { name, request } => (name, request),
+            CollectionNode::Folder { .. } => panic!("expected request"),
+        }
+    }
+
+    #[test]
+    fn test_import_openapi3() {
+        let imported = parse(OPENAPI_YAML).unwrap();
+        assert_eq!(imported.collection.name(), "Petstore");
+        assert_eq!(imported.request_count, 4);
+
+        let environments = &imported.environments;
+        assert_eq!(environments.len(), 2);
+        assert_eq!(environments[0].name, "Petstore (Production)");
+        assert_eq!(
+            environments[1].variables[0].value,
+            "http://localhost:8080/v1"
+        );
+
+        let pets = folder(&imported.collection);
+        assert_eq!(pets[0].name(), "pets");
+
+        let (name, list) = request(&folder(&pets[0])[0]);
+        assert_eq!(name, "List pets");
+        assert_eq!(list.url, "{{baseUrl}}/pets");
+        assert_eq!(
+            list.query_params,
+            vec![
+                KeyValue {
+                    key: "limit".into(),
+                    value: "20".into(),
+                    enabled: false
+                },
+                KeyValue {
+                    key: "status".into(),
+                    value: "available".into(),
+                    enabled: false
+                },
+                KeyValue {
+                    key: "offset".into(),
+                    value: "10".into(),
+                    enabled: false
+                },
+                KeyValue {
+                    key: "sort".into(),
+                    value: "name".into(),
+                    enabled: false
+                },
+            ]
+        );
+        // Header obrigatório habilitado; opcional fica desabilitado
+        assert_eq!(
+            list.headers,
+            vec![
+                KeyValue::new("X-Tenant".into(), "acme".into()),
+                KeyValue {
+                    key: "X-Debug".into(),
+                    value: String::new(),
+                    enabled: false
+                },
+            ]
+        );
+
+        let (_, create) = request(&folder(&pets[0])[1]);
+        assert_eq!(create.method, HTTPMethod::POST);
+        assert_eq!(create.body_type, BodyType::Json);
+        let body: Value = serde_json::from_str(&create.body).unwrap();
+        assert_eq!(
+            body,
+            json!({
+                "name": "string",
+                "tag": "dog",
+                "owner": { "email": "user@example.com" },
+                "vaccinations": ["2024-01-01"]
+            })
+        );
+
+        let (_, get) = request(&folder(&pets[0])[2]);
+        assert_eq!(get.url, "{{baseUrl}}/pets/{{petId}}");
+
+        let (name, health) = request(&folder(&imported.collection)[1]);
+        assert_eq!(name, "GET /health");
+        assert_eq!(health.url, "{{baseUrl}}/health");
+    }
+
+    #[test]
+    fn test_import_swagger2() {
+        let imported = parse(SWAGGER_JSON).unwrap();
+        assert_eq!(imported.environments.len(), 2);
+        assert_eq!(
+            imported.environments[0].variables[0].value,
+            "https://api.example.com/v2"
+        );
+
+        let users = folder(&folder(&imported.collection)[0]);
+        let (name, create) = request(&users[0]);
+        assert_eq!(name, "createUser");
+        assert_eq!(create.body, "{\n  \"id\": 0,\n  \"name\": \"string\"\n}");
+        assert!(create.headers.contains(&KeyValue::new(
+            "Content-Type".into(),
+            "application/json".into()
+        )));
+
+        assert!(imported.warnings.iter().any(|w| w.contains("'avatar'")));
+    }
+
+    #[test]
+    fn test_rejects_unknown_documents() {
+        assert!(parse("name: not a spec").is_err());
+        assert!(parse("{ invalid").is_err());
+    }
+}
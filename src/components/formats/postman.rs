@@ -0,0 +1,976 @@
+//! Importação e exportação de coleções Postman (formato v2.1)
+
+use super::detect_body_type;
+use crate::components::collections::CollectionNode;
+use crate::components::enums::{
+    ApiKeyLocation, Auth, AwsSigV4Config, BodyType, FormPart, FormPartKind, GraphQlBody,
+    HTTPMethod, HttpRequest, KeyValue, OAuth2Config, OAuth2Grant,
+};
+use crate::components::environment::Environment;
+use crate::components::file_dialog::FileFilter;
+use serde_json::{Map, Value, json};
+
+/// Filtro dos diálogos de importação/exportação
+pub const FILE_FILTER: FileFilter = ("Postman collection", &["json"]);
+
+const SCHEMA_V21: &str = "https://schema.getpostman.com/json/collection/v2.1.0/collection.json";
+
+/// Quantidade máxima de avisos listados no resumo da importação
+const MAX_SUMMARY_WARNINGS: usize = 10;
+
+/// Resultado da importação de uma coleção Postman
+#[derive(Debug, Clone)]
+pub struct PostmanImport {
+    /// Pasta raiz com o nome da coleção e toda a árvore importada
+    pub collection: CollectionNode,
+    /// Variáveis da coleção, importadas como um ambiente
+    pub environment: Option<Environment>,
+    pub request_count: usize,
+    /// Partes ignoradas (scripts, autenticações não suportadas...)
+    pub warnings: Vec<String>,
+}
+
+impl PostmanImport {
+    /// Texto exibido ao usuário ao final da importação
+    pub fn summary(&self) -> String {
+        let mut lines = vec![format!(
+            "Imported {} requests from Postman collection '{}'",
+            self.request_count,
+            self.collection.name()
+        )];
+
+        if let Some(environment) = &self.environment {
+            lines.push(format!(
+                "Collection variables saved as environment '{}'",
+                environment.name
+            ));
+        }
+
+        if !self.warnings.is_empty() {
+            lines.push(format!("Not imported ({}):", self.warnings.len()));
+            lines.extend(
+                self.warnings
+                    .iter()
+                    .take(MAX_SUMMARY_WARNINGS)
+                    .map(|w| format!("• {}", w)),
+            );
+            if self.warnings.len() > MAX_SUMMARY_WARNINGS {
+                lines.push(format!(
+                    "…and {} more",
+                    self.warnings.len() - MAX_SUMMARY_WARNINGS
+                ));
+            }
+        }
+
+        lines.join("\n")
+    }
+}
+
+/// Importa uma coleção Postman v2.0/v2.1.
+///
+/// # Errors
+/// Retorna erro se o JSON for inválido ou não for uma coleção v2.
+pub fn parse(json: &str) -> Result<PostmanImport, String> {
+    let root: Value =
+        serde_json::from_str(json).map_err(|e| format!("Invalid Postman collection: {}", e))?;
+
+    let Some(info) = root.get("info") else {
+        return Err(if root.get("requests").is_some() {
+            "Postman v1 collections are not supported; export the collection as v2.1".to_string()
+        } else {
+            "Invalid Postman collection: missing 'info'".to_string()
+        });
+    };
+
+    let name = str_field(info, "name")
+        .filter(|n| !n.is_empty())
+        .unwrap_or("Postman collection")
+        .to_string();
+
+    let mut importer = Importer::default();
+    importer.check_events(&root, "Collection");
+
+    let children = importer.items(items_of(&root), root.get("auth"), "");
+
+    let variables = key_values(root.get("variable"));
+    let environment = (!variables.is_empty()).then(|| Environment {
+        name: name.clone(),
+        variables,
+    });
+
+    Ok(PostmanImport {
+        collection: CollectionNode::Folder {
+            name,
+            children,
+            expanded: true,
+        },
+        environment,
+        request_count: importer.request_count,
+        warnings: importer.warnings,
+    })
+}
+
+#[derive(Default)]
+struct Importer {
+    request_count: usize,
+    warnings: Vec<String>,
+}
+
+impl Importer {
+    fn warn(&mut self, location: &str, message: &str) {
+        self.warnings.push(format!("{}: {}", location, message));
+    }
+
+    fn items(
+        &mut self,
+        items: &[Value],
+        auth: Option<&Value>,
+        parent: &str,
+    ) -> Vec<CollectionNode> {
+        items
+            .iter()
+            .map(|item| self.item(item, auth, parent))
+            .collect()
+    }
+
+    fn item(
+        &mut self,
+        item: &Value,
+        inherited_auth: Option<&Value>,
+        parent: &str,
+    ) -> CollectionNode {
+        let name = str_field(item, "name").unwrap_or("Untitled").to_string();
+        let location = if parent.is_empty() {
+            name.clone()
+        } else {
+            format!("{} / {}", parent, name)
+        };
+
+        self.check_events(item, &location);
+
+        if item.get("item").is_some() {
+            let auth = item.get("auth").or(inherited_auth);
+            return CollectionNode::Folder {
+                children: self.items(items_of(item), auth, &location),
+                name,
+                expanded: true,
+            };
+        }
+
+        if let Some(responses) = item.get("response").and_then(Value::as_array)
+            && !responses.is_empty()
+        {
+            self.warn(
+                &location,
+                &format!("{} saved example responses", responses.len()),
+            );
+        }
+
+        self.request_count += 1;
+        let request = self.request(item.get("request"), inherited_auth, &location);
+        CollectionNode::request(name, request)
+    }
+
+    fn check_events(&mut self, item: &Value, location: &str) {
+        let Some(events) = item.get("event").and_then(Value::as_array) else {
+            return;
+        };
+
+        for event in events {
+            let has_code = event
+                .pointer("/script/exec")
+                .is_some_and(|exec| match exec {
+                    Value::Array(lines) => lines
+                        .iter()
+                        .any(|l| l.as_str().is_some_and(|l| !l.trim().is_empty())),
+                    Value::String(code) => !code.trim().is_empty(),
+                    _ => false,
+                });
+
+            if has_code {
+                let kind = match str_field(event, "listen") {
+                    Some("prerequest") => "pre-request script",
+                    Some("test") => "test script",
+                    _ => "script",
+                };
+                self.warn(location, kind);
+            }
+        }
+    }
+
+    fn request(
+        &mut self,
+        request: Option<&Value>,
+        inherited_auth: Option<&Value>,
+        location: &str,
+    ) -> HttpRequest {
+        let mut result = HttpRequest {
+            headers: Vec::new(),
+            ..HttpRequest::default()
+        };
+
+        let request = match request {
+            Some(Value::String(url)) => {
+                result.url = url.clone();
+                return result;
+            }
+            Some(request) => request,
+            None => {
+                self.warn(location, "item without request");
+                return result;
+            }
+        };
+
+        if let Some(method) = str_field(request, "method") {
+            result.method = HTTPMethod::from_input(method).unwrap_or_default();
+        }
+
+        result.headers = match request.get("header") {
+            Some(Value::String(raw)) => raw
+                .lines()
+                .filter_map(|line| line.split_once(':'))
+                .map(|(k, v)| KeyValue::new(k.trim().to_string(), v.trim().to_string()))
+                .collect(),
+            other => key_values(other),
+        };
+
+        let (url, query_params) = parse_url(request.get("url"));
+        result.url = url;
+        result.query_params = query_params;
+
+        if let Some(body) = request.get("body") {
+            self.body(&mut result, body, location);
+        }
+
+        if let Some(auth) = request.get("auth").or(inherited_auth) {
+            self.auth(&mut result, auth, location);
+        }
+
+        result
+    }
+
+    fn body(&mut self, request: &mut HttpRequest, body: &Value, location: &str) {
+        if body.get("disabled").and_then(Value::as_bool) == Some(true) {
+            return;
+        }
+
+        match str_field(body, "mode") {
+            Some("raw") => {
+                let raw = str_field(body, "raw").unwrap_or_default().to_string();
+                request.body_type = match body.pointer("/options/raw/language") {
+                    Some(Value::String(language)) if language == "json" => BodyType::Json,
+                    Some(Value::String(language)) if language == "xml" => BodyType::Xml,
+                    Some(Value::String(language)) if language == "html" => BodyType::Html,
+                    _ => detect_body_type(&request.headers, &raw),
+                };
+                request.body = raw;
+            }
+            Some("urlencoded") => {
+                request.form_urlencoded = self.form_fields(body.get("urlencoded"), location);
+                request.body_type = BodyType::FormUrlEncoded;
+            }
+            Some("formdata") => {
+                request.form_data = self.form_parts(body.get("formdata"), location);
+                request.body_type = BodyType::FormData;
+            }
+            Some("graphql") => {
+                let field = |key: &str| {
+                    body.pointer(&format!("/graphql/{}", key))
+                        .and_then(Value::as_str)
+                        .unwrap_or_default()
+                        .to_string()
+                };
+                request.graphql = GraphQlBody {
+                    query: field("query"),
+                    variables: field("variables"),
+                    operation_name: String::new(),
+                };
+                request.body_type = BodyType::GraphQl;
+            }
+            Some("file") => match body.pointer("/file/src").and_then(Value::as_str) {
+                Some(src) if !src.is_empty() => {
+                    request.binary_file = src.to_string();
+                    request.body_type = BodyType::Binary;
+                }
+                _ => self.warn(location, "binary file body without a file"),
+            },
+            Some(mode) => self.warn(location, &format!("'{}' body", mode)),
+            None => {}
+        }
+
+        let has_content = !request.body.is_empty()
+            || matches!(
+                request.body_type,
+                BodyType::FormData | BodyType::FormUrlEncoded | BodyType::Binary
+            );
+        if has_content && !request.method.allows_body() {
+            request.force_body = request.method.can_force_body();
+        }
+    }
+
+    /// Partes de um body `formdata`; campos desabilitados são mantidos desabilitados
+    fn form_parts(&mut self, fields: Option<&Value>, location: &str) -> Vec<FormPart> {
+        let mut result = Vec::new();
+
+        for field in fields.and_then(Value::as_array).into_iter().flatten() {
+            let key = str_field(field, "key").unwrap_or_default().to_string();
+            let mut part = if str_field(field, "type") == Some("file") {
+                let sources: Vec<&str> = match field.get("src") {
+                    Some(Value::String(src)) => vec![src.as_str()],
+                    Some(Value::Array(srcs)) => srcs.iter().filter_map(Value::as_str).collect(),
+                    _ => Vec::new(),
+                };
+                if sources.len() > 1 {
+                    self.warn(
+                        location,
+                        &format!(
+                            "extra files of form field '{}' (only the first is kept)",
+                            key
+                        ),
+                    );
+                }
+                FormPart::file(
+                    key,
+                    sources.first().copied().unwrap_or_default().to_string(),
+                )
+            } else {
+                FormPart::text(
+                    key,
+                    str_field(field, "value").unwrap_or_default().to_string(),
+                )
+            };
+
+            part.content_type = str_field(field, "contentType")
+                .unwrap_or_default()
+                .to_string();
+            part.enabled = field.get("disabled").and_then(Value::as_bool) != Some(true);
+            result.push(part);
+        }
+
+        result
+    }
+
+    /// Campos de um body `urlencoded`; desabilitados são mantidos, arquivos viram avisos
+    fn form_fields(&mut self, fields: Option<&Value>, location: &str) -> Vec<KeyValue> {
+        let mut result = Vec::new();
+
+        for field in fields.and_then(Value::as_array).into_iter().flatten() {
+            let key = str_field(field, "key").unwrap_or_default();
+            if str_field(field, "type") == Some("file") {
+                self.warn(location, &format!("file form field '{}'", key));
+            } else {
+                let mut pair = KeyValue::new(
+                    key.to_string(),
+                    str_field(field, "value").unwrap_or_default().to_string(),
+                );
+                pair.enabled = field.get("disabled").and_then(Value::as_bool) != Some(true);
+                result.push(pair);
+            }
+        }
+
+        result
+    }
+
+    fn auth(&mut self, request: &mut HttpRequest, auth: &Value, location: &str) {
+        let auth_type = str_field(auth, "type").unwrap_or("noauth");
+        let param = |key: &str| auth_param(auth, auth_type, key).unwrap_or_default();
+
+        let auth = match auth_type {
+            "noauth" => return,
+            "basic" => Auth::Basic {
+                username: param("username"),
+                password: param("password"),
+            },
+            "digest" => Auth::Digest {
+                username: param("username"),
+                password: param("password"),
+            },
+            "bearer" => Auth::Bearer {
+                token: param("token"),
+            },
+            "apikey" => Auth::ApiKey {
+                name: param("key"),
+                value: param("value"),
+                location: if param("in") == "query" {
+                    ApiKeyLocation::Query
+                } else {
+                    ApiKeyLocation::Header
+                },
+            },
+            "awsv4" => Auth::AwsSigV4(Box::new(AwsSigV4Config {
+                access_key: param("accessKey"),
+                secret_key: param("secretKey"),
+                session_token: param("sessionToken"),
+                region: param("region"),
+                service: param("service"),
+            })),
+            "oauth2" => {
+                // O Postman usa authorization code quando grant_type não é informado
+                let grant = match param("grant_type").as_str() {
+                    "client_credentials" => OAuth2Grant::ClientCredentials,
+                    "password_credentials" => OAuth2Grant::Password,
+                    "" | "authorization_code" | "authorization_code_with_pkce" => {
+                        OAuth2Grant::AuthorizationCode
+                    }
+                    other => {
+                        self.warn(location, &format!("OAuth2 '{}' grant", other));
+                        return;
+                    }
+                };
+
+                Auth::OAuth2(Box::new(OAuth2Config {
+                    grant,
+                    token_url: param("accessTokenUrl"),
+                    auth_url: param("authUrl"),
+                    client_id: param("clientId"),
+                    client_secret: param("clientSecret"),
+                    scope: param("scope"),
+                    audience: param("audience"),
+                    username: param("username"),
+                    password: param("password"),
+                }))
+            }
+            other => {
+                self.warn(location, &format!("'{}' authentication", other));
+                return;
+            }
+        };
+
+        // Um header Authorization explícito na requisição prevalece sobre o herdado
+        let has_header = request
+            .headers
+            .iter()
+            .any(|h| h.enabled && h.key.eq_ignore_ascii_case("authorization"));
+        if !has_header || matches!(auth, Auth::ApiKey { .. }) {
+            request.auth = auth;
+        }
+    }
+}
+
+fn items_of(value: &Value) -> &[Value] {
+    value
+        .get("item")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+fn str_field<'a>(value: &'a Value, key: &str) -> Option<&'a str> {
+    value.get(key).and_then(Value::as_str)
+}
+
+/// Converte arrays `[{key, value, disabled}]` (headers, query, variáveis)
+fn key_values(value: Option<&Value>) -> Vec<KeyValue> {
+    value
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|item| {
+            let key = str_field(item, "key")?;
+            let value = match item.get("value") {
+                Some(Value::String(s)) => s.clone(),
+                Some(Value::Null) | None => String::new(),
+                Some(other) => other.to_string(),
+            };
+
+            Some(KeyValue {
+                key: key.to_string(),
+                value,
+                enabled: item.get("disabled").and_then(Value::as_bool) != Some(true),
+            })
+        })
+        .collect()
+}
+
+/// Separa a URL base dos query params, aplicando as variáveis de caminho (`:id`)
+fn parse_url(url: Option<&Value>) -> (String, Vec<KeyValue>) {
+    let (raw, query, path_variables) = match url {
+        Some(Value::String(raw)) => (raw.as_str(), None, Vec::new()),
+        Some(url) => (
+            str_field(url, "raw").unwrap_or_default(),
+            url.get("query"),
+            key_values(url.get("variable")),
+        ),
+        None => ("", None, Vec::new()),
+    };
+
+    let (base, raw_query) = raw.split_once('?').unwrap_or((raw, ""));
+
+    let query_params = match query {
+        Some(query) => key_values(Some(query)),
+        None => raw_query
+            .split('&')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| {
+                let (k, v) = pair.split_once('=').unwrap_or((pair, ""));
+                KeyValue::new(k.to_string(), v.to_string())
+            })
+            .collect(),
+    };
+
+    let base = base
+        .split('/')
+        .map(|segment| {
+            segment
+                .strip_prefix(':')
+                .and_then(|name| path_variables.iter().find(|v| v.key == name))
+                .filter(|v| !v.value.is_empty())
+                .map_or(segment, |v| v.value.as_str())
+        })
+        .collect::<Vec<_>>()
+        .join("/");
+
+    (base, query_params)
+}
+
+/// Lê um parâmetro de autenticação nos formatos v2.1 (lista) e v2.0 (objeto)
+fn auth_param(auth: &Value, auth_type: &str, key: &str) -> Option<String> {
+    match auth.get(auth_type)? {
+        Value::Array(params) => params
+            .iter()
+            .find(|p| str_field(p, "key") == Some(key))
+            .and_then(|p| p.get("value"))
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        Value::Object(params) => params.get(key).and_then(Value::as_str).map(str::to_string),
+        _ => None,
+    }
+}
+
+/// Exporta nós de coleção (pastas e requisições) como uma coleção Postman v2.1.
+///
+/// # Errors
+/// Retorna erro se a serialização falhar.
+pub fn export(name: &str, nodes: &[CollectionNode]) -> Result<String, String> {
+    let collection = json!({
+        "info": {
+            "name": name,
+            "schema": SCHEMA_V21,
+        },
+        "item": nodes.iter().map(export_node).collect::<Vec<_>>(),
+    });
+
+    serde_json::to_string_pretty(&collection)
+        .map_err(|e| format!("Failed to serialize Postman collection: {}", e))
+}
+
+fn export_node(node: &CollectionNode) -> Value {
+    match node {
+        CollectionNode::Folder { name, children, .. } => json!({
+            "name": name,
+            "item": children.iter().map(export_node).collect::<Vec<_>>(),
+        }),
+        CollectionNode::Request { name, request } => json!({
+            "name": name,
+            "request": export_request(request),
+            "response": [],
+        }),
+    }
+}
+
+fn export_request(request: &HttpRequest) -> Value {
+    let mut result = Map::new();
+    result.insert("method".into(), json!(request.method.to_string()));
+    result.insert("header".into(), export_key_values(&request.headers, true));
+    result.insert("url".into(), export_url(request));
+
+    if let Some(auth) = export_auth(&request.auth) {
+        result.insert("auth".into(), auth);
+    }
+
+    // GraphQL via GET também leva o body: o Postman envia a query nos query params
+    let graphql_get = request.body_type == BodyType::GraphQl
+        && request.method == HTTPMethod::GET
+        && !request.graphql.query.trim().is_empty();
+    if request.has_body() || graphql_get {
+        result.insert("body".into(), export_body(request));
+    }
+
+    Value::Object(result)
+}
+
+/// Objeto `auth` no formato v2.1: parâmetros como lista `[{key, value, type}]`
+fn export_auth(auth: &Auth) -> Option<Value> {
+    let (auth_type, params) = match auth {
+        Auth::None => return None,
+        Auth::OAuth2(config) => {
+            let grant = match config.grant {
+                OAuth2Grant::ClientCredentials => "client_credentials",
+                OAuth2Grant::Password => "password_credentials",
+                OAuth2Grant::AuthorizationCode => "authorization_code_with_pkce",
+            };
+            let params: Vec<Value> = [
+                ("grant_type", grant),
+                ("accessTokenUrl", config.token_url.as_str()),
+                ("authUrl", config.auth_url.as_str()),
+                ("clientId", config.client_id.as_str()),
+                ("clientSecret", config.client_secret.as_str()),
+                ("scope", config.scope.as_str()),
+                ("audience", config.audience.as_str()),
+                ("username", config.username.as_str()),
+                ("password", config.password.as_str()),
+            ]
+            .into_iter()
+            .filter(|(_, value)| !value.is_empty())
+            .map(|(key, value)| json!({"key": key, "value": value, "type": "string"}))
+            .collect();
+
+            return Some(json!({"type": "oauth2", "oauth2": params}));
+        }
+        Auth::Basic { username, password } => (
+            "basic",
+            vec![("username", username), ("password", password)],
+        ),
+        Auth::Digest { username, password } => (
+            "digest",
+            vec![("username", username), ("password", password)],
+        ),
+        Auth::Bearer { token } => ("bearer", vec![("token", token)]),
+        Auth::AwsSigV4(config) => (
+            "awsv4",
+            vec![
+                ("accessKey", &config.access_key),
+                ("secretKey", &config.secret_key),
+                ("sessionToken", &config.session_token),
+                ("region", &config.region),
+                ("service", &config.service),
+            ],
+        ),
+        Auth::ApiKey {
+            name,
+            value,
+            location,
+        } => {
+            let location = match location {
+                ApiKeyLocation::Header => "header",
+                ApiKeyLocation::Query => "query",
+            };
+            return Some(json!({
+                "type": "apikey",
+                "apikey": [
+                    {"key": "key", "value": name, "type": "string"},
+                    {"key": "value", "value": value, "type": "string"},
+                    {"key": "in", "value": location, "type": "string"},
+                ],
+            }));
+        }
+    };
+
+    let params: Vec<Value> = params
+        .into_iter()
+        .map(|(key, value)| json!({"key": key, "value": value, "type": "string"}))
+        .collect();
+
+    Some(json!({"type": auth_type, auth_type: params}))
+}
+
+/// Lista `[{key, value, disabled}]`; headers e campos de formulário levam `"type": "text"`
+fn export_key_values(items: &[KeyValue], text_type: bool) -> Value {
+    items
+        .iter()
+        .filter(|item| !item.key.is_empty())
+        .map(|item| {
+            let mut entry = Map::new();
+            entry.insert("key".into(), json!(item.key));
+            entry.insert("value".into(), json!(item.value));
+            if text_type {
+                entry.insert("type".into(), json!("text"));
+            }
+            if !item.enabled {
+                entry.insert("disabled".into(), json!(true));
+            }
+            Value::Object(entry)
+        })
+        .collect()
+}
+
+fn export_url(request: &HttpRequest) -> Value {
+    let base = request.url.trim();
+    let enabled_query: Vec<String> = request
+        .query_params
+        .iter()
+        .filter(|p| p.enabled && !p.key.is_empty())
+        .map(|p| format!("{}={}", p.key, p.value))
+        .collect();
+
+    let raw = if enabled_query.is_empty() {
+        base.to_string()
+    } else {
+        format!("{}?{}", base, enabled_query.join("&"))
+    };
+
+    let mut url = Map::new();
+    url.insert("raw".into(), json!(raw));
+
+    let (protocol, rest) = match base.split_once("://") {
+        Some((protocol, rest)) => (Some(protocol), rest),
+        None => (None, base),
+    };
+    let (host, path) = rest.split_once('/').unwrap_or((rest, ""));
+
+    if let Some(protocol) = protocol {
+        url.insert("protocol".into(), json!(protocol));
+    }
+    url.insert("host".into(), json!(host.split('.').collect::<Vec<_>>()));
+    if !path.is_empty() {
+        url.insert("path".into(), json!(path.split('/').collect::<Vec<_>>()));
+    }
+    if !request.query_params.is_empty() {
+        url.insert(
+            "query".into(),
+            export_key_values(&request.query_params, false),
+        );
+    }
+
+    Value::Object(url)
+}
+
+fn export_body(request: &HttpRequest) -> Value {
+    if request.body_type == BodyType::FormData {
+        let fields: Vec<Value> = request
+            .form_data
+            .iter()
+            .filter(|part| !part.key.is_empty())
+            .map(|part| {
+                let mut field = Map::new();
+                field.insert("key".into(), json!(part.key));
+                match part.kind {
+                    FormPartKind::Text => {
+                        field.insert("value".into(), json!(part.value));
+                        field.insert("type".into(), json!("text"));
+                    }
+                    FormPartKind::File => {
+                        field.insert("src".into(), json!(part.value));
+                        field.insert("type".into(), json!("file"));
+                    }
+                }
+                if !part.content_type.is_empty() {
+                    field.insert("contentType".into(), json!(part.content_type));
+                }
+                if !part.enabled {
+                    field.insert("disabled".into(), json!(true));
+                }
+                Value::Object(field)
+            })
+            .collect();
+
+        return json!({ "mode": "formdata", "formdata": fields });
+    }
+
+    if request.body_type == BodyType::GraphQl {
+        return json!({
+            "mode": "graphql",
+            "graphql": {
+                "query": request.graphql.query,
+                "variables": request.graphql.variables,
+            },
+        });
+    }
+
+    if request.body_type == BodyType::Binary {
+        return json!({ "mode": "file", "file": { "src": request.binary_file } });
+    }
+
+    if request.body_type == BodyType::FormUrlEncoded {
+        return json!({
+            "mode": "urlencoded",
+            "urlencoded": export_key_values(&request.form_urlencoded, true),
+        });
+    }
+
+    let is_urlencoded = request.headers.iter().any(|h| {
+        h.enabled
+            && h.key.eq_ignore_ascii_case("content-type")
+            && h.value
+                .to_ascii_lowercase()
+                .starts_with("application/x-www-form-urlencoded")
+    });
+
+    if is_urlencoded {
+        let fields: Vec<KeyValue> = url::form_urlencoded::parse(request.body.as_bytes())
+            .map(|(k, v)| KeyValue::new(k.into_owned(), v.into_owned()))
+            .collect();
+
+        return json!({
+            "mode": "urlencoded",
+            "urlencoded": export_key_values(&fields, true),
+        });
+    }
+
+    let language = match request.body_type {
+        BodyType::Json => "json",
+        BodyType::Xml => "xml",
+        BodyType::Html => "html",
+        _ => "text",
+    };
+
+    json!({
+        "mode": "raw",
+        "raw": request.body,
+        "options": { "raw": { "language": language } },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../../../tests/fixtures/postman/partner_api.json");
+
+    fn request_at<'a>(node: &'a CollectionNode, path: &[usize]) -> &'a HttpRequest {
+        let mut node = node;
+        for &index in path {
+            match node {
+                CollectionNode::Folder { children, .. } => node = &children[index],
+                CollectionNode::Request { .. } => panic!("not a folder"),
+            }
+        }
+        match node {
+            CollectionNode::Request { request, .. } => request,
+            CollectionNode::Folder { .. } => panic!("not a request"),
+        }
+    }
+
+    #[test]
+    fn test_import_collection() {
+        let imported = parse(FIXTURE).unwrap();
+
+        assert_eq!(imported.collection.name(), "Partner API");
+        assert_eq!(imported.request_count, 5);
+
+        let environment = imported.environment.unwrap();
+        assert_eq!(environment.variables[0].key, "baseUrl");
+
+        // Auth da coleção herdada pela pasta "Users"
+        let list = request_at(&imported.collection, &[0, 0]);
+        assert_eq!(list.url, "{{baseUrl}}/users");
+        assert_eq!(list.query_params.len(), 2);
+        assert!(!list.query_params[1].enabled);
+        assert_eq!(
+            list.auth,
+            Auth::Bearer {
+                token: "{{token}}".into()
+            }
+        );
+
+        let get_user = request_at(&imported.collection, &[0, 1]);
+        assert_eq!(get_user.url, "{{baseUrl}}/users/42");
+
+        let create = request_at(&imported.collection, &[0, 2]);
+        assert_eq!(create.method, HTTPMethod::POST);
+        assert_eq!(create.body_type, BodyType::Json);
+        assert_eq!(
+            create.auth,
+            Auth::Basic {
+                username: "admin".into(),
+                password: "secret".into()
+            }
+        );
+
+        let login = request_at(&imported.collection, &[1]);
+        assert_eq!(login.body_type, BodyType::FormUrlEncoded);
+        assert_eq!(login.encoded_body(), "username=alice&scope=read+write");
+        assert!(!login.form_urlencoded[2].enabled);
+        assert_eq!(login.auth, Auth::None);
+
+        let upload = request_at(&imported.collection, &[2]);
+        assert!(matches!(
+            &upload.auth,
+            Auth::OAuth2(config) if config.grant == OAuth2Grant::AuthorizationCode
+        ));
+        assert_eq!(upload.body_type, BodyType::FormData);
+        assert_eq!(
+            upload.form_data,
+            vec![
+                FormPart::text("title".into(), "Report".into()),
+                FormPart::file("file".into(), "/tmp/report.pdf".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_import_reports_unsupported_parts() {
+        let imported = parse(FIXTURE).unwrap();
+
+        for expected in [
+            "Collection: pre-request script",
+            "Users / List users: test script",
+            "Users / List users: 1 saved example responses",
+        ] {
+            assert!(
+                imported.warnings.iter().any(|w| w == expected),
+                "missing warning: {}",
+                expected
+            );
+        }
+        assert!(imported.summary().contains("Not imported (3):"));
+    }
+
+    #[test]
+    fn test_export_round_trip() {
+        let imported = parse(FIXTURE).unwrap();
+        let CollectionNode::Folder { children, .. } = &imported.collection else {
+            panic!("expected folder");
+        };
+
+        let exported = export("Partner API", children).unwrap();
+        let reimported = parse(&exported).unwrap();
+
+        assert!(reimported.warnings.is_empty());
+        assert_eq!(reimported.request_count, imported.request_count);
+        for path in [&[0, 0][..], &[0, 1], &[0, 2], &[1], &[2]] {
+            assert_eq!(
+                request_at(&reimported.collection, path),
+                request_at(&imported.collection, path)
+            );
+        }
+    }
+
+    #[test]
+    fn test_binary_file_body_round_trip() {
+        let request = HttpRequest {
+            method: HTTPMethod::PUT,
+            url: "https://example.com/blob".to_string(),
+            body_type: BodyType::Binary,
+            binary_file: "/tmp/logo.png".to_string(),
+            ..HttpRequest::default()
+        };
+        let nodes = vec![CollectionNode::request("Upload".into(), request.clone())];
+
+        let exported = export("Files", &nodes).unwrap();
+        assert!(exported.contains(r#""mode": "file""#));
+
+        let imported = parse(&exported).unwrap();
+        assert!(imported.warnings.is_empty());
+        assert_eq!(request_at(&imported.collection, &[0]), &request);
+    }
+
+    #[test]
+    fn test_graphql_body_round_trip() {
+        let request = HttpRequest {
+            method: HTTPMethod::POST,
+            url: "https://example.com/graphql".to_string(),
+            body_type: BodyType::GraphQl,
+            graphql: GraphQlBody {
+                query: "query User($id: ID!) { user(id: $id) { name } }".to_string(),
+                variables: r#"{"id": "1"}"#.to_string(),
+                operation_name: String::new(),
+            },
+            ..HttpRequest::default()
+        };
+        let nodes = vec![CollectionNode::request("User".into(), request.clone())];
+
+        let exported = export("GraphQL", &nodes).unwrap();
+        assert!(exported.contains(r#""mode": "graphql""#));
+
+        let imported = parse(&exported).unwrap();
+        assert!(imported.warnings.is_empty());
+        assert_eq!(request_at(&imported.collection, &[0]), &request);
+    }
+
+    #[test]
+    fn test_rejects_v1_collection() {
+        let error = parse(r#"{"id": "1", "name": "Old", "requests": []}"#).unwrap_err();
+        assert!(error.contains("v1"));
+    }
+}
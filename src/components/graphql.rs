@@ -0,0 +1,511 @@
+//! Requisições GraphQL: operações do documento, payload, erros da resposta e introspecção
+
+use super::enums::{BodyType, GraphQlBody, HTTPMethod, HttpRequest, KeyValue};
+use super::storage;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+const SCHEMAS_FILE_NAME: &str = "graphql_schemas.json";
+
+/// Query de introspecção: tipos com campos, argumentos, campos de input e valores de enum
+pub const INTROSPECTION_QUERY: &str = "query IntrospectionQuery {
+  __schema {
+    queryType { name }
+    mutationType { name }
+    subscriptionType { name }
+    types {
+      kind
+      name
+      fields(includeDeprecated: true) {
+        name
+        args { name type { ...TypeRef } }
+        type { ...TypeRef }
+      }
+      inputFields { name type { ...TypeRef } }
+      enumValues(includeDeprecated: true) { name }
+    }
+  }
+}
+
+fragment TypeRef on __Type {
+  kind
+  name
+  ofType { kind name ofType { kind name ofType { kind name ofType { kind name } } } }
+}";
+
+/// Nomes das operações (`query`, `mutation`, `subscription`) do documento, na ordem.
+///
+/// Fragments e a forma abreviada `{ ... }` não têm nome e não entram na lista.
+pub fn operations(document: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut depth = 0usize;
+    let mut expects_name = false;
+    let mut chars = document.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        match c {
+            '#' => while chars.next_if(|&(_, c)| c != '\n').is_some() {},
+            '"' => skip_string(document, start, &mut chars),
+            '{' | '(' => {
+                depth += 1;
+                expects_name = false;
+            }
+            '}' | ')' => depth = depth.saturating_sub(1),
+            c if c.is_alphabetic() || c == '_' => {
+                let mut end = start + c.len_utf8();
+                while let Some(&(i, c)) = chars.peek() {
+                    if !(c.is_alphanumeric() || c == '_') {
+                        break;
+                    }
+                    end = i + c.len_utf8();
+                    chars.next();
+                }
+
+                let word = &document[start..end];
+                if depth > 0 {
+                    continue;
+                }
+                if expects_name {
+                    names.push(word.to_string());
+                    expects_name = false;
+                } else {
+                    expects_name = matches!(word, "query" | "mutation" | "subscription");
+                }
+            }
+            _ => {}
+        }
+    }
+
+    names
+}
+
+/// Avança até o fim de uma string (`"..."` ou bloco `"""..."""`)
+fn skip_string(
+    document: &str,
+    start: usize,
+    chars: &mut std::iter::Peekable<std::str::CharIndices<'_>>,
+) {
+    if document[start..].starts_with("\"\"\"") {
+        chars.next();
+        chars.next();
+        let mut quotes = 0;
+        for (_, c) in chars.by_ref() {
+            quotes = if c == '"' { quotes + 1 } else { 0 };
+            if quotes == 3 {
+                break;
+            }
+        }
+    } else {
+        while let Some((_, c)) = chars.next() {
+            match c {
+                '\\' => {
+                    chars.next();
+                }
+                '"' | '\n' => break,
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Operação enviada em `operationName`: só é necessária quando o documento tem várias
+fn operation_name(body: &GraphQlBody) -> Result<Option<String>, String> {
+    let operations = operations(&body.query);
+    if operations.len() < 2 {
+        return Ok(None);
+    }
+
+    let selected = body.operation_name.trim();
+    if operations.iter().any(|name| name == selected) {
+        Ok(Some(selected.to_string()))
+    } else {
+        Err(format!(
+            "Select the operation to run: the document has {} operations",
+            operations.len()
+        ))
+    }
+}
+
+/// Variáveis como objeto JSON; vazias são omitidas
+fn variables(body: &GraphQlBody) -> Result<Option<Map<String, Value>>, String> {
+    if body.variables.trim().is_empty() {
+        return Ok(None);
+    }
+
+    match serde_json::from_str::<Value>(&body.variables) {
+        Ok(Value::Object(map)) => Ok(Some(map)),
+        Ok(Value::Null) => Ok(None),
+        Ok(_) => Err("Invalid GraphQL variables: expected a JSON object".to_string()),
+        Err(e) => Err(format!("Invalid GraphQL variables: {}", e)),
+    }
+}
+
+/// Valida o documento e as variáveis antes do envio
+pub fn validate(body: &GraphQlBody) -> Result<(), String> {
+    payload(body).map(|_| ())
+}
+
+/// Body padrão `{"query", "variables", "operationName"}` de um POST GraphQL
+pub fn payload(body: &GraphQlBody) -> Result<Value, String> {
+    let mut payload = json!({ "query": body.query });
+    if let Some(variables) = variables(body)? {
+        payload["variables"] = Value::Object(variables);
+    }
+    if let Some(name) = operation_name(body)? {
+        payload["operationName"] = Value::String(name);
+    }
+    Ok(payload)
+}
+
+/// Query params de um GET GraphQL: `query`, `variables` (JSON) e `operationName`
+pub fn query_params(body: &GraphQlBody) -> Result<Vec<KeyValue>, String> {
+    let mut params = vec![KeyValue::new("query".to_string(), body.query.clone())];
+    if let Some(variables) = variables(body)? {
+        params.push(KeyValue::new(
+            "variables".to_string(),
+            Value::Object(variables).to_string(),
+        ));
+    }
+    if let Some(name) = operation_name(body)? {
+        params.push(KeyValue::new("operationName".to_string(), name));
+    }
+    Ok(params)
+}
+
+/// Retorna a requisição pronta para envio: um GET GraphQL leva a operação nos query params.
+///
+/// # Errors
+/// Retorna erro se as variáveis não forem um objeto JSON ou se faltar escolher a operação.
+pub fn apply(request: &HttpRequest) -> Result<HttpRequest, String> {
+    let mut applied = request.clone();
+    if request.body_type != BodyType::GraphQl {
+        return Ok(applied);
+    }
+
+    if request.method == HTTPMethod::GET {
+        applied.query_params.extend(query_params(&request.graphql)?);
+    } else if request.has_body() {
+        validate(&request.graphql)?;
+    }
+    Ok(applied)
+}
+
+/// Mensagens de `errors` de uma resposta GraphQL, com posição e caminho quando informados
+pub fn errors(response_body: &str) -> Vec<String> {
+    let Ok(value) = serde_json::from_str::<Value>(response_body) else {
+        return Vec::new();
+    };
+    let Some(errors) = value.get("errors").and_then(Value::as_array) else {
+        return Vec::new();
+    };
+
+    errors
+        .iter()
+        .map(|error| {
+            let mut message = error
+                .get("message")
+                .and_then(Value::as_str)
+                .unwrap_or("Unknown error")
+                .to_string();
+
+            if let Some(location) = error.pointer("/locations/0") {
+                let line = location.get("line").and_then(Value::as_u64).unwrap_or(0);
+                let column = location.get("column").and_then(Value::as_u64).unwrap_or(0);
+                message.push_str(&format!(" (line {}, column {})", line, column));
+            }
+
+            if let Some(path) = error.get("path").and_then(Value::as_array) {
+                let path: Vec<String> = path
+                    .iter()
+                    .map(|segment| match segment {
+                        Value::String(s) => s.clone(),
+                        other => other.to_string(),
+                    })
+                    .collect();
+                message.push_str(&format!(" at {}", path.join(".")));
+            }
+
+            message
+        })
+        .collect()
+}
+
+/// Schema obtido pela introspecção, resumido para listagem no editor
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct GraphQlSchema {
+    pub query_type: Option<String>,
+    pub mutation_type: Option<String>,
+    pub subscription_type: Option<String>,
+    /// Tipos do schema, sem os internos (`__Type`, `__Schema`...)
+    pub types: Vec<SchemaType>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchemaType {
+    pub name: String,
+    /// `OBJECT`, `INPUT_OBJECT`, `ENUM`, `SCALAR`...
+    pub kind: String,
+    /// Campos como `user(id: ID!): User`, campos de input ou valores de enum
+    pub fields: Vec<String>,
+}
+
+impl GraphQlSchema {
+    /// Interpreta a resposta da `INTROSPECTION_QUERY`
+    pub fn from_introspection(response_body: &str) -> Result<Self, String> {
+        let value: Value = serde_json::from_str(response_body)
+            .map_err(|e| format!("Introspection response is not JSON: {}", e))?;
+
+        let Some(schema) = value.pointer("/data/__schema") else {
+            return Err(match errors(response_body).first() {
+                Some(error) => format!("Introspection failed: {}", error),
+                None => "Introspection response has no schema".to_string(),
+            });
+        };
+
+        let root = |key: &str| {
+            schema
+                .pointer(&format!("/{}/name", key))
+                .and_then(Value::as_str)
+                .map(str::to_string)
+        };
+
+        let types = schema
+            .get("types")
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(schema_type)
+            .filter(|t| !t.name.starts_with("__"))
+            .collect();
+
+        Ok(Self {
+            query_type: root("queryType"),
+            mutation_type: root("mutationType"),
+            subscription_type: root("subscriptionType"),
+            types,
+        })
+    }
+
+    /// Tipos raiz primeiro (Query, Mutation, Subscription), depois os demais por nome
+    pub fn sorted_types(&self) -> Vec<&SchemaType> {
+        let roots = [
+            &self.query_type,
+            &self.mutation_type,
+            &self.subscription_type,
+        ];
+        let rank = |t: &SchemaType| {
+            roots
+                .iter()
+                .position(|root| root.as_deref() == Some(t.name.as_str()))
+                .unwrap_or(roots.len())
+        };
+
+        let mut types: Vec<&SchemaType> = self.types.iter().collect();
+        types.sort_by(|a, b| rank(a).cmp(&rank(b)).then_with(|| a.name.cmp(&b.name)));
+        types
+    }
+}
+
+fn schema_type(value: &Value) -> Option<SchemaType> {
+    let name = value.get("name")?.as_str()?.to_string();
+    let kind = value.get("kind")?.as_str()?.to_string();
+    let list = |key: &str| {
+        value
+            .get(key)
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    };
+
+    let mut fields: Vec<String> = list("fields")
+        .iter()
+        .chain(list("inputFields"))
+        .filter_map(|field| {
+            let name = field.get("name")?.as_str()?;
+            let args: Vec<String> = field
+                .get("args")
+                .and_then(Value::as_array)
+                .map(Vec::as_slice)
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|arg| {
+                    Some(format!(
+                        "{}: {}",
+                        arg.get("name")?.as_str()?,
+                        type_ref(arg.get("type")?)
+                    ))
+                })
+                .collect();
+
+            let args = if args.is_empty() {
+                String::new()
+            } else {
+                format!("({})", args.join(", "))
+            };
+            Some(format!(
+                "{}{}: {}",
+                name,
+                args,
+                type_ref(field.get("type")?)
+            ))
+        })
+        .collect();
+
+    fields.extend(
+        list("enumValues")
+            .iter()
+            .filter_map(|value| value.get("name")?.as_str().map(str::to_string)),
+    );
+
+    Some(SchemaType { name, kind, fields })
+}
+
+/// Tipo de um campo na notação do GraphQL: `[User!]!`
+fn type_ref(value: &Value) -> String {
+    let inner = || value.get("ofType").map(type_ref).unwrap_or_default();
+    match value.get("kind").and_then(Value::as_str) {
+        Some("NON_NULL") => format!("{}!", inner()),
+        Some("LIST") => format!("[{}]", inner()),
+        _ => value
+            .get("name")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string(),
+    }
+}
+
+/// Schemas obtidos por introspecção, por URL do endpoint, persistidos no diretório de config
+pub struct SchemaCache {
+    schemas: BTreeMap<String, GraphQlSchema>,
+    file_path: Option<PathBuf>,
+}
+
+impl SchemaCache {
+    pub fn new() -> Self {
+        let file_path = storage::config_file_path(SCHEMAS_FILE_NAME);
+        let schemas = storage::load_json(&file_path, "GraphQL schemas").unwrap_or_default();
+
+        Self {
+            schemas,
+            file_path: Some(file_path),
+        }
+    }
+
+    pub fn get(&self, url: &str) -> Option<&GraphQlSchema> {
+        self.schemas.get(url.trim())
+    }
+
+    pub fn insert(&mut self, url: &str, schema: GraphQlSchema) -> Result<(), String> {
+        self.schemas.insert(url.trim().to_string(), schema);
+        match &self.file_path {
+            Some(path) => storage::save_json(path, &self.schemas, "GraphQL schemas"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Default for SchemaCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOCUMENT: &str = r#"
+        # query Commented { ignored }
+        query ListUsers($first: Int) {
+          users(first: $first, filter: "mutation Fake") { ...UserFields }
+        }
+
+        mutation CreateUser { createUser(name: """block "quoted" query X""") { id } }
+
+        fragment UserFields on User { id name }
+    "#;
+
+    #[test]
+    fn test_operations_skip_comments_strings_and_fragments() {
+        assert_eq!(operations(DOCUMENT), vec!["ListUsers", "CreateUser"]);
+        assert_eq!(operations(INTROSPECTION_QUERY), vec!["IntrospectionQuery"]);
+        assert!(operations("{ me { id } }").is_empty());
+    }
+
+    #[test]
+    fn test_payload_and_get_params() {
+        let mut body = GraphQlBody {
+            query: DOCUMENT.to_string(),
+            variables: r#"{"first": 10}"#.to_string(),
+            operation_name: String::new(),
+        };
+        assert_eq!(
+            validate(&body).unwrap_err(),
+            "Select the operation to run: the document has 2 operations"
+        );
+
+        body.operation_name = "ListUsers".to_string();
+        assert_eq!(
+            payload(&body).unwrap(),
+            json!({ "query": DOCUMENT, "variables": { "first": 10 }, "operationName": "ListUsers" })
+        );
+
+        let request = HttpRequest {
+            method: HTTPMethod::GET,
+            url: "https://api.example.com/graphql".into(),
+            body_type: BodyType::GraphQl,
+            graphql: body.clone(),
+            ..HttpRequest::default()
+        };
+        let applied = apply(&request).unwrap();
+        assert_eq!(applied.query_params[1].value, r#"{"first":10}"#);
+        assert_eq!(applied.query_params[2].key, "operationName");
+        assert!(!applied.has_body());
+
+        body.variables = "[1]".to_string();
+        assert!(
+            validate(&body)
+                .unwrap_err()
+                .contains("expected a JSON object")
+        );
+    }
+
+    #[test]
+    fn test_errors_from_response() {
+        let body = r#"{"data":{"user":null},"errors":[{"message":"Not found","locations":[{"line":2,"column":3}],"path":["user",0,"name"]}]}"#;
+        assert_eq!(
+            errors(body),
+            vec!["Not found (line 2, column 3) at user.0.name"]
+        );
+        assert!(errors(r#"{"data":{"user":{"id":1}}}"#).is_empty());
+        assert!(errors("not json").is_empty());
+    }
+
+    #[test]
+    fn test_schema_from_introspection() {
+        let response = include_str!("../../tests/fixtures/graphql/introspection.json");
+        let schema = GraphQlSchema::from_introspection(response).unwrap();
+
+        let types = schema.sorted_types();
+        assert_eq!(types[0].name, "Query");
+        assert_eq!(
+            types[0].fields,
+            vec!["user(id: ID!): User", "users(first: Int): [User!]!"]
+        );
+        assert!(types.iter().all(|t| !t.name.starts_with("__")));
+
+        let role = types.iter().find(|t| t.name == "Role").unwrap();
+        assert_eq!(role.fields, vec!["ADMIN", "MEMBER"]);
+
+        assert_eq!(
+            GraphQlSchema::from_introspection(
+                r#"{"errors":[{"message":"Introspection disabled"}]}"#
+            )
+            .unwrap_err(),
+            "Introspection failed: Introspection disabled"
+        );
+    }
+}
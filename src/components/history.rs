@@ -1,15 +1,18 @@
 use super::enums::{HistoryItem, HttpRequest, HttpResponse};
-use std::fs;
-use std::path::PathBuf;
+use super::storage;
+use super::websocket::WsMessage;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 const MAX_HISTORY_ITEMS: usize = 50;
 const HISTORY_FILE_NAME: &str = "history.json";
-const APP_NAME: &str = "http-client";
 
 #[derive(Debug, Clone)]
 pub struct RequestHistory {
     items: Vec<HistoryItem>,
+    /// Índices marcados para exportação; limpo sempre que a lista muda
+    selected: BTreeSet<usize>,
     file_path: PathBuf,
 }
 
@@ -18,56 +21,102 @@ impl RequestHistory {
         let file_path = Self::get_history_file_path();
         let items = Self::load_from_file(&file_path).unwrap_or_default();
 
-        Self { items, file_path }
+        Self {
+            items,
+            selected: BTreeSet::new(),
+            file_path,
+        }
     }
 
     fn get_history_file_path() -> PathBuf {
-        let config_dir = dirs::config_dir()
-            .map(|dir| dir.join(APP_NAME))
-            .unwrap_or_else(|| PathBuf::from("."));
-
-        let _ = fs::create_dir_all(&config_dir);
-
-        config_dir.join(HISTORY_FILE_NAME)
+        storage::config_file_path(HISTORY_FILE_NAME)
     }
 
-    fn load_from_file(path: &PathBuf) -> Result<Vec<HistoryItem>, String> {
-        if !path.exists() {
-            return Ok(Vec::new());
-        }
-
-        let contents =
-            fs::read_to_string(path).map_err(|e| format!("Failed to read history file: {}", e))?;
-
-        let items: Vec<HistoryItem> = serde_json::from_str(&contents)
-            .map_err(|e| format!("Failed to parse history file: {}", e))?;
-
-        Ok(items)
+    fn load_from_file(path: &Path) -> Result<Vec<HistoryItem>, String> {
+        storage::load_json(path, "history")
     }
 
     fn save_to_file(&self) -> Result<(), String> {
-        let json = serde_json::to_string_pretty(&self.items)
-            .map_err(|e| format!("Failed to serialize history: {}", e))?;
+        storage::save_json(&self.file_path, &self.items, "history")
+    }
 
-        fs::write(&self.file_path, json)
-            .map_err(|e| format!("Failed to write history file: {}", e))?;
+    pub fn add_item(
+        &mut self,
+        request: HttpRequest,
+        resolved: HttpRequest,
+        response: HttpResponse,
+    ) {
+        self.push(request, resolved, Some(response), None);
+    }
 
-        Ok(())
+    /// Registra uma requisição cancelada pelo usuário
+    pub fn add_cancelled(&mut self, request: HttpRequest, resolved: HttpRequest) {
+        self.push(request, resolved, None, None);
     }
 
-    pub fn add_item(&mut self, request: HttpRequest, response: HttpResponse) {
+    /// Registra uma sessão WebSocket encerrada, com a resposta do handshake e as mensagens
+    pub fn add_websocket(
+        &mut self,
+        request: HttpRequest,
+        resolved: HttpRequest,
+        handshake: Option<HttpResponse>,
+        messages: Vec<WsMessage>,
+    ) {
+        self.push(request, resolved, handshake, Some(messages));
+    }
+
+    fn push(
+        &mut self,
+        request: HttpRequest,
+        resolved: HttpRequest,
+        response: Option<HttpResponse>,
+        websocket: Option<Vec<WsMessage>>,
+    ) {
         let timestamp = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .unwrap()
             .as_secs() as i64;
 
+        let resolved_request = (resolved != request).then_some(resolved);
+        // O arquivo temporário pertence só à resposta exibida, que o remove ao ser trocada
+        let response = response.map(|response| HttpResponse {
+            spill_file: None,
+            ..response
+        });
+
         let item = HistoryItem {
             request,
+            resolved_request,
             response,
             timestamp,
+            websocket,
         };
 
         self.items.insert(0, item);
+        self.items_changed();
+    }
+
+    /// Mescla itens importados (ex.: de um HAR) mantendo a ordem do mais recente
+    /// para o mais antigo. Retorna quantos itens importados foram mantidos.
+    pub fn import(&mut self, items: Vec<HistoryItem>) -> usize {
+        let mut merged: Vec<(bool, HistoryItem)> = self
+            .items
+            .drain(..)
+            .map(|item| (false, item))
+            .chain(items.into_iter().map(|item| (true, item)))
+            .collect();
+
+        merged.sort_by_key(|(_, item)| std::cmp::Reverse(item.timestamp));
+        merged.truncate(MAX_HISTORY_ITEMS);
+
+        let kept = merged.iter().filter(|(imported, _)| *imported).count();
+        self.items = merged.into_iter().map(|(_, item)| item).collect();
+        self.items_changed();
+        kept
+    }
+
+    fn items_changed(&mut self) {
+        self.selected.clear();
 
         if self.items.len() > MAX_HISTORY_ITEMS {
             self.items.truncate(MAX_HISTORY_ITEMS);
@@ -86,8 +135,35 @@ impl RequestHistory {
         self.items.get(index)
     }
 
+    pub fn toggle_selected(&mut self, index: usize) {
+        if !self.selected.remove(&index) && index < self.items.len() {
+            self.selected.insert(index);
+        }
+    }
+
+    pub fn is_selected(&self, index: usize) -> bool {
+        self.selected.contains(&index)
+    }
+
+    pub fn selected_count(&self) -> usize {
+        self.selected.len()
+    }
+
+    /// Itens a exportar: a seleção, ou todo o histórico se nada estiver marcado
+    pub fn items_to_export(&self) -> Vec<HistoryItem> {
+        if self.selected.is_empty() {
+            return self.items.clone();
+        }
+
+        self.selected
+            .iter()
+            .filter_map(|&i| self.items.get(i).cloned())
+            .collect()
+    }
+
     pub fn clear(&mut self) {
         self.items.clear();
+        self.selected.clear();
         if let Err(e) = self.save_to_file() {
             eprintln!("Warning: Failed to save history after clear: {}", e);
         }
@@ -115,3 +191,49 @@ impl Default for RequestHistory {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history() -> RequestHistory {
+        RequestHistory {
+            items: Vec::new(),
+            selected: BTreeSet::new(),
+            file_path: std::env::temp_dir().join("http-client-history-test.json"),
+        }
+    }
+
+    fn item(timestamp: i64) -> HistoryItem {
+        HistoryItem {
+            request: HttpRequest::default(),
+            resolved_request: None,
+            response: None,
+            timestamp,
+            websocket: None,
+        }
+    }
+
+    #[test]
+    fn test_import_merges_by_timestamp() {
+        let mut history = history();
+        history.import(vec![item(20)]);
+        history.toggle_selected(0);
+        assert_eq!(history.selected_count(), 1);
+
+        let kept = history.import(vec![item(10), item(30)]);
+        assert_eq!(kept, 2);
+        assert_eq!(history.selected_count(), 0);
+
+        let timestamps: Vec<i64> = history.get_items().iter().map(|i| i.timestamp).collect();
+        assert_eq!(timestamps, vec![30, 20, 10]);
+
+        let kept = history.import(
+            (0..MAX_HISTORY_ITEMS as i64)
+                .map(|t| item(t - 100))
+                .collect(),
+        );
+        assert_eq!(kept, MAX_HISTORY_ITEMS - 3);
+        assert_eq!(history.len(), MAX_HISTORY_ITEMS);
+    }
+}
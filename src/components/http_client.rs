@@ -1,39 +1,618 @@
+use bytes::BytesMut;
+use chrono::Utc;
 use reqwest::Client;
+use reqwest::header::{CONTENT_TYPE, HeaderValue, LOCATION, SET_COOKIE};
+use reqwest::multipart::{Form, Part};
+use reqwest::redirect::Policy;
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tokio::runtime::Runtime;
+use tokio::io::AsyncWriteExt;
+use tokio::net::TcpListener;
 use url::Url;
 
-use super::enums::{HttpRequest, HttpResponse, KeyValue};
+use super::auth::{self, DigestChallenge};
+use super::cookies::{CookieJar, ReceivedCookie};
+use super::enums::{
+    ApiKeyLocation, Auth, BodyType, DEFAULT_TIMEOUT_MS, DownloadProgress, FormPartKind, HTTPMethod,
+    HttpRequest, HttpResponse, KeyValue, OAuth2Config, RedirectHop, RedirectPolicy,
+};
+use super::graphql;
+use super::oauth2::{self, OAuth2Token, Pkce, TokenCache, TokenGrant};
+use super::sigv4::{self, SigningParams};
+use super::sse::EventStream;
+use super::utils::{mime, url_validator};
 
+/// Tempo máximo para o usuário concluir o login no navegador
+const OAUTH2_AUTHORIZATION_TIMEOUT: Duration = Duration::from_secs(300);
+
+const TIMEOUT_ERROR: &str = "Request timeout - the server took too long to respond";
+
+/// Headers com credenciais removidos ao seguir um redirect para outra origem
+const CREDENTIAL_HEADERS: [&str; 3] = ["authorization", "proxy-authorization", "cookie"];
+
+/// Início do body exibido enquanto o download continua
+pub const PREVIEW_BYTES: usize = 64 * 1024;
+
+/// Intervalo mínimo entre dois avisos de andamento do download
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Body montado no momento do envio: arquivos só são lidos agora
+enum Payload {
+    Empty,
+    Bytes(Vec<u8>),
+    Multipart(Form),
+}
+
+/// Content-Type multipart do usuário com o boundary do body gerado
+fn with_boundary(content_type: &str, boundary: &str) -> String {
+    let params = content_type
+        .split(';')
+        .filter(|param| !param.trim().to_ascii_lowercase().starts_with("boundary="))
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join("; ");
+    format!("{}; boundary={}", params, boundary)
+}
+
+/// Resposta final com o que foi registrado no caminho até ela
+struct Exchange {
+    response: reqwest::Response,
+    /// Requisição do último salto (método, body e credenciais ajustados pelos redirects)
+    request: HttpRequest,
+    redirects: Vec<RedirectHop>,
+    cookies: Vec<ReceivedCookie>,
+}
+
+/// Arquivo temporário com o body completo de uma resposta truncada, removido quando a
+/// última cópia da resposta é descartada ou quando a leitura falha no meio
+#[derive(Debug)]
+pub struct SpillFile(PathBuf);
+
+impl SpillFile {
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+/// Os redirects são seguidos por `execute`, conforme o `RedirectPolicy` da requisição
 #[derive(Clone)]
 pub struct HttpClient {
     client: Client,
+    oauth2_tokens: TokenCache,
+    cookies: CookieJar,
 }
 
 impl HttpClient {
     pub fn new() -> Self {
+        Self::with_stores(TokenCache::new(), CookieJar::new())
+    }
+
+    /// Cliente com cache de tokens e cookie jar sem arquivo, para testes
+    #[cfg(test)]
+    pub fn in_memory() -> Self {
+        Self::with_stores(TokenCache::in_memory(), CookieJar::in_memory())
+    }
+
+    fn with_stores(oauth2_tokens: TokenCache, cookies: CookieJar) -> Self {
         Self {
             client: Client::builder()
+                .redirect(Policy::none())
                 .build()
                 .expect("Failed to create HTTP client"),
+            oauth2_tokens,
+            cookies,
+        }
+    }
+
+    pub fn oauth2_tokens(&self) -> &TokenCache {
+        &self.oauth2_tokens
+    }
+
+    pub fn cookies(&self) -> &CookieJar {
+        &self.cookies
+    }
+
+    /// Envia a requisição reaproveitando o pool de conexões do `Client`.
+    ///
+    /// Deve ser executado no runtime Tokio do iced; descartar o future
+    /// interrompe a requisição em andamento.
+    pub async fn send(&self, request: HttpRequest) -> Result<HttpResponse, String> {
+        self.send_with_progress(request, |_| {}).await
+    }
+
+    /// Como `send`, informando `on_progress` enquanto o body da resposta é recebido.
+    ///
+    /// Respostas `text/event-stream` não têm o body lido: a conexão fica em
+    /// `HttpResponse::event_stream` para o viewer de eventos.
+    pub async fn send_with_progress(
+        &self,
+        request: HttpRequest,
+        mut on_progress: impl FnMut(DownloadProgress) + Send,
+    ) -> Result<HttpResponse, String> {
+        let start = Instant::now();
+        let (request, exchange) = self.respond(request).await?;
+        let Exchange {
+            response,
+            redirects,
+            cookies,
+            ..
+        } = exchange;
+        let duration_ms = start.elapsed().as_millis();
+
+        if Self::is_event_stream(&response) {
+            let mut http_response = HttpResponse::new(
+                response.status().as_u16(),
+                response
+                    .status()
+                    .canonical_reason()
+                    .unwrap_or("Unknown")
+                    .to_string(),
+                Self::extract_headers(response.headers()),
+                bytes::Bytes::new(),
+                duration_ms,
+            );
+            http_response.event_stream = Some(EventStream::new(response));
+            http_response.redirects = redirects;
+            http_response.cookies = cookies;
+            return Ok(http_response);
+        }
+
+        let limit = usize::try_from(request.response_limit_mb.max(1))
+            .unwrap_or(usize::MAX)
+            .saturating_mul(1024 * 1024);
+        // No body, o timeout vale para o intervalo entre blocos: downloads lentos mas
+        // contínuos terminam mesmo passando do tempo total
+        let idle = Duration::from_millis(request.timeout_ms);
+        let mut http_response =
+            Self::process_response(response, duration_ms, idle, limit, &mut on_progress).await?;
+        http_response.redirects = redirects;
+        http_response.cookies = cookies;
+        Ok(http_response)
+    }
+
+    /// Reabre um event stream (reconexão do viewer SSE), sem timeout para o body
+    pub async fn open_event_stream(
+        &self,
+        request: HttpRequest,
+    ) -> Result<reqwest::Response, String> {
+        let (_, Exchange { response, .. }) = self.respond(request).await?;
+        if Self::is_event_stream(&response) {
+            Ok(response)
+        } else {
+            Err(format!(
+                "Expected an event stream, got {} {}",
+                response.status().as_u16(),
+                response
+                    .headers()
+                    .get(CONTENT_TYPE)
+                    .and_then(|value| value.to_str().ok())
+                    .unwrap_or("without Content-Type")
+            ))
+        }
+    }
+
+    fn is_event_stream(response: &reqwest::Response) -> bool {
+        response.status().is_success()
+            && response
+                .headers()
+                .get(CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .is_some_and(|value| mime::essence(value) == "text/event-stream")
+    }
+
+    /// Envia a requisição até receber os headers da resposta final (depois dos
+    /// desafios Digest e da renovação do token OAuth 2.0). Retorna a requisição
+    /// enviada e a troca com o servidor.
+    async fn respond(&self, request: HttpRequest) -> Result<(HttpRequest, Exchange), String> {
+        let mut request = auth::apply(&graphql::apply(&request)?);
+        let full_url = Self::full_url(&request)?;
+        let deadline = tokio::time::Instant::now() + Duration::from_millis(request.timeout_ms);
+
+        let oauth2 = match &request.auth {
+            Auth::OAuth2(config) => Some(config.clone()),
+            _ => None,
+        };
+        let mut token_from_cache = false;
+        if let Some(config) = &oauth2 {
+            let (token, cached) = self.oauth2_access_token(config, false).await?;
+            auth::set_header(&mut request, "Authorization", format!("Bearer {}", token));
+            token_from_cache = cached;
+        }
+
+        let mut exchange = self.execute(&request, &full_url, deadline).await?;
+
+        // O desafio vem do último salto: responde nele, com o método e a URL finais.
+        // Credenciais removidas num redirect para outra origem não são reenviadas.
+        if let Auth::Digest { username, password } = &exchange.request.auth
+            && let Some(challenge) = Self::digest_challenge(&exchange.response)
+        {
+            let url = exchange.response.url().to_string();
+            let mut retry = exchange.request.clone();
+            let authorization = challenge.authorization(
+                username,
+                password,
+                &retry.method.to_string(),
+                &Self::request_uri(&url)?,
+                &auth::new_cnonce(),
+            )?;
+            auth::set_header(&mut retry, "Authorization", authorization);
+
+            let retried = self.execute(&retry, &url, deadline).await?;
+            exchange.redirects.extend(retried.redirects);
+            exchange.cookies.extend(retried.cookies);
+            exchange = Exchange {
+                redirects: exchange.redirects,
+                cookies: exchange.cookies,
+                ..retried
+            };
+        }
+
+        // Token do cache recusado (revogado ou expirado antes do previsto): renova uma vez.
+        // Se não for possível renovar, o 401 original é exibido.
+        if let Some(config) = &oauth2
+            && token_from_cache
+            && exchange.response.status() == reqwest::StatusCode::UNAUTHORIZED
+            && let Ok((token, _)) = self.oauth2_access_token(config, true).await
+        {
+            auth::set_header(&mut request, "Authorization", format!("Bearer {}", token));
+            exchange = self.execute(&request, &full_url, deadline).await?;
         }
+
+        Ok((request, exchange))
     }
 
-    pub fn send_request(&self, request: HttpRequest) -> Result<HttpResponse, String> {
-        let runtime = Runtime::new().unwrap();
-        runtime.block_on(async {
-            let validated_url = Self::validate_and_normalize_url(&request.url)?;
-            let full_url = Self::build_url_with_params(&validated_url, &request.query_params)?;
-            let headers = Self::build_headers(&request.headers)?;
+    /// Envia a requisição seguindo os redirects conforme `request.redirects`.
+    /// Ao atingir `max_hops`, a última resposta 3xx é devolvida como final.
+    ///
+    /// Em cada salto os cookies do jar são enviados (se `send_cookies`) e os
+    /// `Set-Cookie` recebidos são guardados.
+    async fn execute(
+        &self,
+        request: &HttpRequest,
+        full_url: &str,
+        deadline: tokio::time::Instant,
+    ) -> Result<Exchange, String> {
+        let policy = request.redirects;
+        let mut request = request.clone();
+        let mut url = full_url.to_string();
+        let mut redirects = Vec::new();
+        let mut cookies = Vec::new();
 
+        loop {
             let start = Instant::now();
-            let req_builder = self.build_request_with_body(&request, &full_url, headers);
-            let response = req_builder.send().await.map_err(Self::format_error)?;
-            let duration_ms = start.elapsed().as_millis();
+            let with_cookies = request
+                .send_cookies
+                .then(|| self.cookies.header_for(&url))
+                .flatten()
+                .map(|jar_cookies| Self::with_cookies(&request, &jar_cookies));
+            let response = self
+                .execute_once(with_cookies.as_ref().unwrap_or(&request), &url, deadline)
+                .await?;
+            cookies.extend(
+                self.cookies.store(
+                    &url,
+                    response
+                        .headers()
+                        .get_all(SET_COOKIE)
+                        .iter()
+                        .filter_map(|value| value.to_str().ok()),
+                ),
+            );
 
-            Self::process_response(response, duration_ms).await
-        })
+            let location = response
+                .headers()
+                .get(LOCATION)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|location| Url::parse(&url).ok()?.join(location).ok());
+            let Some(location) = location.filter(|_| {
+                policy.follow
+                    && response.status().is_redirection()
+                    && redirects.len() < policy.max_hops as usize
+            }) else {
+                return Ok(Exchange {
+                    response,
+                    request,
+                    redirects,
+                    cookies,
+                });
+            };
+
+            redirects.push(RedirectHop {
+                method: request.method.to_string(),
+                url: url.clone(),
+                status: response.status().as_u16(),
+                status_text: response
+                    .status()
+                    .canonical_reason()
+                    .unwrap_or("Unknown")
+                    .to_string(),
+                location: location.to_string(),
+                headers: Self::extract_headers(response.headers()),
+                duration_ms: start.elapsed().as_millis(),
+            });
+
+            Self::redirect_request(
+                &mut request,
+                response.status().as_u16(),
+                &url,
+                &location,
+                policy,
+            );
+            url = location.into();
+        }
+    }
+
+    /// Cookies do jar junto aos de um header `Cookie` digitado pelo usuário
+    fn with_cookies(request: &HttpRequest, jar_cookies: &str) -> HttpRequest {
+        let mut request = request.clone();
+        match request
+            .headers
+            .iter_mut()
+            .find(|h| h.enabled && h.key.eq_ignore_ascii_case("cookie"))
+        {
+            Some(header) => header.value = format!("{}; {}", header.value, jar_cookies),
+            None => request
+                .headers
+                .push(KeyValue::new("Cookie".to_string(), jar_cookies.to_string())),
+        }
+        request
+    }
+
+    /// Ajusta a requisição para o próximo salto (RFC 9110, seção 15.4)
+    fn redirect_request(
+        request: &mut HttpRequest,
+        status: u16,
+        from: &str,
+        to: &Url,
+        policy: RedirectPolicy,
+    ) {
+        let keeps_method = match status {
+            307 | 308 => policy.keep_method,
+            303 => request.method == HTTPMethod::HEAD,
+            // 301/302: como navegadores e curl, só POST vira GET
+            _ => request.method != HTTPMethod::POST,
+        };
+        if !keeps_method {
+            request.method = HTTPMethod::GET;
+            request.body_type = BodyType::None;
+            request.force_body = false;
+            request.headers.retain(|h| {
+                !h.key.eq_ignore_ascii_case("content-type")
+                    && !h.key.eq_ignore_ascii_case("content-length")
+            });
+        }
+
+        let same_origin = Url::parse(from).is_ok_and(|from| from.origin() == to.origin());
+        if policy.strip_auth_cross_origin && !same_origin {
+            let api_key = match &request.auth {
+                Auth::ApiKey {
+                    name,
+                    location: ApiKeyLocation::Header,
+                    ..
+                } => Some(name.trim().to_string()),
+                _ => None,
+            };
+            request.headers.retain(|h| {
+                !CREDENTIAL_HEADERS
+                    .iter()
+                    .any(|name| h.key.eq_ignore_ascii_case(name))
+                    && api_key
+                        .as_deref()
+                        .is_none_or(|name| !h.key.eq_ignore_ascii_case(name))
+            });
+            request.auth = Auth::None;
+        }
+    }
+
+    /// O timeout cobre só até `deadline`: um event stream continua aberto depois dele
+    async fn execute_once(
+        &self,
+        request: &HttpRequest,
+        full_url: &str,
+        deadline: tokio::time::Instant,
+    ) -> Result<reqwest::Response, String> {
+        let headers = Self::build_headers(&request.headers)?;
+        let payload = Self::payload(request).await?;
+        let req_builder = self.build_request_with_body(request, full_url, headers, payload)?;
+        tokio::time::timeout_at(deadline, req_builder.send())
+            .await
+            .map_err(|_| TIMEOUT_ERROR.to_string())?
+            .map_err(Self::format_error)
+    }
+
+    async fn payload(request: &HttpRequest) -> Result<Payload, String> {
+        if !request.has_body() {
+            return Ok(Payload::Empty);
+        }
+
+        match request.body_type {
+            BodyType::FormData => Ok(Payload::Multipart(Self::multipart_form(request).await?)),
+            BodyType::Binary => {
+                let path = request.binary_file.trim();
+                let contents = tokio::fs::read(path)
+                    .await
+                    .map_err(|e| format!("Failed to read file {}: {}", path, e))?;
+                Ok(Payload::Bytes(contents))
+            }
+            _ => Ok(Payload::Bytes(request.encoded_body().into_bytes())),
+        }
+    }
+
+    /// Monta o multipart lendo os arquivos no momento do envio
+    async fn multipart_form(request: &HttpRequest) -> Result<Form, String> {
+        let mut form = Form::new();
+
+        for part in request.form_parts() {
+            let (mut field, default_type) = match part.kind {
+                FormPartKind::Text => (Part::text(part.value.clone()), ""),
+                FormPartKind::File => {
+                    let contents = tokio::fs::read(&part.value)
+                        .await
+                        .map_err(|e| format!("Failed to read file {}: {}", part.value, e))?;
+                    (
+                        Part::bytes(contents).file_name(part.upload_file_name()),
+                        "application/octet-stream",
+                    )
+                }
+            };
+
+            let content_type = match part.content_type.trim() {
+                "" => default_type,
+                content_type => content_type,
+            };
+            if !content_type.is_empty() {
+                field = field.mime_str(content_type).map_err(|_| {
+                    format!(
+                        "Invalid content type for form field '{}': {}",
+                        part.key, content_type
+                    )
+                })?;
+            }
+
+            form = form.part(part.key.clone(), field);
+        }
+
+        Ok(form)
+    }
+
+    /// Token válido para a configuração: do cache, renovado via refresh token
+    /// ou obtido diretamente (client credentials / password).
+    ///
+    /// Retorna também se o token veio do cache sem renovação.
+    async fn oauth2_access_token(
+        &self,
+        config: &OAuth2Config,
+        force_refresh: bool,
+    ) -> Result<(String, bool), String> {
+        let key = oauth2::cache_key(config);
+        let cached = self.oauth2_tokens.get(&key);
+
+        if let Some(token) = &cached
+            && !force_refresh
+            && !token.is_expired(oauth2::now())
+        {
+            return Ok((token.access_token.clone(), true));
+        }
+
+        let mut refreshed = None;
+        if let Some(refresh_token) = cached.and_then(|token| token.refresh_token) {
+            refreshed = self
+                .request_token(config, TokenGrant::Refresh(&refresh_token))
+                .await
+                .ok()
+                .map(|mut token| {
+                    // O servidor pode omitir um novo refresh token e manter o anterior válido
+                    token.refresh_token.get_or_insert(refresh_token);
+                    token
+                });
+        }
+
+        let token = match (refreshed, TokenGrant::direct(config.grant)) {
+            (Some(token), _) => token,
+            (None, Some(grant)) => self.request_token(config, grant).await?,
+            (None, None) => {
+                return Err(
+                    "OAuth2 token missing or expired: use Get New Token in the Auth tab to sign in"
+                        .to_string(),
+                );
+            }
+        };
+
+        self.oauth2_tokens.insert(key, token.clone());
+        Ok((token.access_token, false))
+    }
+
+    /// Obtém um token novo, inclusive pelo login no navegador, e guarda no cache
+    pub async fn fetch_oauth2_token(&self, config: OAuth2Config) -> Result<OAuth2Token, String> {
+        let token = match TokenGrant::direct(config.grant) {
+            Some(grant) => self.request_token(&config, grant).await?,
+            None => self.authorize_in_browser(&config).await?,
+        };
+
+        self.oauth2_tokens
+            .insert(oauth2::cache_key(&config), token.clone());
+        Ok(token)
+    }
+
+    /// Authorization code + PKCE com redirect para um listener em 127.0.0.1 (RFC 8252)
+    async fn authorize_in_browser(&self, config: &OAuth2Config) -> Result<OAuth2Token, String> {
+        let listener = TcpListener::bind(("127.0.0.1", 0))
+            .await
+            .map_err(|e| format!("Failed to start OAuth2 callback listener: {}", e))?;
+        let port = listener
+            .local_addr()
+            .map_err(|e| format!("Failed to start OAuth2 callback listener: {}", e))?
+            .port();
+        let redirect_uri = format!("http://127.0.0.1:{}{}", port, oauth2::CALLBACK_PATH);
+
+        let pkce = Pkce::new()?;
+        let state = oauth2::random_string(16)?;
+        let url = oauth2::authorization_url(config, &redirect_uri, &state, &pkce)?;
+        open::that_detached(&url).map_err(|e| format!("Failed to open the browser: {}", e))?;
+
+        let code = tokio::time::timeout(
+            OAUTH2_AUTHORIZATION_TIMEOUT,
+            oauth2::wait_for_code(listener, &state),
+        )
+        .await
+        .map_err(|_| "Timed out waiting for the browser sign-in".to_string())??;
+
+        self.request_token(
+            config,
+            TokenGrant::AuthorizationCode {
+                code: &code,
+                redirect_uri: &redirect_uri,
+                verifier: &pkce.verifier,
+            },
+        )
+        .await
+    }
+
+    async fn request_token(
+        &self,
+        config: &OAuth2Config,
+        grant: TokenGrant<'_>,
+    ) -> Result<OAuth2Token, String> {
+        let token_url = Self::validate_and_normalize_url(&config.token_url)
+            .map_err(|e| format!("Token URL: {}", e))?;
+        let form: String = url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(oauth2::token_form(config, &grant))
+            .finish();
+
+        let mut builder = self
+            .client
+            .post(token_url)
+            .timeout(Duration::from_millis(DEFAULT_TIMEOUT_MS))
+            .header(reqwest::header::ACCEPT, "application/json")
+            .header(
+                reqwest::header::CONTENT_TYPE,
+                "application/x-www-form-urlencoded",
+            )
+            .body(form);
+
+        if !config.client_secret.is_empty() {
+            builder = builder.header(
+                reqwest::header::AUTHORIZATION,
+                auth::basic_authorization(config.client_id.trim(), &config.client_secret),
+            );
+        }
+
+        let response = builder.send().await.map_err(Self::format_error)?;
+        let status = response.status().as_u16();
+        let body = response
+            .text()
+            .await
+            .map_err(|e| format!("Failed to read token response: {}", e))?;
+
+        oauth2::parse_token_response(status, &body, oauth2::now())
     }
 
     fn build_request_with_body(
@@ -41,32 +620,121 @@ impl HttpClient {
         request: &HttpRequest,
         url: &str,
         headers: HashMap<String, String>,
-    ) -> reqwest::RequestBuilder {
-        let mut builder = self
-            .client
-            .request(request.method.as_reqwest(), url)
-            .timeout(Duration::from_millis(request.timeout_ms));
+        payload: Payload,
+    ) -> Result<reqwest::RequestBuilder, String> {
+        let mut url = url.to_string();
+        let mut headers: Vec<(String, String)> = headers.into_iter().collect();
+
+        // O Content-Type do multipart carrega o boundary gerado pelo reqwest;
+        // um header do usuário é reaplicado depois, com esse boundary
+        let mut multipart_content_type = None;
+        if matches!(payload, Payload::Multipart(_)) {
+            multipart_content_type = headers
+                .iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case("content-type"))
+                .map(|(_, value)| value.clone());
+            headers.retain(|(key, _)| !key.eq_ignore_ascii_case("content-type"));
+        } else if let Some(content_type) = request.implicit_content_type() {
+            headers.push(("Content-Type".to_string(), content_type));
+        }
+
+        // Assina por último, sobre a URL e os headers que serão enviados
+        if let Auth::AwsSigV4(config) = &request.auth {
+            let payload = match &payload {
+                Payload::Empty => Some(&[][..]),
+                Payload::Bytes(bytes) => Some(bytes.as_slice()),
+                Payload::Multipart(_) => None,
+            };
+            let signed = sigv4::sign(
+                &SigningParams {
+                    access_key: &config.access_key,
+                    secret_key: &config.secret_key,
+                    session_token: Some(config.session_token.as_str()),
+                    region: &config.region,
+                    service: &config.service,
+                },
+                &request.method.to_string(),
+                &url,
+                &headers,
+                payload,
+                Utc::now(),
+            )?;
+
+            headers.retain(|(key, _)| {
+                !signed
+                    .headers
+                    .iter()
+                    .any(|(name, _)| name.eq_ignore_ascii_case(key))
+            });
+            headers.extend(signed.headers);
+            url = signed.url;
+        }
+
+        let mut builder = self.client.request(request.method.as_reqwest()?, url);
 
         for (key, value) in headers {
             builder = builder.header(key, value);
         }
 
-        if Self::should_include_body(request) {
-            builder = builder.body(request.body.clone());
+        match payload {
+            Payload::Empty => {}
+            Payload::Bytes(bytes) => builder = builder.body(bytes),
+            Payload::Multipart(form) => {
+                let content_type =
+                    multipart_content_type.map(|value| with_boundary(&value, form.boundary()));
+                builder = builder.multipart(form);
+
+                if let Some(content_type) = content_type {
+                    let (client, request) = builder.build_split();
+                    let mut request = request.map_err(Self::format_error)?;
+                    let value = HeaderValue::from_str(&content_type)
+                        .map_err(|_| format!("Invalid Content-Type header: {}", content_type))?;
+                    request.headers_mut().insert(CONTENT_TYPE, value);
+                    builder = reqwest::RequestBuilder::from_parts(client, request);
+                }
+            }
+        }
+
+        Ok(builder)
+    }
+
+    /// Desafio Digest de uma resposta 401, se houver
+    fn digest_challenge(response: &reqwest::Response) -> Option<DigestChallenge> {
+        if response.status() != reqwest::StatusCode::UNAUTHORIZED {
+            return None;
         }
 
-        builder
+        response
+            .headers()
+            .get_all(reqwest::header::WWW_AUTHENTICATE)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .find_map(DigestChallenge::parse)
+    }
+
+    /// `uri` do Digest: caminho com query string
+    fn request_uri(full_url: &str) -> Result<String, String> {
+        let url = Url::parse(full_url).map_err(|e| format!("Failed to parse URL: {}", e))?;
+        Ok(match url.query() {
+            Some(query) => format!("{}?{}", url.path(), query),
+            None => url.path().to_string(),
+        })
     }
 
-    fn should_include_body(request: &HttpRequest) -> bool {
-        request.method.allows_body()
-            && !request.body.is_empty()
-            && request.body_type != super::enums::BodyType::None
+    /// URL final: protocolo normalizado e query params habilitados
+    pub fn full_url(request: &HttpRequest) -> Result<String, String> {
+        let validated_url = Self::validate_and_normalize_url(&request.url)?;
+        Self::build_url_with_params(&validated_url, &request.query_params)
     }
 
+    /// Lê o body em partes, informando o andamento. Acima de `limit` bytes só o início
+    /// fica em memória e o body completo é gravado em um arquivo temporário.
     async fn process_response(
-        response: reqwest::Response,
+        mut response: reqwest::Response,
         duration_ms: u128,
+        idle: Duration,
+        limit: usize,
+        on_progress: &mut (impl FnMut(DownloadProgress) + Send),
     ) -> Result<HttpResponse, String> {
         let status = response.status().as_u16();
         let status_text = response
@@ -76,19 +744,94 @@ impl HttpClient {
             .to_string();
 
         let response_headers = Self::extract_headers(response.headers());
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let total = response.content_length();
 
-        let body = response
-            .text()
+        let mut body = BytesMut::new();
+        // O arquivo é fechado antes do guard, que o remove se a leitura não terminar
+        let mut spill: Option<(tokio::fs::File, SpillFile)> = None;
+        let mut received = 0u64;
+        let mut last_progress: Option<Instant> = None;
+
+        while let Some(chunk) = tokio::time::timeout(idle, response.chunk())
             .await
-            .map_err(|e| format!("Failed to read response body: {}", e))?;
+            .map_err(|_| TIMEOUT_ERROR.to_string())?
+            .map_err(|e| format!("Failed to read response body: {}", e))?
+        {
+            received += chunk.len() as u64;
+
+            if spill.is_none() && body.len() + chunk.len() > limit {
+                let (mut file, guard) = Self::create_spill_file(content_type.as_deref()).await?;
+                file.write_all(&body)
+                    .await
+                    .map_err(|e| format!("Failed to write {}: {}", guard.path().display(), e))?;
+                spill = Some((file, guard));
+            }
+
+            match &mut spill {
+                Some((file, guard)) => {
+                    file.write_all(&chunk).await.map_err(|e| {
+                        format!("Failed to write {}: {}", guard.path().display(), e)
+                    })?;
+                    let room = limit.saturating_sub(body.len()).min(chunk.len());
+                    body.extend_from_slice(&chunk[..room]);
+                }
+                None => body.extend_from_slice(&chunk),
+            }
+
+            if last_progress.is_none_or(|at| at.elapsed() >= PROGRESS_INTERVAL) {
+                last_progress = Some(Instant::now());
+                on_progress(DownloadProgress {
+                    received,
+                    total,
+                    preview: mime::decode_text(
+                        content_type.as_deref(),
+                        &body[..body.len().min(PREVIEW_BYTES)],
+                    ),
+                });
+            }
+        }
 
-        Ok(HttpResponse {
+        let mut http_response = HttpResponse::new(
             status,
             status_text,
-            body,
-            headers: response_headers,
+            response_headers,
+            body.freeze(),
             duration_ms,
-        })
+        );
+
+        if let Some((mut file, guard)) = spill {
+            file.flush()
+                .await
+                .map_err(|e| format!("Failed to write {}: {}", guard.path().display(), e))?;
+            drop(file);
+            http_response.full_size = Some(received);
+            http_response.spill_file = Some(Arc::new(guard));
+        }
+
+        Ok(http_response)
+    }
+
+    /// Arquivo no diretório temporário do sistema para o body completo de respostas grandes
+    async fn create_spill_file(
+        content_type: Option<&str>,
+    ) -> Result<(tokio::fs::File, SpillFile), String> {
+        let extension = mime::extension(&mime::essence(content_type.unwrap_or_default()));
+        let path = std::env::temp_dir().join(format!(
+            "http-client-response-{}-{}.{}",
+            std::process::id(),
+            Utc::now().timestamp_nanos_opt().unwrap_or_default(),
+            extension
+        ));
+
+        let file = tokio::fs::File::create(&path)
+            .await
+            .map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
+        Ok((file, SpillFile(path)))
     }
 
     fn extract_headers(headers: &reqwest::header::HeaderMap) -> HashMap<String, String> {
@@ -117,7 +860,7 @@ impl HttpClient {
     }
 
     fn add_protocol_if_missing(url: &str) -> String {
-        if url.starts_with("http://") || url.starts_with("https://") {
+        if url_validator::has_scheme(url) {
             url.to_string()
         } else {
             format!("https://{}", url)
@@ -133,9 +876,15 @@ impl HttpClient {
     fn build_url_with_params(base_url: &str, params: &[KeyValue]) -> Result<String, String> {
         let mut url = Url::parse(base_url).map_err(|e| format!("Failed to parse URL: {}", e))?;
 
-        {
+        let enabled: Vec<&KeyValue> = params
+            .iter()
+            .filter(|p| p.enabled && !p.key.is_empty())
+            .collect();
+
+        // Evita um "?" solto quando não há parâmetros habilitados
+        if !enabled.is_empty() {
             let mut query_pairs = url.query_pairs_mut();
-            for param in params.iter().filter(|p| p.enabled && !p.key.is_empty()) {
+            for param in enabled {
                 query_pairs.append_pair(&param.key, &param.value);
             }
         }
@@ -153,7 +902,7 @@ impl HttpClient {
 
     fn format_error(error: reqwest::Error) -> String {
         if error.is_timeout() {
-            "Request timeout - the server took too long to respond".to_string()
+            TIMEOUT_ERROR.to_string()
         } else if error.is_connect() {
             format!("Connection failed: {}", error)
         } else if error.is_request() {
@@ -169,3 +918,534 @@ impl Default for HttpClient {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::enums::{FormPart, HTTPMethod, OAuth2Grant};
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    /// Lê uma requisição HTTP/1.1 completa (headers e body com Content-Length)
+    async fn read_request(stream: &mut tokio::net::TcpStream) -> String {
+        let mut buffer = vec![0u8; 65536];
+        let mut read = 0;
+        loop {
+            read += stream.read(&mut buffer[read..]).await.unwrap();
+            let raw = String::from_utf8_lossy(&buffer[..read]).to_string();
+            if let Some((head, body)) = raw.split_once("\r\n\r\n") {
+                let length = head
+                    .lines()
+                    .find_map(|l| {
+                        l.to_ascii_lowercase()
+                            .strip_prefix("content-length: ")
+                            .map(str::to_string)
+                    })
+                    .and_then(|l| l.parse::<usize>().ok())
+                    .unwrap_or(0);
+                if body.len() >= length {
+                    return raw;
+                }
+            }
+        }
+    }
+
+    /// Servidor local: cada conexão recebe as partes que `handler` devolve para a
+    /// requisição lida, com 300 ms entre elas; a conexão fica aberta até o cliente fechá-la
+    async fn serve(handler: impl Fn(String) -> Vec<Vec<u8>> + Send + Sync + 'static) -> String {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handler = Arc::new(handler);
+
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let handler = handler.clone();
+                tokio::spawn(async move {
+                    let raw = read_request(&mut stream).await;
+                    for (i, part) in handler(raw).iter().enumerate() {
+                        if i > 0 {
+                            tokio::time::sleep(Duration::from_millis(300)).await;
+                        }
+                        stream.write_all(part).await.unwrap();
+                    }
+                    let _ = stream.read(&mut [0u8; 1]).await;
+                });
+            }
+        });
+
+        base_url
+    }
+
+    /// Resposta completa em uma parte; `headers` são linhas extras terminadas em `\r\n`
+    fn reply(status: &str, headers: &str, body: &[u8]) -> Vec<Vec<u8>> {
+        let head = format!(
+            "HTTP/1.1 {}\r\n{}Content-Length: {}\r\nConnection: close\r\n\r\n",
+            status,
+            headers,
+            body.len()
+        );
+        vec![[head.as_bytes(), body].concat()]
+    }
+
+    /// Servidor local que devolve a requisição recebida como body da resposta
+    async fn echo_server() -> String {
+        serve(|raw| reply("200 OK", "Content-Type: text/plain\r\n", raw.as_bytes())).await
+    }
+
+    /// Servidor local que responde sempre com o mesmo body
+    async fn static_server(content_type: &'static str, body: &'static [u8]) -> String {
+        serve(move |_| {
+            reply(
+                "200 OK",
+                &format!("Content-Type: {}\r\n", content_type),
+                body,
+            )
+        })
+        .await
+    }
+
+    /// Servidor local que envia `sent` bytes e depois um byte a cada 300 ms, `drips` vezes.
+    /// Com `stall`, anuncia um byte a mais do que envia e deixa a conexão parada.
+    async fn stalled_server(
+        content_type: &'static str,
+        sent: usize,
+        drips: usize,
+        stall: bool,
+    ) -> String {
+        serve(move |_| {
+            let head = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: {}\r\nContent-Length: {}\r\n\r\n",
+                content_type,
+                sent + drips + usize::from(stall)
+            );
+            let mut parts = vec![[head.as_bytes(), &vec![b'a'; sent]].concat()];
+            parts.extend(std::iter::repeat_n(b"a".to_vec(), drips));
+            parts
+        })
+        .await
+    }
+
+    /// Servidor local com redirects: `/start` (302) → `/next` (307, para `localhost`,
+    /// outra origem) → `/final`, `/keep` (307) → `/final`, `/login` (302 com `Set-Cookie`)
+    /// → `/final` e `/moved` (302) → `/protected` (desafio Digest até receber credenciais);
+    /// `/final` e `/protected` autenticado devolvem a requisição
+    async fn redirect_server() -> String {
+        serve(|raw| {
+            let path = raw.split_whitespace().nth(1).unwrap_or_default();
+            let port = raw
+                .lines()
+                .find_map(|line| line.strip_prefix("host: 127.0.0.1:"))
+                .unwrap_or_default();
+
+            let (status, headers) = match path {
+                "/start" => ("302 Found", "Location: /next\r\n".to_string()),
+                "/next" => (
+                    "307 Temporary Redirect",
+                    format!("Location: http://localhost:{}/final\r\n", port),
+                ),
+                "/keep" => ("307 Temporary Redirect", "Location: /final\r\n".to_string()),
+                "/login" => (
+                    "302 Found",
+                    "Location: /final\r\nSet-Cookie: sid=abc; Path=/; HttpOnly\r\n".to_string(),
+                ),
+                "/moved" => ("302 Found", "Location: /protected?page=2\r\n".to_string()),
+                "/protected?page=2" if !raw.contains("authorization: Digest") => (
+                    "401 Unauthorized",
+                    "WWW-Authenticate: Digest realm=\"test\", qop=\"auth\", nonce=\"abc\"\r\n"
+                        .to_string(),
+                ),
+                _ => return reply("200 OK", "Content-Type: text/plain\r\n", raw.as_bytes()),
+            };
+            reply(status, &headers, b"")
+        })
+        .await
+    }
+
+    /// Servidor local com um token endpoint (`/token`) e uma API (`/api`) que só
+    /// aceita o último token emitido
+    async fn stub_server() -> (String, Arc<Mutex<Vec<String>>>) {
+        let token_requests = Arc::new(Mutex::new(Vec::new()));
+        let requests = token_requests.clone();
+
+        let base_url = serve(move |raw| {
+            let (head, body) = raw.split_once("\r\n\r\n").unwrap();
+            let mut requests = requests.lock().unwrap();
+            let issued = requests.len();
+
+            let (status, body) = if head.starts_with("POST /token") {
+                requests.push(body.to_string());
+                (
+                    "200 OK",
+                    format!(
+                        r#"{{"access_token":"token-{}","token_type":"Bearer","expires_in":3600}}"#,
+                        issued + 1
+                    ),
+                )
+            } else if head.contains(&format!("authorization: Bearer token-{}", issued))
+                && issued > 0
+            {
+                ("200 OK", r#"{"ok":true}"#.to_string())
+            } else {
+                ("401 Unauthorized", "{}".to_string())
+            };
+
+            reply(
+                status,
+                "Content-Type: application/json\r\n",
+                body.as_bytes(),
+            )
+        })
+        .await;
+
+        (base_url, token_requests)
+    }
+
+    #[tokio::test]
+    async fn test_oauth2_fetches_and_refreshes_tokens() {
+        let (base_url, token_requests) = stub_server().await;
+        let client = HttpClient::in_memory();
+
+        let config = OAuth2Config {
+            grant: OAuth2Grant::ClientCredentials,
+            token_url: format!("{}/token", base_url),
+            client_id: "cli".into(),
+            client_secret: "secret".into(),
+            scope: "read".into(),
+            ..OAuth2Config::default()
+        };
+        let request = HttpRequest {
+            url: format!("{}/api", base_url),
+            auth: Auth::OAuth2(Box::new(config.clone())),
+            ..HttpRequest::default()
+        };
+
+        // Sem token em cache: client credentials direto no token endpoint
+        let response = client.send(request.clone()).await.unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(
+            token_requests.lock().unwrap()[0],
+            "grant_type=client_credentials&scope=read"
+        );
+
+        // Token em cache recusado com 401: o refresh token é usado e a requisição reenviada
+        let key = oauth2::cache_key(&config);
+        let mut stale = client.oauth2_tokens().get(&key).unwrap();
+        stale.access_token = "revoked".into();
+        stale.refresh_token = Some("r1".into());
+        client.oauth2_tokens().insert(key.clone(), stale);
+
+        let response = client.send(request).await.unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(
+            token_requests.lock().unwrap()[1],
+            "grant_type=refresh_token&refresh_token=r1&scope=read"
+        );
+
+        let token = client.oauth2_tokens().get(&key).unwrap();
+        assert_eq!(token.access_token, "token-2");
+        assert_eq!(token.refresh_token.as_deref(), Some("r1"));
+    }
+
+    #[tokio::test]
+    async fn test_sends_multipart_form_data() {
+        let base_url = echo_server().await;
+        let path = std::env::temp_dir().join(format!("form-part-{}.csv", std::process::id()));
+        std::fs::write(&path, "id,name\n1,Ada\n").unwrap();
+
+        let mut file = FormPart::file("report".into(), path.display().to_string());
+        file.content_type = "text/csv".into();
+        file.file_name = "ada.csv".into();
+        let mut disabled = FormPart::text("debug".into(), "1".into());
+        disabled.enabled = false;
+
+        let request = HttpRequest {
+            method: HTTPMethod::POST,
+            url: format!("{}/upload", base_url),
+            body_type: BodyType::FormData,
+            form_data: vec![FormPart::text("title".into(), "Q3".into()), file, disabled],
+            ..HttpRequest::default()
+        };
+
+        let response = HttpClient::in_memory().send(request).await.unwrap();
+        std::fs::remove_file(&path).ok();
+
+        let echoed = response.text();
+        assert!(echoed.contains("content-type: multipart/form-data; boundary="));
+        assert!(!echoed.contains("application/json"));
+        assert!(echoed.contains("name=\"title\"\r\n\r\nQ3\r\n"));
+        assert!(echoed.contains(
+            "name=\"report\"; filename=\"ada.csv\"\r\nContent-Type: text/csv\r\n\r\nid,name\n1,Ada\n\r\n"
+        ));
+        assert!(!echoed.contains("debug"));
+    }
+
+    #[tokio::test]
+    async fn test_multipart_content_type_override_keeps_boundary() {
+        let base_url = echo_server().await;
+        let request = HttpRequest {
+            method: HTTPMethod::POST,
+            url: format!("{}/upload", base_url),
+            headers: vec![KeyValue::new(
+                "Content-Type".into(),
+                "multipart/mixed; boundary=stale".into(),
+            )],
+            body_type: BodyType::FormData,
+            form_data: vec![FormPart::text("title".into(), "Q3".into())],
+            ..HttpRequest::default()
+        };
+
+        let response = HttpClient::in_memory().send(request).await.unwrap();
+        let echoed = response.text();
+
+        let (_, boundary) = echoed
+            .split_once("content-type: multipart/mixed; boundary=")
+            .unwrap();
+        let boundary = boundary.lines().next().unwrap();
+        assert_ne!(boundary, "stale");
+        assert!(echoed.contains(&format!("--{}\r\n", boundary)));
+        assert_eq!(echoed.matches("content-type:").count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_sends_binary_file_body() {
+        let base_url = echo_server().await;
+        let path = std::env::temp_dir().join(format!("binary-body-{}.bin", std::process::id()));
+        std::fs::write(&path, b"\x00\x01payload").unwrap();
+
+        let request = HttpRequest {
+            method: HTTPMethod::PUT,
+            url: format!("{}/blob", base_url),
+            headers: Vec::new(),
+            body_type: BodyType::Binary,
+            binary_file: path.display().to_string(),
+            ..HttpRequest::default()
+        };
+
+        let response = HttpClient::in_memory().send(request.clone()).await.unwrap();
+        std::fs::remove_file(&path).ok();
+
+        let echoed = response.text();
+        assert!(echoed.contains("content-type: application/octet-stream\r\n"));
+        assert!(echoed.contains("content-length: 9\r\n"));
+        assert!(echoed.ends_with("\r\n\r\n\0\u{1}payload"));
+
+        // Arquivo ausente vira erro legível, sem enviar a requisição
+        let error = HttpClient::in_memory().send(request).await.unwrap_err();
+        assert!(error.starts_with("Failed to read file"));
+    }
+
+    #[tokio::test]
+    async fn test_keeps_binary_response_bytes() {
+        const PNG: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+        let base_url = static_server("image/png", PNG).await;
+
+        let request = HttpRequest {
+            url: format!("{}/logo.png", base_url),
+            ..HttpRequest::default()
+        };
+        let response = HttpClient::in_memory().send(request).await.unwrap();
+
+        assert_eq!(response.body, None);
+        assert_eq!(response.raw_body(), PNG);
+        assert!(response.is_image());
+    }
+
+    #[tokio::test]
+    async fn test_event_stream_is_left_open() {
+        let base_url = static_server("text/event-stream", b"data: hello\n\n").await;
+
+        let request = HttpRequest {
+            url: format!("{}/events", base_url),
+            ..HttpRequest::default()
+        };
+        let response = HttpClient::in_memory().send(request).await.unwrap();
+
+        // O body fica para o viewer de eventos
+        assert_eq!(response.status, 200);
+        assert!(response.event_stream.is_some());
+        assert!(response.raw_body().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_spills_body_over_limit_to_temp_file() {
+        let body: &'static [u8] = vec![b'a'; 1536 * 1024].leak();
+        let base_url = static_server("text/plain", body).await;
+
+        let request = HttpRequest {
+            url: format!("{}/export", base_url),
+            response_limit_mb: 1,
+            ..HttpRequest::default()
+        };
+        let mut progress = Vec::new();
+        let response = HttpClient::in_memory()
+            .send_with_progress(request, |download| progress.push(download))
+            .await
+            .unwrap();
+
+        // Só o primeiro MB fica em memória; o arquivo tem o body inteiro
+        assert_eq!(response.raw_body().len(), 1024 * 1024);
+        assert_eq!(response.full_size, Some(body.len() as u64));
+        let path = response.spill_file.clone().unwrap().path().to_path_buf();
+        assert_eq!(std::fs::read(&path).unwrap(), body);
+
+        // Descartar a resposta remove o arquivo
+        drop(response);
+        assert!(!path.exists());
+
+        let first = progress.first().unwrap();
+        assert_eq!(first.total, Some(body.len() as u64));
+        assert!(first.preview.as_ref().unwrap().len() <= PREVIEW_BYTES);
+    }
+
+    #[tokio::test]
+    async fn test_removes_spill_file_when_reading_fails() {
+        let base_url = stalled_server("text/csv", 1536 * 1024, 1, true).await;
+        let spill_files = || {
+            let prefix = format!("http-client-response-{}-", std::process::id());
+            std::fs::read_dir(std::env::temp_dir())
+                .unwrap()
+                .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+                .filter(|name| name.starts_with(&prefix) && name.ends_with(".csv"))
+                .count()
+        };
+
+        let request = HttpRequest {
+            url: format!("{}/export", base_url),
+            response_limit_mb: 1,
+            timeout_ms: 1000,
+            ..HttpRequest::default()
+        };
+        let mut spilled = 0;
+        let result = HttpClient::in_memory()
+            .send_with_progress(request, |_| spilled = spilled.max(spill_files()))
+            .await;
+
+        // O arquivo existia durante a leitura e foi removido com o timeout
+        assert_eq!(result.unwrap_err(), TIMEOUT_ERROR);
+        assert_eq!(spilled, 1);
+        assert_eq!(spill_files(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_timeout_restarts_on_each_body_chunk() {
+        let base_url = stalled_server("text/plain", 16, 5, false).await;
+        let request = HttpRequest {
+            url: format!("{}/slow", base_url),
+            timeout_ms: 600,
+            ..HttpRequest::default()
+        };
+
+        // 1,5 s de body no total, mas nunca mais de 300 ms sem dados
+        let response = HttpClient::in_memory().send(request).await.unwrap();
+        assert_eq!(response.text(), "a".repeat(21));
+    }
+
+    #[tokio::test]
+    async fn test_follows_redirects_and_records_hops() {
+        let base_url = redirect_server().await;
+        let request = HttpRequest {
+            method: HTTPMethod::POST,
+            url: format!("{}/start", base_url),
+            body: "payload".into(),
+            body_type: BodyType::Raw,
+            auth: Auth::Bearer {
+                token: "secret".into(),
+            },
+            ..HttpRequest::default()
+        };
+        let response = HttpClient::in_memory().send(request.clone()).await.unwrap();
+
+        // POST vira GET no 302; o 307 para outra origem mantém o método, sem credenciais
+        let hops: Vec<_> = response
+            .redirects
+            .iter()
+            .map(|hop| (hop.method.as_str(), hop.status))
+            .collect();
+        assert_eq!(hops, vec![("POST", 302), ("GET", 307)]);
+        assert_eq!(response.redirects[0].location, format!("{}/next", base_url));
+        assert!(
+            response.redirects[1]
+                .location
+                .starts_with("http://localhost:")
+        );
+        let received = response.text().to_ascii_lowercase();
+        assert!(received.starts_with("get /final"));
+        assert!(!received.contains("authorization"));
+        assert!(!received.contains("payload"));
+
+        // 307 na mesma origem: método, body e autenticação mantidos
+        let keep = HttpRequest {
+            url: format!("{}/keep", base_url),
+            ..request.clone()
+        };
+        let response = HttpClient::in_memory().send(keep).await.unwrap();
+        let received = response.text().to_ascii_lowercase();
+        assert!(received.starts_with("post /final"));
+        assert!(received.contains("authorization: bearer secret"));
+        assert!(received.ends_with("payload"));
+
+        let mut limited = request;
+        limited.redirects.max_hops = 1;
+        let response = HttpClient::in_memory().send(limited).await.unwrap();
+        assert_eq!(response.status, 307);
+        assert_eq!(response.redirects.len(), 1);
+
+        let mut manual = HttpRequest {
+            url: format!("{}/start", base_url),
+            ..HttpRequest::default()
+        };
+        manual.redirects.follow = false;
+        let response = HttpClient::in_memory().send(manual).await.unwrap();
+        assert_eq!(response.status, 302);
+        assert!(response.redirects.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_digest_challenge_after_redirect_uses_final_url() {
+        let base_url = redirect_server().await;
+        let request = HttpRequest {
+            url: format!("{}/moved", base_url),
+            auth: Auth::Digest {
+                username: "ana".into(),
+                password: "secret".into(),
+            },
+            ..HttpRequest::default()
+        };
+        let response = HttpClient::in_memory().send(request).await.unwrap();
+
+        assert_eq!(response.status, 200);
+        assert_eq!(response.redirects.len(), 1);
+        let received = response.text();
+        assert!(received.starts_with("GET /protected?page=2 "));
+        assert!(received.contains(r#"uri="/protected?page=2""#));
+    }
+
+    #[tokio::test]
+    async fn test_cookie_jar_across_requests() {
+        let base_url = redirect_server().await;
+        let client = HttpClient::in_memory();
+
+        // O cookie do 302 já vai no salto seguinte
+        let login = HttpRequest {
+            url: format!("{}/login", base_url),
+            ..HttpRequest::default()
+        };
+        let response = client.send(login).await.unwrap();
+        assert_eq!(response.cookies.len(), 1);
+        assert!(response.cookies[0].cookie.is_some());
+        assert!(response.text().contains("cookie: sid=abc"));
+
+        let mut request = HttpRequest {
+            url: format!("{}/final", base_url),
+            headers: vec![KeyValue::new("Cookie".into(), "theme=dark".into())],
+            ..HttpRequest::default()
+        };
+        let response = client.send(request.clone()).await.unwrap();
+        assert!(response.text().contains("cookie: theme=dark; sid=abc"));
+
+        request.send_cookies = false;
+        let response = client.send(request).await.unwrap();
+        assert!(response.text().contains("cookie: theme=dark\r\n"));
+    }
+}
@@ -0,0 +1,527 @@
+//! Validação, pretty print e minificação de bodies XML e HTML
+
+use quick_xml::Reader;
+use quick_xml::events::Event;
+
+const INDENT: &str = "  ";
+
+/// Elementos HTML sem tag de fechamento
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+/// Elementos HTML cujo conteúdo é mantido como está (sem validar nem reindentar)
+const VERBATIM_ELEMENTS: &[&str] = &["script", "style", "textarea", "pre"];
+
+/// Elementos HTML cuja tag de fechamento é opcional
+const OPTIONAL_END_ELEMENTS: &[&str] = &[
+    "html", "head", "body", "p", "li", "dt", "dd", "tr", "td", "th", "thead", "tbody", "tfoot",
+    "option", "optgroup", "colgroup", "rt", "rp",
+];
+
+/// Elementos que fecham implicitamente um `<p>` aberto
+const CLOSES_PARAGRAPH: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "div",
+    "dl",
+    "fieldset",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hr",
+    "main",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "table",
+    "ul",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Markup {
+    Xml,
+    Html,
+}
+
+impl std::fmt::Display for Markup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Markup::Xml => write!(f, "XML"),
+            Markup::Html => write!(f, "HTML"),
+        }
+    }
+}
+
+impl Markup {
+    /// Valida o documento; o erro indica linha e coluna
+    pub fn validate(self, source: &str) -> Result<(), String> {
+        self.tokens(source).map(|_| ())
+    }
+
+    /// Reindenta o documento com dois espaços por nível
+    pub fn format(self, source: &str) -> Result<String, String> {
+        Ok(render(source, &self.tokens(source)?, true))
+    }
+
+    /// Remove a indentação e o espaço em branco entre as tags
+    pub fn minify(self, source: &str) -> Result<String, String> {
+        Ok(render(source, &self.tokens(source)?, false))
+    }
+
+    fn tokens(self, source: &str) -> Result<Vec<Token>, String> {
+        let tokens = match self {
+            Markup::Xml => xml_tokens(source),
+            Markup::Html => html_tokens(source),
+        };
+
+        tokens.map_err(|(offset, message)| {
+            let (line, column) = line_column(source, offset);
+            format!(
+                "Invalid {}: {} at line {} column {}",
+                self, message, line, column
+            )
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Open,
+    Close,
+    /// Elemento sem conteúdo: `<a/>` ou elemento void do HTML
+    Empty,
+    Text,
+    /// Texto que não pode ser reindentado (`<pre>`, `<script>`...)
+    Verbatim,
+    /// Comentário, CDATA, declaração, doctype ou instrução de processamento
+    Other,
+}
+
+/// Trecho do documento original com a profundidade em que deve ser impresso
+#[derive(Debug, Clone, Copy)]
+struct Token {
+    kind: Kind,
+    start: usize,
+    end: usize,
+    depth: usize,
+}
+
+type TokenResult = Result<Vec<Token>, (usize, String)>;
+
+fn xml_tokens(source: &str) -> TokenResult {
+    let mut reader = Reader::from_str(source);
+    let mut tokens: Vec<Token> = Vec::new();
+    let mut open: Vec<(usize, String)> = Vec::new();
+    let mut has_root = false;
+
+    loop {
+        let start = reader.buffer_position() as usize;
+        let event = reader
+            .read_event()
+            .map_err(|e| (reader.error_position() as usize, e.to_string()))?;
+        let end = reader.buffer_position() as usize;
+        let mut depth = open.len();
+
+        let kind = match event {
+            Event::Eof => break,
+            Event::Start(ref tag) | Event::Empty(ref tag) => {
+                if depth == 0 && has_root {
+                    return Err((start, "multiple root elements".to_string()));
+                }
+                for attribute in tag.attributes() {
+                    attribute.map_err(|e| (start, e.to_string()))?;
+                }
+                if let Event::Start(_) = event {
+                    let name = String::from_utf8_lossy(tag.name().as_ref()).into_owned();
+                    open.push((start, name));
+                    Kind::Open
+                } else {
+                    has_root |= depth == 0;
+                    Kind::Empty
+                }
+            }
+            Event::End(_) => {
+                open.pop();
+                depth = open.len();
+                has_root |= depth == 0;
+                Kind::Close
+            }
+            Event::Text(ref text) if depth == 0 => {
+                if !text.iter().all(u8::is_ascii_whitespace) {
+                    return Err((start, "text outside the root element".to_string()));
+                }
+                continue;
+            }
+            Event::GeneralRef(ref reference) => {
+                let predefined = matches!(&**reference, b"lt" | b"gt" | b"amp" | b"apos" | b"quot");
+                let valid = match reference.resolve_char_ref() {
+                    Ok(Some(_)) => true,
+                    Ok(None) => predefined,
+                    Err(_) => false,
+                };
+                if !valid {
+                    let name = String::from_utf8_lossy(reference);
+                    return Err((start, format!("unknown entity &{};", name)));
+                }
+                Kind::Text
+            }
+            Event::Text(_) => Kind::Text,
+            Event::CData(_) if depth == 0 => {
+                return Err((start, "CDATA outside the root element".to_string()));
+            }
+            Event::CData(_)
+            | Event::Comment(_)
+            | Event::Decl(_)
+            | Event::PI(_)
+            | Event::DocType(_) => Kind::Other,
+        };
+
+        push(&mut tokens, kind, start, end, depth);
+    }
+
+    if let Some((start, name)) = open.pop() {
+        return Err((start, format!("unclosed tag <{}>", name)));
+    }
+    if !has_root {
+        return Err((source.len(), "missing root element".to_string()));
+    }
+
+    Ok(tokens)
+}
+
+fn html_tokens(source: &str) -> TokenResult {
+    let mut tokens: Vec<Token> = Vec::new();
+    let mut open: Vec<(usize, String)> = Vec::new();
+    let mut pos = 0;
+
+    while pos < source.len() {
+        let rest = &source[pos..];
+
+        if let Some(after) = rest.strip_prefix("<!--") {
+            let end = after
+                .find("-->")
+                .map(|i| pos + 4 + i + 3)
+                .ok_or((pos, "unclosed comment".to_string()))?;
+            push(&mut tokens, Kind::Other, pos, end, open.len());
+            pos = end;
+        } else if rest.starts_with("<!") || rest.starts_with("<?") {
+            let end = rest
+                .find('>')
+                .map(|i| pos + i + 1)
+                .ok_or((pos, "unclosed declaration".to_string()))?;
+            push(&mut tokens, Kind::Other, pos, end, open.len());
+            pos = end;
+        } else if let Some(after) = rest.strip_prefix("</") {
+            let end = after
+                .find('>')
+                .map(|i| pos + 2 + i + 1)
+                .ok_or((pos, "unclosed tag".to_string()))?;
+            let name = tag_name(&source[pos + 2..end - 1]);
+            close_html(&mut open, &name, pos)?;
+            push(&mut tokens, Kind::Close, pos, end, open.len());
+            pos = end;
+        } else if rest.starts_with('<') && rest[1..].starts_with(|c: char| c.is_ascii_alphabetic())
+        {
+            let end = tag_end(rest)
+                .map(|i| pos + i + 1)
+                .ok_or((pos, "unclosed tag".to_string()))?;
+            let raw = &source[pos + 1..end - 1];
+            let name = tag_name(raw);
+
+            while let Some((_, top)) = open.last()
+                && closes_implicitly(top, &name)
+            {
+                open.pop();
+            }
+
+            if raw.ends_with('/') || VOID_ELEMENTS.contains(&name.as_str()) {
+                push(&mut tokens, Kind::Empty, pos, end, open.len());
+                pos = end;
+                continue;
+            }
+
+            push(&mut tokens, Kind::Open, pos, end, open.len());
+            pos = end;
+
+            if VERBATIM_ELEMENTS.contains(&name.as_str()) {
+                let closing = format!("</{}", name);
+                let content_end = source[pos..]
+                    .to_ascii_lowercase()
+                    .find(&closing)
+                    .map(|i| pos + i)
+                    .ok_or((tokens.last().map_or(0, |t| t.start), unclosed(&name)))?;
+                push(
+                    &mut tokens,
+                    Kind::Verbatim,
+                    pos,
+                    content_end,
+                    open.len() + 1,
+                );
+                pos = content_end;
+            }
+            open.push((tokens.last().map_or(0, |t| t.start), name));
+        } else {
+            let end = rest[1..].find('<').map_or(source.len(), |i| pos + 1 + i);
+            push(&mut tokens, Kind::Text, pos, end, open.len());
+            pos = end;
+        }
+    }
+
+    match open
+        .iter()
+        .rev()
+        .find(|(_, name)| !OPTIONAL_END_ELEMENTS.contains(&name.as_str()))
+    {
+        Some((start, name)) => Err((*start, unclosed(name))),
+        None => Ok(tokens),
+    }
+}
+
+/// Fecha o elemento `name`, aceitando que elementos de fechamento opcional
+/// abertos dentro dele fiquem sem a tag de fechamento
+fn close_html(
+    open: &mut Vec<(usize, String)>,
+    name: &str,
+    pos: usize,
+) -> Result<(), (usize, String)> {
+    let Some(index) = open.iter().rposition(|(_, open_name)| open_name == name) else {
+        return Err((pos, format!("unexpected closing tag </{}>", name)));
+    };
+
+    if let Some((_, inner)) = open[index + 1..]
+        .iter()
+        .find(|(_, inner)| !OPTIONAL_END_ELEMENTS.contains(&inner.as_str()))
+    {
+        return Err((pos, format!("expected </{}>, found </{}>", inner, name)));
+    }
+
+    open.truncate(index);
+    Ok(())
+}
+
+/// Retorna true se abrir `next` fecha o elemento `open` (ex.: `<li>` seguido de `<li>`)
+fn closes_implicitly(open: &str, next: &str) -> bool {
+    match open {
+        "p" => CLOSES_PARAGRAPH.contains(&next),
+        "li" => next == "li",
+        "dt" | "dd" => matches!(next, "dt" | "dd"),
+        "td" | "th" => matches!(next, "td" | "th" | "tr"),
+        "tr" => next == "tr",
+        "option" => matches!(next, "option" | "optgroup"),
+        _ => false,
+    }
+}
+
+/// Posição do `>` que fecha a tag, ignorando os que estão em valores entre aspas
+fn tag_end(tag: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in tag.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if q == c => quote = None,
+            (None, '>') => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn tag_name(raw: &str) -> String {
+    raw.split(|c: char| c.is_whitespace() || c == '/')
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase()
+}
+
+fn unclosed(name: &str) -> String {
+    format!("unclosed tag <{}>", name)
+}
+
+/// Adiciona o token, juntando textos vizinhos (texto e entidades no XML)
+fn push(tokens: &mut Vec<Token>, kind: Kind, start: usize, end: usize, depth: usize) {
+    if let Some(last) = tokens.last_mut()
+        && kind == Kind::Text
+        && last.kind == Kind::Text
+        && last.end == start
+    {
+        last.end = end;
+        return;
+    }
+    tokens.push(Token {
+        kind,
+        start,
+        end,
+        depth,
+    });
+}
+
+/// Imprime os tokens com uma tag por linha (`pretty`) ou sem espaço entre elas.
+///
+/// Um elemento que só contém texto fica em uma única linha.
+fn render(source: &str, tokens: &[Token], pretty: bool) -> String {
+    let slice = |token: &Token| &source[token.start..token.end];
+    let mut output = String::new();
+    let mut i = 0;
+
+    while i < tokens.len() {
+        let token = &tokens[i];
+        let mut line = match token.kind {
+            Kind::Text => slice(token).trim().to_string(),
+            _ => slice(token).to_string(),
+        };
+        i += 1;
+
+        if token.kind == Kind::Open {
+            let content = tokens
+                .get(i)
+                .filter(|t| matches!(t.kind, Kind::Text | Kind::Verbatim));
+            if let Some(content) = content {
+                match content.kind {
+                    Kind::Verbatim => line.push_str(slice(content)),
+                    _ => line.push_str(slice(content).trim()),
+                }
+            }
+            let after = i + usize::from(content.is_some());
+            match tokens.get(after) {
+                Some(next) if next.kind == Kind::Close && next.depth == token.depth => {
+                    line.push_str(slice(next));
+                    i = after + 1;
+                }
+                // Elemento fechado implicitamente (HTML) logo após o texto
+                next if content.is_some() && next.is_none_or(|t| t.depth <= token.depth) => {
+                    i = after;
+                }
+                _ => line.truncate(slice(token).len()),
+            }
+        }
+
+        if line.is_empty() {
+            continue;
+        }
+        if pretty {
+            if !output.is_empty() && token.kind != Kind::Verbatim {
+                output.push('\n');
+            }
+            if token.kind != Kind::Verbatim {
+                output.push_str(&INDENT.repeat(token.depth));
+            }
+        }
+        output.push_str(&line);
+    }
+
+    output
+}
+
+/// Linha e coluna (a partir de 1) de um offset em bytes
+fn line_column(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset.min(source.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before
+        .rsplit('\n')
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .count()
+        + 1;
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENVELOPE: &str = r#"<?xml version="1.0"?><soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/"><soap:Body><m:GetPrice xmlns:m="https://example.com/prices"><m:Item>Tom &amp; Jerry</m:Item><m:Empty/></m:GetPrice></soap:Body></soap:Envelope>"#;
+
+    #[test]
+    fn test_xml_format_and_minify() {
+        let formatted = Markup::Xml.format(ENVELOPE).unwrap();
+        assert_eq!(
+            formatted,
+            r#"<?xml version="1.0"?>
+<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+  <soap:Body>
+    <m:GetPrice xmlns:m="https://example.com/prices">
+      <m:Item>Tom &amp; Jerry</m:Item>
+      <m:Empty/>
+    </m:GetPrice>
+  </soap:Body>
+</soap:Envelope>"#
+        );
+        assert_eq!(Markup::Xml.minify(&formatted).unwrap(), ENVELOPE);
+    }
+
+    #[test]
+    fn test_xml_errors_have_position() {
+        assert_eq!(
+            Markup::Xml.validate("<a>\n  <b></c>\n</a>").unwrap_err(),
+            "Invalid XML: ill-formed document: expected `</b>`, but `</c>` was found at line 2 column 6"
+        );
+        assert!(
+            Markup::Xml
+                .validate("<a>\n  <b>")
+                .unwrap_err()
+                .ends_with("unclosed tag <b> at line 2 column 3")
+        );
+        assert!(
+            Markup::Xml
+                .validate("<a>&nbsp;</a>")
+                .unwrap_err()
+                .contains("unknown entity &nbsp;")
+        );
+        assert!(Markup::Xml.validate("<a/><b/>").is_err());
+        assert!(Markup::Xml.validate("plain text").is_err());
+    }
+
+    #[test]
+    fn test_html_format_keeps_verbatim_content() {
+        let html = "<!DOCTYPE html><html><body><ul><li>One<li>Two</ul><br><pre>  keep\n  this</pre><p>Text</body></html>";
+        assert_eq!(
+            Markup::Html.format(html).unwrap(),
+            "<!DOCTYPE html>
+<html>
+  <body>
+    <ul>
+      <li>One
+      <li>Two
+    </ul>
+    <br>
+    <pre>  keep
+  this</pre>
+    <p>Text
+  </body>
+</html>"
+        );
+        assert_eq!(
+            Markup::Html
+                .minify(&Markup::Html.format(html).unwrap())
+                .unwrap(),
+            html
+        );
+    }
+
+    #[test]
+    fn test_html_errors_have_position() {
+        assert_eq!(
+            Markup::Html.validate("<div>\n<span></div>").unwrap_err(),
+            "Invalid HTML: expected </span>, found </div> at line 2 column 7"
+        );
+        assert_eq!(
+            Markup::Html.validate("<main>\n  <div>").unwrap_err(),
+            "Invalid HTML: unclosed tag <div> at line 2 column 3"
+        );
+        assert!(Markup::Html.validate("<p>ok</p></span>").is_err());
+        assert!(Markup::Html.validate("<img src=\"a>b.png\">").is_ok());
+    }
+}
@@ -1,7 +1,20 @@
+pub mod auth;
+pub mod collections;
+pub mod cookies;
 pub mod enums;
+pub mod environment;
+pub mod file_dialog;
+pub mod formats;
+pub mod graphql;
 pub mod history;
 pub mod http_client;
+pub mod markup;
+pub mod oauth2;
 pub mod pick_list;
+pub mod sigv4;
+pub mod sse;
+pub mod storage;
 pub mod styles;
 pub mod ui;
 pub mod utils;
+pub mod websocket;
@@ -0,0 +1,558 @@
+//! OAuth 2.0: formulários do token endpoint, PKCE, redirect loopback e cache de tokens
+
+use super::enums::{OAuth2Config, OAuth2Grant};
+use super::storage;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD as BASE64_URL;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+const TOKENS_FILE_NAME: &str = "oauth2_tokens.json";
+
+/// Caminho do redirect registrado no listener loopback
+pub const CALLBACK_PATH: &str = "/callback";
+
+/// Tokens são renovados um pouco antes da expiração anunciada
+const EXPIRY_MARGIN_SECS: i64 = 30;
+
+/// Token de acesso obtido no token endpoint
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OAuth2Token {
+    pub access_token: String,
+    pub token_type: String,
+    pub refresh_token: Option<String>,
+    /// Timestamp Unix da expiração; `None` se o servidor não informou `expires_in`
+    pub expires_at: Option<i64>,
+    pub scope: Option<String>,
+}
+
+impl OAuth2Token {
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| now + EXPIRY_MARGIN_SECS >= expires_at)
+    }
+
+    /// Texto curto de validade exibido no cache de tokens
+    pub fn expiry_label(&self, now: i64) -> String {
+        match self.expires_at {
+            None => "no expiry".to_string(),
+            Some(_) if self.is_expired(now) => "expired".to_string(),
+            Some(expires_at) => match expires_at - now {
+                secs if secs < 120 => format!("expires in {}s", secs),
+                secs => format!("expires in {} min", secs / 60),
+            },
+        }
+    }
+
+    /// Início do token seguido de máscara, para exibição
+    pub fn masked(&self) -> String {
+        let prefix: String = self.access_token.chars().take(6).collect();
+        format!("{}••••••", prefix)
+    }
+}
+
+/// Cache de tokens compartilhado entre as tarefas de envio e a UI.
+///
+/// Persistido em `oauth2_tokens.json`; requisições com a mesma configuração
+/// (ex.: todas as de uma coleção) reaproveitam o mesmo token.
+#[derive(Debug, Clone)]
+pub struct TokenCache {
+    tokens: Arc<Mutex<BTreeMap<String, OAuth2Token>>>,
+    file_path: Option<PathBuf>,
+}
+
+impl TokenCache {
+    pub fn new() -> Self {
+        let file_path = storage::config_file_path(TOKENS_FILE_NAME);
+        let tokens = storage::load_json(&file_path, "OAuth2 tokens").unwrap_or_default();
+
+        Self {
+            tokens: Arc::new(Mutex::new(tokens)),
+            file_path: Some(file_path),
+        }
+    }
+
+    /// Cache sem arquivo, para testes
+    #[cfg(test)]
+    pub fn in_memory() -> Self {
+        Self {
+            tokens: Arc::default(),
+            file_path: None,
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<OAuth2Token> {
+        self.lock().get(key).cloned()
+    }
+
+    pub fn insert(&self, key: String, token: OAuth2Token) {
+        let mut tokens = self.lock();
+        tokens.insert(key, token);
+        self.save(&tokens);
+    }
+
+    pub fn remove(&self, key: &str) {
+        let mut tokens = self.lock();
+        tokens.remove(key);
+        self.save(&tokens);
+    }
+
+    pub fn clear(&self) {
+        let mut tokens = self.lock();
+        tokens.clear();
+        self.save(&tokens);
+    }
+
+    pub fn entries(&self) -> Vec<(String, OAuth2Token)> {
+        self.lock()
+            .iter()
+            .map(|(key, token)| (key.clone(), token.clone()))
+            .collect()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BTreeMap<String, OAuth2Token>> {
+        self.tokens.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn save(&self, tokens: &BTreeMap<String, OAuth2Token>) {
+        if let Some(path) = &self.file_path
+            && let Err(e) = storage::save_json(path, tokens, "OAuth2 tokens")
+        {
+            eprintln!("Warning: Failed to save OAuth2 tokens: {}", e);
+        }
+    }
+}
+
+impl Default for TokenCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Chave do cache: identifica quem pediu o token e com quais permissões
+pub fn cache_key(config: &OAuth2Config) -> String {
+    let mut key = format!(
+        "{} · {} @ {}",
+        config.grant,
+        config.client_id.trim(),
+        config.token_url.trim()
+    );
+
+    if config.grant == OAuth2Grant::Password {
+        key.push_str(&format!(" as {}", config.username.trim()));
+    }
+    if !config.scope.trim().is_empty() {
+        key.push_str(&format!(" [{}]", config.scope.trim()));
+    }
+    if !config.audience.trim().is_empty() {
+        key.push_str(&format!(" for {}", config.audience.trim()));
+    }
+
+    key
+}
+
+/// Pedido enviado ao token endpoint
+pub enum TokenGrant<'a> {
+    ClientCredentials,
+    Password,
+    AuthorizationCode {
+        code: &'a str,
+        redirect_uri: &'a str,
+        verifier: &'a str,
+    },
+    Refresh(&'a str),
+}
+
+impl TokenGrant<'_> {
+    /// Grant obtido sem interação do usuário; `None` no authorization code
+    pub fn direct(grant: OAuth2Grant) -> Option<Self> {
+        match grant {
+            OAuth2Grant::ClientCredentials => Some(TokenGrant::ClientCredentials),
+            OAuth2Grant::Password => Some(TokenGrant::Password),
+            OAuth2Grant::AuthorizationCode => None,
+        }
+    }
+}
+
+/// Campos `application/x-www-form-urlencoded` do token endpoint.
+///
+/// O `client_id` só vai no formulário quando não há secret; com secret o
+/// cliente se autentica via Basic (RFC 6749, seção 2.3.1).
+pub fn token_form(config: &OAuth2Config, grant: &TokenGrant) -> Vec<(&'static str, String)> {
+    let mut form = Vec::new();
+
+    match grant {
+        TokenGrant::ClientCredentials => form.push(("grant_type", "client_credentials".into())),
+        TokenGrant::Password => {
+            form.push(("grant_type", "password".into()));
+            form.push(("username", config.username.clone()));
+            form.push(("password", config.password.clone()));
+        }
+        TokenGrant::AuthorizationCode {
+            code,
+            redirect_uri,
+            verifier,
+        } => {
+            form.push(("grant_type", "authorization_code".into()));
+            form.push(("code", code.to_string()));
+            form.push(("redirect_uri", redirect_uri.to_string()));
+            form.push(("code_verifier", verifier.to_string()));
+        }
+        TokenGrant::Refresh(refresh_token) => {
+            form.push(("grant_type", "refresh_token".into()));
+            form.push(("refresh_token", refresh_token.to_string()));
+        }
+    }
+
+    // O code já carrega o escopo concedido no navegador
+    let sends_scope = !matches!(grant, TokenGrant::AuthorizationCode { .. });
+    if sends_scope && !config.scope.trim().is_empty() {
+        form.push(("scope", config.scope.trim().to_string()));
+    }
+    if !config.audience.trim().is_empty() {
+        form.push(("audience", config.audience.trim().to_string()));
+    }
+    if config.client_secret.is_empty() {
+        form.push(("client_id", config.client_id.trim().to_string()));
+    }
+
+    form
+}
+
+/// Interpreta a resposta do token endpoint (RFC 6749, seções 5.1 e 5.2)
+pub fn parse_token_response(status: u16, body: &str, now: i64) -> Result<OAuth2Token, String> {
+    let json: Value = serde_json::from_str(body).map_err(|_| {
+        format!(
+            "Token endpoint returned {} with a non-JSON body: {}",
+            status,
+            body.chars().take(200).collect::<String>()
+        )
+    })?;
+
+    let field = |key: &str| json.get(key).and_then(Value::as_str).map(str::to_string);
+
+    if let Some(error) = field("error") {
+        return Err(match field("error_description") {
+            Some(description) => format!("OAuth2 error: {} ({})", error, description),
+            None => format!("OAuth2 error: {}", error),
+        });
+    }
+
+    let access_token = field("access_token")
+        .ok_or_else(|| format!("Token endpoint returned {} without an access_token", status))?;
+
+    // Alguns provedores enviam expires_in como string
+    let expires_in = match json.get("expires_in") {
+        Some(Value::Number(n)) => n.as_i64(),
+        Some(Value::String(s)) => s.parse().ok(),
+        _ => None,
+    };
+
+    Ok(OAuth2Token {
+        access_token,
+        token_type: field("token_type").unwrap_or_else(|| "Bearer".to_string()),
+        refresh_token: field("refresh_token"),
+        expires_at: expires_in.map(|secs| now + secs),
+        scope: field("scope"),
+    })
+}
+
+/// Par verifier/challenge do PKCE com método S256 (RFC 7636)
+pub struct Pkce {
+    pub verifier: String,
+    pub challenge: String,
+}
+
+impl Pkce {
+    pub fn new() -> Result<Self, String> {
+        Ok(Self::from_verifier(random_string(32)?))
+    }
+
+    pub fn from_verifier(verifier: String) -> Self {
+        let challenge = BASE64_URL.encode(Sha256::digest(verifier.as_bytes()));
+        Self {
+            verifier,
+            challenge,
+        }
+    }
+}
+
+/// Bytes aleatórios do sistema codificados em base64url
+pub fn random_string(bytes: usize) -> Result<String, String> {
+    let mut buffer = vec![0u8; bytes];
+    getrandom::fill(&mut buffer).map_err(|e| format!("Failed to generate random data: {}", e))?;
+    Ok(BASE64_URL.encode(buffer))
+}
+
+/// URL aberta no navegador para o login do authorization code
+pub fn authorization_url(
+    config: &OAuth2Config,
+    redirect_uri: &str,
+    state: &str,
+    pkce: &Pkce,
+) -> Result<String, String> {
+    let mut url = url::Url::parse(config.auth_url.trim())
+        .map_err(|e| format!("Invalid authorization URL: {}", e))?;
+
+    {
+        let mut query = url.query_pairs_mut();
+        query
+            .append_pair("response_type", "code")
+            .append_pair("client_id", config.client_id.trim())
+            .append_pair("redirect_uri", redirect_uri)
+            .append_pair("state", state)
+            .append_pair("code_challenge", &pkce.challenge)
+            .append_pair("code_challenge_method", "S256");
+
+        if !config.scope.trim().is_empty() {
+            query.append_pair("scope", config.scope.trim());
+        }
+        if !config.audience.trim().is_empty() {
+            query.append_pair("audience", config.audience.trim());
+        }
+    }
+
+    Ok(url.to_string())
+}
+
+/// Aguarda o redirect do navegador no listener loopback e retorna o `code`.
+///
+/// Requisições para outros caminhos (ex.: `/favicon.ico`) recebem 404 e são ignoradas.
+pub async fn wait_for_code(listener: TcpListener, state: &str) -> Result<String, String> {
+    loop {
+        let (mut stream, _) = listener
+            .accept()
+            .await
+            .map_err(|e| format!("OAuth2 callback listener failed: {}", e))?;
+
+        let mut buffer = vec![0u8; 8192];
+        let mut read = 0;
+        while read < buffer.len() && !buffer[..read].windows(4).any(|w| w == b"\r\n\r\n") {
+            match stream.read(&mut buffer[read..]).await {
+                Ok(0) | Err(_) => break,
+                Ok(n) => read += n,
+            }
+        }
+
+        let head = String::from_utf8_lossy(&buffer[..read]);
+        let target = head
+            .lines()
+            .next()
+            .and_then(|line| line.split_whitespace().nth(1))
+            .unwrap_or_default();
+
+        let (status, message, result) = match parse_callback(target, state) {
+            None => ("404 Not Found", "Not found", None),
+            Some(Ok(code)) => (
+                "200 OK",
+                "Authorization complete. You can close this tab and return to HTTP Client.",
+                Some(Ok(code)),
+            ),
+            Some(Err(e)) => ("400 Bad Request", "Authorization failed.", Some(Err(e))),
+        };
+
+        let body = format!(
+            "<html><body style=\"font-family: sans-serif\"><p>{}</p></body></html>",
+            message
+        );
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        );
+        let _ = stream.write_all(response.as_bytes()).await;
+        let _ = stream.shutdown().await;
+
+        if let Some(result) = result {
+            return result;
+        }
+    }
+}
+
+/// Extrai o `code` do alvo da requisição de callback; `None` se o caminho não for o callback
+fn parse_callback(target: &str, expected_state: &str) -> Option<Result<String, String>> {
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    if path != CALLBACK_PATH {
+        return None;
+    }
+
+    let params: BTreeMap<String, String> = url::form_urlencoded::parse(query.as_bytes())
+        .into_owned()
+        .collect();
+
+    if let Some(error) = params.get("error") {
+        return Some(Err(match params.get("error_description") {
+            Some(description) => format!("Authorization denied: {} ({})", error, description),
+            None => format!("Authorization denied: {}", error),
+        }));
+    }
+
+    if params.get("state").map(String::as_str) != Some(expected_state) {
+        return Some(Err(
+            "Authorization callback has an invalid state parameter".to_string()
+        ));
+    }
+
+    Some(
+        params
+            .get("code")
+            .cloned()
+            .ok_or_else(|| "Authorization callback has no code".to_string()),
+    )
+}
+
+/// Timestamp Unix atual, em segundos
+pub fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(grant: OAuth2Grant) -> OAuth2Config {
+        OAuth2Config {
+            grant,
+            token_url: "https://idp.example.com/token".into(),
+            auth_url: "https://idp.example.com/authorize".into(),
+            client_id: "cli".into(),
+            scope: "read write".into(),
+            audience: "https://api.example.com".into(),
+            username: "alice".into(),
+            password: "pw".into(),
+            ..OAuth2Config::default()
+        }
+    }
+
+    /// Exemplo do apêndice B da RFC 7636
+    #[test]
+    fn test_pkce_s256_challenge() {
+        let pkce = Pkce::from_verifier("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk".into());
+        assert_eq!(
+            pkce.challenge,
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+
+        let random = Pkce::new().unwrap();
+        assert_eq!(random.verifier.len(), 43);
+    }
+
+    #[test]
+    fn test_token_form() {
+        let form = token_form(&config(OAuth2Grant::Password), &TokenGrant::Password);
+        assert_eq!(
+            form,
+            vec![
+                ("grant_type", "password".to_string()),
+                ("username", "alice".to_string()),
+                ("password", "pw".to_string()),
+                ("scope", "read write".to_string()),
+                ("audience", "https://api.example.com".to_string()),
+                ("client_id", "cli".to_string()),
+            ]
+        );
+
+        let mut confidential = config(OAuth2Grant::AuthorizationCode);
+        confidential.client_secret = "s3cret".into();
+        let form = token_form(
+            &confidential,
+            &TokenGrant::AuthorizationCode {
+                code: "abc",
+                redirect_uri: "http://127.0.0.1:5000/callback",
+                verifier: "v",
+            },
+        );
+        assert!(form.contains(&("code_verifier", "v".to_string())));
+        assert!(
+            form.iter()
+                .all(|(key, _)| *key != "client_id" && *key != "scope")
+        );
+    }
+
+    #[test]
+    fn test_parse_token_response() {
+        let token = parse_token_response(
+            200,
+            r#"{"access_token":"abc123xyz","token_type":"bearer","expires_in":"3600","refresh_token":"r1"}"#,
+            1_000,
+        )
+        .unwrap();
+
+        assert_eq!(token.expires_at, Some(4_600));
+        assert_eq!(token.refresh_token.as_deref(), Some("r1"));
+        assert!(!token.is_expired(4_000));
+        assert!(token.is_expired(4_580));
+        assert_eq!(token.expiry_label(1_000), "expires in 60 min");
+        assert_eq!(token.masked(), "abc123••••••");
+
+        let error = parse_token_response(
+            401,
+            r#"{"error":"invalid_client","error_description":"bad secret"}"#,
+            0,
+        )
+        .unwrap_err();
+        assert_eq!(error, "OAuth2 error: invalid_client (bad secret)");
+        assert!(parse_token_response(502, "<html>", 0).is_err());
+    }
+
+    #[test]
+    fn test_authorization_url() {
+        let pkce = Pkce::from_verifier("verifier".into());
+        let url = authorization_url(
+            &config(OAuth2Grant::AuthorizationCode),
+            "http://127.0.0.1:5000/callback",
+            "st",
+            &pkce,
+        )
+        .unwrap();
+
+        assert!(
+            url.starts_with("https://idp.example.com/authorize?response_type=code&client_id=cli")
+        );
+        assert!(url.contains("redirect_uri=http%3A%2F%2F127.0.0.1%3A5000%2Fcallback"));
+        assert!(url.contains("code_challenge_method=S256"));
+        assert!(url.contains("scope=read+write"));
+    }
+
+    #[test]
+    fn test_parse_callback() {
+        assert_eq!(parse_callback("/favicon.ico", "st"), None);
+        assert_eq!(
+            parse_callback("/callback?code=xyz&state=st", "st"),
+            Some(Ok("xyz".to_string()))
+        );
+        assert!(
+            parse_callback("/callback?code=xyz&state=other", "st")
+                .unwrap()
+                .is_err()
+        );
+        assert_eq!(
+            parse_callback("/callback?error=access_denied&state=st", "st"),
+            Some(Err("Authorization denied: access_denied".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_cache_key_separates_configs() {
+        let client = config(OAuth2Grant::ClientCredentials);
+        let mut other_scope = client.clone();
+        other_scope.scope = "admin".into();
+
+        assert_ne!(cache_key(&client), cache_key(&other_scope));
+        assert_eq!(
+            cache_key(&client),
+            "Client Credentials · cli @ https://idp.example.com/token [read write] for https://api.example.com"
+        );
+    }
+}
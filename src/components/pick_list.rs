@@ -1,22 +1,38 @@
 use iced::Element;
-use iced::widget::{column, pick_list};
+use iced::widget::{column, combo_box};
 
 use crate::components::enums::{HTTPMethod, Message};
 
 impl HTTPMethod {
-    const ALL: [HTTPMethod; 5] = [
+    pub const ALL: [HTTPMethod; 9] = [
         HTTPMethod::GET,
         HTTPMethod::POST,
         HTTPMethod::PUT,
         HTTPMethod::PATCH,
         HTTPMethod::DELETE,
+        HTTPMethod::HEAD,
+        HTTPMethod::OPTIONS,
+        HTTPMethod::TRACE,
+        HTTPMethod::CONNECT,
     ];
 }
 
-pub fn pick_list_view(selected_method: Option<HTTPMethod>) -> Element<'static, Message> {
+/// Seletor editável: escolhe um método da lista ou digita um verbo customizado,
+/// aplicado quando o combo perde o foco
+pub fn pick_list_view<'a>(
+    methods: &'a combo_box::State<HTTPMethod>,
+    selected_method: &HTTPMethod,
+) -> Element<'a, Message> {
     column![
-        pick_list(&HTTPMethod::ALL[..], selected_method, Message::HTTPSelected,)
-            .placeholder("Method")
+        combo_box(
+            methods,
+            "Method",
+            Some(selected_method),
+            Message::HTTPSelected
+        )
+        .on_input(Message::MethodInputChanged)
+        .on_close(Message::MethodInputClosed)
+        .width(130)
     ]
     .into()
 }
@@ -32,6 +48,11 @@ impl std::fmt::Display for HTTPMethod {
                 HTTPMethod::PUT => "PUT",
                 HTTPMethod::PATCH => "PATCH",
                 HTTPMethod::DELETE => "DELETE",
+                HTTPMethod::HEAD => "HEAD",
+                HTTPMethod::OPTIONS => "OPTIONS",
+                HTTPMethod::TRACE => "TRACE",
+                HTTPMethod::CONNECT => "CONNECT",
+                HTTPMethod::Custom(verb) => verb,
             }
         )
     }
@@ -0,0 +1,401 @@
+//! Server-Sent Events (`text/event-stream`): parser, sessão exibida na UI e a
+//! subscription que lê os eventos enquanto a conexão está aberta
+
+use super::auth;
+use super::enums::HttpRequest;
+use super::http_client::HttpClient;
+use chrono::{DateTime, Local};
+use iced::futures::{SinkExt, Stream};
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex};
+
+/// Eventos mantidos na sessão; os mais antigos são descartados
+const MAX_EVENTS: usize = 1000;
+
+/// Evento despachado pelo servidor (bloco terminado por uma linha em branco)
+#[derive(Debug, Clone, PartialEq)]
+pub struct SseEvent {
+    /// Último `id` recebido até este evento, como no `lastEventId` do navegador
+    pub id: String,
+    /// Tipo do evento; `message` quando o campo `event` não é enviado
+    pub event: String,
+    pub data: String,
+    pub received_at: DateTime<Local>,
+}
+
+/// Mensagens da subscription para a sessão
+#[derive(Debug, Clone)]
+pub enum SseUpdate {
+    Connected,
+    Event(SseEvent),
+    /// Intervalo de reconexão (`retry`) sugerido pelo servidor, em ms
+    Retry(u64),
+    /// Conexão encerrada: `None` quando o servidor fechou o stream
+    Closed(Option<String>),
+}
+
+/// Parser incremental: recebe os bytes como chegam, mesmo com linhas divididas
+#[derive(Debug, Default)]
+pub struct SseParser {
+    line: Vec<u8>,
+    /// `\r` no fim do último chunk: um `\n` no início do próximo faz parte do mesmo fim de linha
+    skip_lf: bool,
+    started: bool,
+    event: String,
+    data: String,
+    has_data: bool,
+    last_event_id: String,
+}
+
+impl SseParser {
+    pub fn feed(&mut self, chunk: &[u8]) -> Vec<SseUpdate> {
+        let mut updates = Vec::new();
+
+        for &byte in chunk {
+            if std::mem::take(&mut self.skip_lf) && byte == b'\n' {
+                continue;
+            }
+            match byte {
+                b'\r' | b'\n' => {
+                    self.skip_lf = byte == b'\r';
+                    let line = std::mem::take(&mut self.line);
+                    updates.extend(self.process_line(&line));
+                }
+                _ => self.line.push(byte),
+            }
+        }
+
+        updates
+    }
+
+    fn process_line(&mut self, line: &[u8]) -> Option<SseUpdate> {
+        let mut line = String::from_utf8_lossy(line).into_owned();
+        if !std::mem::replace(&mut self.started, true) && line.starts_with('\u{feff}') {
+            line.remove(0);
+        }
+
+        if line.is_empty() {
+            return self.dispatch();
+        }
+        if line.starts_with(':') {
+            return None;
+        }
+
+        let (field, value) = match line.split_once(':') {
+            Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+            None => (line.as_str(), ""),
+        };
+
+        match field {
+            "event" => self.event = value.to_string(),
+            "data" => {
+                if self.has_data {
+                    self.data.push('\n');
+                }
+                self.data.push_str(value);
+                self.has_data = true;
+            }
+            "id" if !value.contains('\0') => self.last_event_id = value.to_string(),
+            "retry" if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) => {
+                return value.parse().ok().map(SseUpdate::Retry);
+            }
+            _ => {}
+        }
+        None
+    }
+
+    /// Linha em branco: entrega o evento acumulado, se houver `data`
+    fn dispatch(&mut self) -> Option<SseUpdate> {
+        let event = std::mem::take(&mut self.event);
+        if !std::mem::take(&mut self.has_data) {
+            return None;
+        }
+
+        Some(SseUpdate::Event(SseEvent {
+            id: self.last_event_id.clone(),
+            event: if event.is_empty() {
+                "message".to_string()
+            } else {
+                event
+            },
+            data: std::mem::take(&mut self.data),
+            received_at: Local::now(),
+        }))
+    }
+}
+
+/// Resposta `text/event-stream` aberta pelo envio, entregue à subscription sem ler o body
+#[derive(Clone, Default)]
+pub struct EventStream(Arc<Mutex<Option<reqwest::Response>>>);
+
+impl EventStream {
+    pub fn new(response: reqwest::Response) -> Self {
+        Self(Arc::new(Mutex::new(Some(response))))
+    }
+
+    /// A conexão só pode ser lida uma vez
+    fn take(&self) -> Option<reqwest::Response> {
+        self.0.lock().ok().and_then(|mut response| response.take())
+    }
+}
+
+impl std::fmt::Debug for EventStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("EventStream")
+    }
+}
+
+/// Estado do viewer de eventos da última resposta SSE
+#[derive(Debug)]
+pub struct SseSession {
+    pub id: u64,
+    /// Requisição enviada (já com variáveis resolvidas), repetida ao reconectar
+    request: HttpRequest,
+    stream: Option<EventStream>,
+    /// Muda a cada reconexão para reiniciar a subscription
+    generation: u32,
+    pub running: bool,
+    pub connected: bool,
+    pub paused: bool,
+    pub events: Vec<SseEvent>,
+    /// Eventos recebidos durante a pausa, exibidos ao retomar
+    pub pending: Vec<SseEvent>,
+    pub last_event_id: String,
+    pub retry_ms: Option<u64>,
+    pub error: Option<String>,
+}
+
+impl SseSession {
+    pub fn new(id: u64, request: HttpRequest, stream: EventStream) -> Self {
+        Self {
+            id,
+            request,
+            stream: Some(stream),
+            generation: 0,
+            running: true,
+            connected: true,
+            paused: false,
+            events: Vec::new(),
+            pending: Vec::new(),
+            last_event_id: String::new(),
+            retry_ms: None,
+            error: None,
+        }
+    }
+
+    pub fn apply(&mut self, update: SseUpdate) {
+        match update {
+            SseUpdate::Connected => {
+                self.connected = true;
+                self.error = None;
+            }
+            SseUpdate::Event(event) => {
+                self.last_event_id = event.id.clone();
+                let target = if self.paused {
+                    &mut self.pending
+                } else {
+                    &mut self.events
+                };
+                target.push(event);
+                self.trim();
+            }
+            SseUpdate::Retry(retry_ms) => self.retry_ms = Some(retry_ms),
+            SseUpdate::Closed(error) => {
+                self.running = false;
+                self.connected = false;
+                self.error = error;
+            }
+        }
+    }
+
+    pub fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+        if !self.paused {
+            self.events.append(&mut self.pending);
+            self.trim();
+        }
+    }
+
+    /// Fecha a conexão; a subscription deixa de existir
+    pub fn stop(&mut self) {
+        self.running = false;
+        self.connected = false;
+    }
+
+    /// Reenvia a requisição com `Last-Event-ID` para continuar de onde parou
+    pub fn reconnect(&mut self) {
+        self.stop();
+        self.stream = None;
+        self.generation += 1;
+        self.running = true;
+        self.error = None;
+    }
+
+    /// Conexão lida pela subscription enquanto a sessão está ativa
+    pub fn connection(&self, client: &HttpClient) -> Option<SseConnection> {
+        self.running.then(|| {
+            let mut request = self.request.clone();
+            if self.stream.is_none() && !self.last_event_id.is_empty() {
+                auth::set_header(&mut request, "Last-Event-ID", self.last_event_id.clone());
+            }
+
+            SseConnection {
+                session: self.id,
+                generation: self.generation,
+                client: client.clone(),
+                request,
+                stream: self.stream.clone(),
+            }
+        })
+    }
+
+    fn trim(&mut self) {
+        for list in [&mut self.events, &mut self.pending] {
+            if list.len() > MAX_EVENTS {
+                list.drain(..list.len() - MAX_EVENTS);
+            }
+        }
+    }
+}
+
+/// Identidade da subscription: sessão e reconexão
+pub struct SseConnection {
+    session: u64,
+    generation: u32,
+    client: HttpClient,
+    request: HttpRequest,
+    stream: Option<EventStream>,
+}
+
+impl Hash for SseConnection {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.session.hash(state);
+        self.generation.hash(state);
+    }
+}
+
+/// Lê os eventos da conexão aberta pelo envio ou, ao reconectar, de uma nova requisição
+pub fn listen(connection: &SseConnection) -> impl Stream<Item = SseUpdate> + use<> {
+    let client = connection.client.clone();
+    let request = connection.request.clone();
+    let stream = connection.stream.clone();
+
+    iced::stream::channel(
+        100,
+        async move |mut output: iced::futures::channel::mpsc::Sender<SseUpdate>| {
+            let response = match stream.and_then(|stream| stream.take()) {
+                Some(response) => Ok(response),
+                None => client.open_event_stream(request).await,
+            };
+
+            let mut response = match response {
+                Ok(response) => response,
+                Err(error) => {
+                    let _ = output.send(SseUpdate::Closed(Some(error))).await;
+                    return;
+                }
+            };
+
+            let _ = output.send(SseUpdate::Connected).await;
+
+            let mut parser = SseParser::default();
+            let closed = loop {
+                match response.chunk().await {
+                    Ok(Some(chunk)) => {
+                        for update in parser.feed(&chunk) {
+                            let _ = output.send(update).await;
+                        }
+                    }
+                    Ok(None) => break None,
+                    Err(e) => break Some(format!("Event stream interrupted: {}", e)),
+                }
+            };
+            let _ = output.send(SseUpdate::Closed(closed)).await;
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn events(updates: Vec<SseUpdate>) -> Vec<(String, String, String)> {
+        updates
+            .into_iter()
+            .filter_map(|update| match update {
+                SseUpdate::Event(e) => Some((e.event, e.id, e.data)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parses_fields_across_chunks() {
+        let mut parser = SseParser::default();
+        let mut updates = parser.feed(b"\xef\xbb\xbf: keep-alive\r\nevent: delta\r\nid: 7\r");
+        updates.extend(parser.feed(b"\ndata: {\"text\":\"Hel"));
+        updates.extend(parser.feed(b"lo\"}\ndata:second line\n\nretry: 3000\n"));
+        updates.extend(parser.feed(b"data: done\n\n"));
+
+        assert!(
+            updates
+                .iter()
+                .any(|update| matches!(update, SseUpdate::Retry(3000)))
+        );
+        assert_eq!(
+            events(updates),
+            vec![
+                (
+                    "delta".to_string(),
+                    "7".to_string(),
+                    "{\"text\":\"Hello\"}\nsecond line".to_string()
+                ),
+                // O tipo volta a `message`; o id continua o último recebido
+                ("message".to_string(), "7".to_string(), "done".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ignores_blocks_without_data() {
+        let mut parser = SseParser::default();
+        let updates = parser.feed(b"event: ping\n\nid: 1\nretry: soon\n\ndata\n\n");
+
+        // `data` sem valor gera um evento vazio; `retry` inválido é ignorado
+        assert_eq!(updates.len(), 1);
+        assert_eq!(
+            events(updates),
+            vec![("message".to_string(), "1".to_string(), String::new())]
+        );
+    }
+
+    #[test]
+    fn test_session_pause_keeps_events_for_resume() {
+        let mut session = SseSession::new(1, HttpRequest::default(), EventStream::default());
+        let mut parser = SseParser::default();
+
+        session.toggle_pause();
+        for update in parser.feed(b"id: a\ndata: 1\n\nid: b\ndata: 2\n\n") {
+            session.apply(update);
+        }
+        assert!(session.events.is_empty());
+        assert_eq!(session.pending.len(), 2);
+        assert_eq!(session.last_event_id, "b");
+
+        session.toggle_pause();
+        assert_eq!(session.events.len(), 2);
+        assert!(session.pending.is_empty());
+
+        session.apply(SseUpdate::Closed(None));
+        assert!(!session.running);
+        session.reconnect();
+        let connection = session.connection(&HttpClient::in_memory()).unwrap();
+        assert!(
+            connection
+                .request
+                .headers
+                .iter()
+                .any(|h| h.key == "Last-Event-ID" && h.value == "b")
+        );
+    }
+}
@@ -0,0 +1,38 @@
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const APP_NAME: &str = "http-client";
+
+/// Retorna o caminho de um arquivo no diretório de configuração do app,
+/// criando o diretório se necessário
+pub fn config_file_path(file_name: &str) -> PathBuf {
+    let config_dir = dirs::config_dir()
+        .map(|dir| dir.join(APP_NAME))
+        .unwrap_or_else(|| PathBuf::from("."));
+
+    let _ = fs::create_dir_all(&config_dir);
+
+    config_dir.join(file_name)
+}
+
+/// Carrega um arquivo JSON; retorna o valor padrão se o arquivo não existir
+pub fn load_json<T: DeserializeOwned + Default>(path: &Path, what: &str) -> Result<T, String> {
+    if !path.exists() {
+        return Ok(T::default());
+    }
+
+    let contents =
+        fs::read_to_string(path).map_err(|e| format!("Failed to read {} file: {}", what, e))?;
+
+    serde_json::from_str(&contents).map_err(|e| format!("Failed to parse {} file: {}", what, e))
+}
+
+/// Serializa um valor em JSON formatado e grava no disco
+pub fn save_json<T: Serialize + ?Sized>(path: &Path, value: &T, what: &str) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(value)
+        .map_err(|e| format!("Failed to serialize {}: {}", what, e))?;
+
+    fs::write(path, json).map_err(|e| format!("Failed to write {} file: {}", what, e))
+}
@@ -25,6 +25,18 @@ pub fn error_card(_theme: &Theme) -> container::Style {
     }
 }
 
+pub fn notice_card(_theme: &Theme) -> container::Style {
+    container::Style {
+        border: iced::Border {
+            width: 1.0,
+            color: iced::Color::from_rgb(0.5, 0.5, 0.5),
+            radius: 4.0.into(),
+        },
+        background: Some(iced::Color::from_rgba(0.5, 0.5, 0.5, 0.1).into()),
+        ..container::Style::default()
+    }
+}
+
 pub fn config_card(_theme: &Theme) -> container::Style {
     container::Style {
         border: iced::Border {
@@ -0,0 +1,287 @@
+use crate::components::enums::{
+    ApiKeyLocation, Auth, AuthField, AuthKind, Message, OAuth2Config, OAuth2Grant,
+};
+use crate::components::oauth2::{self, OAuth2Token};
+use crate::components::styles;
+use iced::Element;
+use iced::Length::Fill;
+use iced::widget::{Column, button, column, container, pick_list, row, text, text_input};
+
+/// Estado do cache de tokens exibido junto da configuração OAuth 2.0
+pub struct OAuth2Status {
+    /// Chave da configuração atual, com variáveis já resolvidas
+    pub current_key: Option<String>,
+    pub tokens: Vec<(String, OAuth2Token)>,
+    pub fetching: bool,
+}
+
+pub fn view_auth_editor(auth: &Auth, oauth2_status: OAuth2Status) -> Element<'_, Message> {
+    let selector = row![
+        text("Type:").size(14),
+        pick_list(
+            &AuthKind::ALL[..],
+            Some(auth.kind()),
+            Message::AuthKindSelected
+        ),
+    ]
+    .spacing(8)
+    .align_y(iced::alignment::Vertical::Center);
+
+    let mut content = column![selector].spacing(12).padding(16);
+
+    content = match auth {
+        Auth::None => content.push(view_hint("This request does not use any authorization.")),
+        Auth::Basic { username, password } => content
+            .push(view_field("Username", username, AuthField::Username, false))
+            .push(view_field("Password", password, AuthField::Password, true)),
+        Auth::Bearer { token } => content.push(view_field("Token", token, AuthField::Token, true)),
+        Auth::ApiKey {
+            name,
+            value,
+            location,
+        } => content
+            .push(view_field("Key", name, AuthField::KeyName, false))
+            .push(view_field("Value", value, AuthField::KeyValue, true))
+            .push(
+                row![
+                    text("Add to:").size(14),
+                    pick_list(
+                        &ApiKeyLocation::ALL[..],
+                        Some(*location),
+                        Message::ApiKeyLocationSelected
+                    ),
+                ]
+                .spacing(8)
+                .align_y(iced::alignment::Vertical::Center),
+            ),
+        Auth::Digest { username, password } => content
+            .push(view_field("Username", username, AuthField::Username, false))
+            .push(view_field("Password", password, AuthField::Password, true))
+            .push(view_hint(
+                "The server challenge is answered automatically (MD5 or SHA-256, qop=auth).",
+            )),
+        Auth::AwsSigV4(config) => content
+            .push(view_field(
+                "Access Key",
+                &config.access_key,
+                AuthField::AccessKey,
+                false,
+            ))
+            .push(view_field(
+                "Secret Key",
+                &config.secret_key,
+                AuthField::SecretKey,
+                true,
+            ))
+            .push(view_field(
+                "Session",
+                &config.session_token,
+                AuthField::SessionToken,
+                true,
+            ))
+            .push(view_field("Region", &config.region, AuthField::Region, false))
+            .push(view_field("Service", &config.service, AuthField::Service, false))
+            .push(view_hint(
+                "Signed on send over the final URL, headers and body. Session token is optional; use service \"s3\" for S3/MinIO.",
+            )),
+        Auth::OAuth2(config) => content
+            .push(view_oauth2_config(config))
+            .push(view_oauth2_token(config, &oauth2_status)),
+    };
+
+    if !oauth2_status.tokens.is_empty() {
+        content = content.push(view_token_cache(oauth2_status.tokens));
+    }
+
+    if auth.kind() != AuthKind::None {
+        content = content.push(view_hint(
+            "Applied when sending; overrides a header or query param with the same name.",
+        ));
+    }
+
+    content.into()
+}
+
+fn view_field<'a>(
+    label: &'static str,
+    value: &'a str,
+    field: AuthField,
+    secret: bool,
+) -> Element<'a, Message> {
+    row![
+        text(label).size(14).width(90),
+        text_input(label, value)
+            .on_input(move |value| Message::AuthFieldChanged(field, value))
+            .secure(secret)
+            .width(Fill),
+    ]
+    .spacing(8)
+    .align_y(iced::alignment::Vertical::Center)
+    .into()
+}
+
+fn view_hint(message: &'static str) -> Element<'static, Message> {
+    text(message)
+        .size(12)
+        .color(iced::Color::from_rgb(0.55, 0.55, 0.55))
+        .into()
+}
+
+fn view_oauth2_config(config: &OAuth2Config) -> Element<'_, Message> {
+    let mut fields = Column::new().spacing(12).push(
+        row![
+            text("Grant type").size(14).width(90),
+            pick_list(
+                &OAuth2Grant::ALL[..],
+                Some(config.grant),
+                Message::OAuth2GrantSelected
+            ),
+        ]
+        .spacing(8)
+        .align_y(iced::alignment::Vertical::Center),
+    );
+
+    if config.grant == OAuth2Grant::AuthorizationCode {
+        fields = fields.push(view_field(
+            "Auth URL",
+            &config.auth_url,
+            AuthField::AuthUrl,
+            false,
+        ));
+    }
+
+    fields = fields
+        .push(view_field(
+            "Token URL",
+            &config.token_url,
+            AuthField::TokenUrl,
+            false,
+        ))
+        .push(view_field(
+            "Client ID",
+            &config.client_id,
+            AuthField::ClientId,
+            false,
+        ))
+        .push(view_field(
+            "Secret",
+            &config.client_secret,
+            AuthField::ClientSecret,
+            true,
+        ));
+
+    if config.grant == OAuth2Grant::Password {
+        fields = fields
+            .push(view_field(
+                "Username",
+                &config.username,
+                AuthField::Username,
+                false,
+            ))
+            .push(view_field(
+                "Password",
+                &config.password,
+                AuthField::Password,
+                true,
+            ));
+    }
+
+    fields
+        .push(view_field("Scope", &config.scope, AuthField::Scope, false))
+        .push(view_field(
+            "Audience",
+            &config.audience,
+            AuthField::Audience,
+            false,
+        ))
+        .into()
+}
+
+fn view_oauth2_token<'a>(config: &OAuth2Config, status: &OAuth2Status) -> Element<'a, Message> {
+    let now = oauth2::now();
+    let current = status
+        .current_key
+        .as_ref()
+        .and_then(|key| status.tokens.iter().find(|(k, _)| k == key))
+        .map(|(_, token)| token);
+
+    let description = match (current, config.grant) {
+        (Some(token), _) => format!(
+            "Current token: {} · {}",
+            token.masked(),
+            token.expiry_label(now)
+        ),
+        (None, OAuth2Grant::AuthorizationCode) => {
+            "No token yet: sign in with Get New Token (opens the browser)".to_string()
+        }
+        (None, _) => "No token yet: it is fetched automatically on send".to_string(),
+    };
+
+    let label = if status.fetching {
+        if config.grant == OAuth2Grant::AuthorizationCode {
+            "Waiting for browser…"
+        } else {
+            "Fetching…"
+        }
+    } else {
+        "Get New Token"
+    };
+
+    row![
+        button(text(label).size(13))
+            .on_press_maybe((!status.fetching).then_some(Message::FetchOAuth2Token))
+            .padding([8, 14])
+            .style(button::primary),
+        text(description)
+            .size(12)
+            .color(iced::Color::from_rgb(0.7, 0.7, 0.7)),
+    ]
+    .spacing(12)
+    .align_y(iced::alignment::Vertical::Center)
+    .into()
+}
+
+/// Tokens guardados; expirados são renovados automaticamente no próximo envio
+fn view_token_cache<'a>(tokens: Vec<(String, OAuth2Token)>) -> Element<'a, Message> {
+    let now = oauth2::now();
+    let mut list = column![
+        row![
+            text("Token cache").size(14),
+            iced::widget::space::horizontal(),
+            button(text("Clear all").size(12))
+                .on_press(Message::ClearOAuth2Tokens)
+                .padding([4, 10])
+                .style(button::danger),
+        ]
+        .align_y(iced::alignment::Vertical::Center),
+    ]
+    .spacing(8);
+
+    for (key, token) in tokens {
+        let details = format!("{} · {}", token.masked(), token.expiry_label(now));
+        list = list.push(
+            row![
+                column![
+                    text(key.clone()).size(12),
+                    text(details)
+                        .size(11)
+                        .color(iced::Color::from_rgb(0.55, 0.55, 0.55)),
+                ]
+                .spacing(2)
+                .width(Fill),
+                button(text("✕").size(12))
+                    .on_press(Message::RemoveOAuth2Token(key))
+                    .padding([4, 8])
+                    .style(button::secondary),
+            ]
+            .spacing(8)
+            .align_y(iced::alignment::Vertical::Center),
+        );
+    }
+
+    container(list)
+        .padding(12)
+        .width(Fill)
+        .style(styles::config_card)
+        .into()
+}
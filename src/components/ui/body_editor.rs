@@ -1,55 +1,160 @@
-use crate::components::enums::{BodyType, HTTPMethod, Message};
+use super::form_data_editor::view_form_data_editor;
+use super::graphql_editor::{GraphQlEditor, view_graphql_editor};
+use super::key_value_list::view_key_value_list;
+use crate::components::enums::{BodyType, FormPart, HTTPMethod, KeyValue, Message};
 use crate::components::styles;
-use iced::Element;
-use iced::widget::{Button, button, column, container, row, text, text_editor};
+use iced::widget::{
+    Button, button, checkbox, column, container, row, text, text_editor, text_input,
+};
+use iced::{Element, Length};
 
+#[allow(clippy::too_many_arguments)]
 pub fn view_body_editor<'a>(
-    method: HTTPMethod,
+    method: &HTTPMethod,
+    force_body: bool,
     body_type: BodyType,
     body_content: &'a text_editor::Content,
+    body_error: Option<&'a str>,
+    form_data: &'a [FormPart],
+    form_urlencoded: &'a [KeyValue],
+    binary_file: &'a str,
+    graphql: Option<GraphQlEditor<'a>>,
 ) -> Element<'a, Message> {
-    if method == HTTPMethod::GET {
-        return view_body_unavailable();
+    let forced = force_body && method.can_force_body();
+
+    if !method.allows_body() && !forced {
+        // GraphQL via GET: a operação vai nos query params
+        return match graphql {
+            Some(graphql) if *method == HTTPMethod::GET => {
+                let mut content = column![
+                    text(
+                        "GET: the query, variables and operation name are sent as query parameters"
+                    )
+                    .size(12)
+                    .color(iced::Color::from_rgb(0.5, 0.5, 0.5)),
+                ]
+                .spacing(12)
+                .padding(16);
+                if let Some(error) = body_error {
+                    content = content.push(view_body_error(error));
+                }
+                content.push(view_graphql_editor(graphql)).into()
+            }
+            _ => view_body_unavailable(method),
+        };
     }
 
-    column![
-        view_body_type_selector(body_type),
-        view_body_input(body_type, body_content),
-    ]
-    .spacing(12)
-    .padding(16)
-    .into()
+    let mut content = column![].spacing(12).padding(16);
+
+    if forced {
+        content = content.push(view_force_body_toggle(true));
+    }
+
+    let input = match body_type {
+        BodyType::FormData => view_form_data_editor(form_data),
+        BodyType::FormUrlEncoded => view_key_value_list(
+            form_urlencoded,
+            "key",
+            "value",
+            Message::UrlEncodedFieldKeyChanged,
+            Message::UrlEncodedFieldValueChanged,
+            Message::UrlEncodedFieldEnabledToggled,
+            Message::RemoveUrlEncodedField,
+            Message::AddUrlEncodedField,
+            "+ Add Field",
+        ),
+        BodyType::Binary => view_binary_file_input(binary_file),
+        BodyType::GraphQl => match graphql {
+            Some(graphql) => view_graphql_editor(graphql),
+            None => view_body_input(body_type, body_content),
+        },
+        _ => view_body_input(body_type, body_content),
+    };
+
+    content
+        .push(view_body_type_selector(body_type, body_error))
+        .push(input)
+        .into()
 }
 
-fn view_body_unavailable<'a>() -> Element<'a, Message> {
-    container(
-        text("📝 Body is not available for GET requests")
+fn view_body_unavailable<'a>(method: &HTTPMethod) -> Element<'a, Message> {
+    let mut content = column![
+        text(format!("📝 Body is not available for {} requests", method))
             .size(14)
             .color(iced::Color::from_rgb(0.5, 0.5, 0.5)),
-    )
-    .padding(24)
-    .into()
+    ]
+    .spacing(12);
+
+    if method.can_force_body() {
+        content = content.push(view_force_body_toggle(false));
+    }
+
+    container(content).padding(24).into()
 }
 
-fn view_body_type_selector(body_type: BodyType) -> Element<'static, Message> {
-    row![
-        text("Body Type:").size(14),
-        body_type_button("None", BodyType::None, body_type),
-        body_type_button("Raw", BodyType::Raw, body_type),
-        body_type_button("JSON", BodyType::Json, body_type),
-    ]
-    .spacing(8)
-    .into()
+fn view_force_body_toggle<'a>(force_body: bool) -> Element<'a, Message> {
+    checkbox(force_body)
+        .label("Send body anyway (e.g. Elasticsearch-style APIs)")
+        .on_toggle(Message::ForceBodyToggled)
+        .into()
+}
+
+/// Tipos de body; abaixo dos botões fica o erro de sintaxe do body atual
+fn view_body_type_selector(body_type: BodyType, body_error: Option<&str>) -> Element<'_, Message> {
+    let mut types = row![text("Body Type:").size(14)].spacing(8);
+    for option in [
+        BodyType::None,
+        BodyType::Raw,
+        BodyType::Json,
+        BodyType::Xml,
+        BodyType::Yaml,
+        BodyType::Html,
+        BodyType::FormData,
+        BodyType::FormUrlEncoded,
+        BodyType::Binary,
+        BodyType::GraphQl,
+    ] {
+        types = types.push(body_type_button(option, body_type, body_error.is_some()));
+    }
+
+    let mut selector = row![types.width(Length::Fill).wrap()].spacing(8);
+    if body_type.can_format() {
+        selector = selector.push(
+            button(text("Format").size(13))
+                .on_press(Message::FormatBody)
+                .padding([8, 14])
+                .style(button::secondary),
+        );
+        selector = selector.push(
+            button(text("Minify").size(13))
+                .on_press(Message::MinifyBody)
+                .padding([8, 14])
+                .style(button::secondary),
+        );
+    }
+
+    let mut content = column![selector].spacing(8);
+    if let Some(error) = body_error {
+        content = content.push(view_body_error(error));
+    }
+    content.into()
+}
+
+/// Erro de sintaxe do body (com linha e coluna), exibido antes do envio
+fn view_body_error(error: &str) -> Element<'_, Message> {
+    text(error).size(12).style(text::danger).into()
 }
 
 fn view_body_input<'a>(
     body_type: BodyType,
     body_content: &'a text_editor::Content,
 ) -> Element<'a, Message> {
-    let placeholder = if body_type == BodyType::Json {
-        "Enter JSON: {\"key\": \"value\"}"
-    } else {
-        "Enter body content..."
+    let placeholder = match body_type {
+        BodyType::Json => "Enter JSON: {\"key\": \"value\"}",
+        BodyType::Xml => "Enter XML: <root><key>value</key></root>",
+        BodyType::Yaml => "Enter YAML: key: value",
+        BodyType::Html => "Enter HTML: <p>content</p>",
+        _ => "Enter body content...",
     };
 
     container(
@@ -63,18 +168,39 @@ fn view_body_input<'a>(
     .into()
 }
 
+/// Caminho do arquivo enviado como body; o conteúdo só é lido no envio
+fn view_binary_file_input(binary_file: &str) -> Element<'_, Message> {
+    column![
+        row![
+            text_input("/path/to/file", binary_file)
+                .on_input(Message::BinaryFileChanged)
+                .width(Length::Fill),
+            button(text("Choose…"))
+                .on_press(Message::PickBinaryFile)
+                .style(button::secondary),
+        ]
+        .spacing(8),
+        text("The file is read when the request is sent")
+            .size(12)
+            .color(iced::Color::from_rgb(0.5, 0.5, 0.5)),
+    ]
+    .spacing(8)
+    .into()
+}
+
+/// O botão do tipo selecionado fica vermelho enquanto o body for inválido
 fn body_type_button(
-    label: &'static str,
     body_type: BodyType,
     current_type: BodyType,
+    invalid: bool,
 ) -> Button<'static, Message> {
-    let btn = button(text(label).size(13))
+    let btn = button(text(body_type.to_string()).size(13))
         .on_press(Message::BodyTypeChanged(body_type))
         .padding([8, 14]);
 
-    if current_type == body_type {
-        btn.style(button::primary)
-    } else {
-        btn.style(button::secondary)
+    match (current_type == body_type, invalid) {
+        (true, true) => btn.style(button::danger),
+        (true, false) => btn.style(button::primary),
+        (false, _) => btn.style(button::secondary),
     }
 }
@@ -0,0 +1,39 @@
+use crate::components::enums::Message;
+use crate::components::formats::codegen::CodeTarget;
+use crate::components::styles;
+use iced::widget::{button, column, container, pick_list, row, scrollable, text};
+use iced::{Element, Length};
+
+pub fn view_code_panel(target: CodeTarget, code: &Result<String, String>) -> Element<'_, Message> {
+    let header = row![
+        text("</> Generate code").size(16),
+        iced::widget::space::horizontal(),
+        pick_list(
+            &CodeTarget::ALL[..],
+            Some(target),
+            Message::CodeTargetSelected
+        )
+        .width(200),
+        button(text("Copy").size(13))
+            .on_press_maybe(code.is_ok().then_some(Message::CopyCode))
+            .style(button::secondary),
+    ]
+    .spacing(8)
+    .align_y(iced::alignment::Vertical::Center);
+
+    let content: Element<'_, Message> = match code {
+        Ok(code) => {
+            container(scrollable(text(code).font(iced::Font::MONOSPACE).size(13)).height(220))
+                .padding(12)
+                .width(Length::Fill)
+                .style(styles::body_input_border)
+                .into()
+        }
+        Err(error) => text(error).style(text::danger).into(),
+    };
+
+    container(column![header, content].spacing(12))
+        .padding(16)
+        .style(styles::config_card)
+        .into()
+}
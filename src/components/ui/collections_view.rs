@@ -0,0 +1,159 @@
+use crate::components::collections::{CollectionNode, RequestCollections};
+use crate::components::enums::Message;
+use crate::components::styles;
+use iced::widget::{button, column, container, row, text, text_input};
+use iced::{Element, Length};
+
+/// Recuo por nível de pasta na árvore
+const INDENT: f32 = 14.0;
+
+pub fn view_collections<'a>(
+    collections: &'a RequestCollections,
+    selection: Option<&[usize]>,
+    name_input: &'a str,
+) -> Element<'a, Message> {
+    let mut tree = column![view_collections_header(collections, selection, name_input)].spacing(6);
+
+    if collections.is_empty() {
+        tree = tree.push(view_empty_collections());
+    } else {
+        for (index, node) in collections.get_items().iter().enumerate() {
+            tree = push_node(tree, node, vec![index], selection);
+        }
+    }
+
+    container(tree)
+        .padding(16)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .style(styles::history_container)
+        .into()
+}
+
+fn view_collections_header<'a>(
+    collections: &RequestCollections,
+    selection: Option<&[usize]>,
+    name_input: &'a str,
+) -> Element<'a, Message> {
+    let has_selection = selection.is_some();
+    let request_selected = selection
+        .and_then(|path| collections.get(path))
+        .is_some_and(|node| !node.is_folder());
+
+    column![
+        text("📁 Collections")
+            .size(18)
+            .color(iced::Color::from_rgb(0.9, 0.9, 0.9)),
+        text_input("Name", name_input)
+            .on_input(Message::CollectionNameChanged)
+            .size(13),
+        row![
+            action_button("Save", request_selected.then_some(Message::SaveRequest)),
+            action_button("Save as", Some(Message::SaveRequestAs)),
+            action_button("+ Folder", Some(Message::AddCollectionFolder)),
+        ]
+        .spacing(6),
+        row![
+            action_button(
+                "Rename",
+                has_selection.then_some(Message::RenameCollectionNode)
+            ),
+            action_button(
+                "Duplicate",
+                has_selection.then_some(Message::DuplicateCollectionNode)
+            ),
+            button(text("Delete").size(12))
+                .on_press_maybe(has_selection.then_some(Message::DeleteCollectionNode))
+                .padding([6, 8])
+                .width(Length::Fill)
+                .style(button::danger),
+        ]
+        .spacing(6),
+        row![
+            action_button("⬇ Postman", Some(Message::ImportPostman)),
+            action_button("⬇ OpenAPI", Some(Message::ImportOpenApi)),
+            action_button(
+                "⬆ Export",
+                (!collections.is_empty()).then_some(Message::ExportCollections)
+            ),
+        ]
+        .spacing(6),
+    ]
+    .spacing(8)
+    .into()
+}
+
+fn action_button(label: &'static str, on_press: Option<Message>) -> Element<'static, Message> {
+    button(text(label).size(12))
+        .on_press_maybe(on_press)
+        .padding([6, 8])
+        .width(Length::Fill)
+        .style(button::secondary)
+        .into()
+}
+
+fn view_empty_collections() -> Element<'static, Message> {
+    container(
+        text("No saved requests yet")
+            .size(13)
+            .color(iced::Color::from_rgb(0.5, 0.5, 0.5)),
+    )
+    .padding(12)
+    .center(Length::Fill)
+    .into()
+}
+
+fn push_node<'a>(
+    mut tree: iced::widget::Column<'a, Message>,
+    node: &'a CollectionNode,
+    path: Vec<usize>,
+    selection: Option<&[usize]>,
+) -> iced::widget::Column<'a, Message> {
+    let depth = path.len() - 1;
+    let is_selected = selection == Some(path.as_slice());
+
+    let label = match node {
+        CollectionNode::Folder { name, expanded, .. } => {
+            let arrow = if *expanded { "▾" } else { "▸" };
+            row![text(format!("{} 📂 {}", arrow, name)).size(13)]
+        }
+        CollectionNode::Request { name, request } => row![
+            container(text(request.method.to_string()).size(11))
+                .padding([2, 6])
+                .style(styles::method_badge),
+            text(name.as_str()).size(13),
+        ]
+        .spacing(6),
+    };
+
+    let entry = button(label.align_y(iced::alignment::Vertical::Center))
+        .on_press(Message::SelectCollectionNode(path.clone()))
+        .padding([6, 8])
+        .width(Length::Fill);
+
+    let entry = if is_selected {
+        entry.style(button::primary)
+    } else {
+        entry.style(button::secondary)
+    };
+
+    tree = tree.push(container(entry).padding(iced::Padding {
+        left: INDENT * depth as f32,
+        ..iced::Padding::ZERO
+    }));
+
+    if let CollectionNode::Folder {
+        children,
+        expanded: true,
+        ..
+    } = node
+    {
+        for (index, child) in children.iter().enumerate() {
+            let mut child_path = path.clone();
+            child_path.push(index);
+            tree = push_node(tree, child, child_path, selection);
+        }
+    }
+
+    tree
+}
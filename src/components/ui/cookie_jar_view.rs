@@ -0,0 +1,151 @@
+use crate::components::cookies::{self, Cookie, CookieDraft, CookieField};
+use crate::components::enums::Message;
+use crate::components::styles;
+use iced::widget::{Column, button, column, container, row, text, text_input};
+use iced::{Element, Length};
+
+/// Gerenciador do cookie jar: cookies agrupados por domínio, com edição do valor,
+/// remoção e um formulário para adicionar cookies
+pub fn view_cookie_jar<'a>(cookies: Vec<Cookie>, draft: &CookieDraft) -> Element<'a, Message> {
+    let mut content = column![view_header(!cookies.is_empty()), view_draft(draft)].spacing(10);
+
+    if cookies.is_empty() {
+        content = content.push(
+            container(
+                text("No cookies stored")
+                    .size(13)
+                    .color(iced::Color::from_rgb(0.5, 0.5, 0.5)),
+            )
+            .padding(12)
+            .center_x(Length::Fill),
+        );
+    }
+
+    let now = cookies::now();
+    let mut domain: Option<String> = None;
+    let mut group = Column::new().spacing(6);
+    for cookie in cookies {
+        if domain.as_deref() != Some(cookie.domain.as_str()) {
+            if let Some(previous) = domain.take() {
+                content = content.push(view_domain(previous, group));
+                group = Column::new().spacing(6);
+            }
+            domain = Some(cookie.domain.clone());
+        }
+        group = group.push(view_cookie(cookie, now));
+    }
+    if let Some(domain) = domain {
+        content = content.push(view_domain(domain, group));
+    }
+
+    container(content)
+        .padding(16)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .style(styles::history_container)
+        .into()
+}
+
+fn view_header<'a>(has_cookies: bool) -> Element<'a, Message> {
+    row![
+        text("🍪 Cookies")
+            .size(18)
+            .color(iced::Color::from_rgb(0.9, 0.9, 0.9)),
+        iced::widget::space::horizontal(),
+        button(text("Clear all").size(12))
+            .on_press_maybe(has_cookies.then_some(Message::ClearCookies))
+            .padding([6, 12])
+            .style(button::danger),
+    ]
+    .align_y(iced::alignment::Vertical::Center)
+    .into()
+}
+
+/// Cookie de sessão para o domínio e subdomínios
+fn view_draft<'a>(draft: &CookieDraft) -> Element<'a, Message> {
+    column![
+        text_input("Domain (example.com)", &draft.domain)
+            .on_input(|value| Message::CookieDraftChanged(CookieField::Domain, value))
+            .size(12),
+        row![
+            text_input("Name", &draft.name)
+                .on_input(|value| Message::CookieDraftChanged(CookieField::Name, value))
+                .size(12),
+            text_input("Value", &draft.value)
+                .on_input(|value| Message::CookieDraftChanged(CookieField::Value, value))
+                .on_submit(Message::AddCookie)
+                .size(12),
+            button(text("Add").size(12))
+                .on_press(Message::AddCookie)
+                .padding([6, 12]),
+        ]
+        .spacing(6),
+    ]
+    .spacing(6)
+    .into()
+}
+
+fn view_domain<'a>(domain: String, cookies: Column<'a, Message>) -> Element<'a, Message> {
+    container(
+        column![
+            row![
+                text(domain.clone()).size(14),
+                iced::widget::space::horizontal(),
+                button(text("Clear").size(11))
+                    .on_press(Message::ClearCookieDomain(domain))
+                    .padding([4, 8])
+                    .style(button::secondary),
+            ]
+            .align_y(iced::alignment::Vertical::Center),
+            cookies,
+        ]
+        .spacing(8),
+    )
+    .padding(10)
+    .style(styles::history_item)
+    .into()
+}
+
+fn view_cookie<'a>(cookie: Cookie, now: i64) -> Element<'a, Message> {
+    let key = cookie.key();
+    let details = cookie_details(&cookie, now);
+
+    column![
+        row![
+            text(cookie.name.clone()).size(12),
+            iced::widget::space::horizontal(),
+            button(text("✕").size(11))
+                .on_press(Message::DeleteCookie(key.clone()))
+                .padding([2, 6])
+                .style(button::danger),
+        ]
+        .align_y(iced::alignment::Vertical::Center),
+        text_input("value", &cookie.value)
+            .on_input(move |value| Message::CookieValueChanged(key.clone(), value))
+            .size(12)
+            .font(iced::Font::MONOSPACE),
+        text(details)
+            .size(11)
+            .color(iced::Color::from_rgb(0.55, 0.55, 0.55)),
+    ]
+    .spacing(4)
+    .into()
+}
+
+/// Caminho, validade e flags do cookie em uma linha
+pub fn cookie_details(cookie: &Cookie, now: i64) -> String {
+    let mut details = vec![cookie.path.clone(), cookie.expiry_label(now)];
+    if cookie.host_only {
+        details.push("host only".to_string());
+    }
+    if cookie.secure {
+        details.push("Secure".to_string());
+    }
+    if cookie.http_only {
+        details.push("HttpOnly".to_string());
+    }
+    if let Some(same_site) = &cookie.same_site {
+        details.push(format!("SameSite={}", same_site));
+    }
+    details.join(" · ")
+}
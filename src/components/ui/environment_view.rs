@@ -0,0 +1,101 @@
+use crate::components::enums::Message;
+use crate::components::environment::EnvironmentStore;
+use crate::components::styles;
+use crate::components::ui::view_key_value_list;
+use iced::widget::{button, column, container, pick_list, row, text, text_input};
+use iced::{Element, Length};
+
+/// Opção exibida no seletor quando nenhum ambiente está ativo
+pub const NO_ENVIRONMENT: &str = "No environment";
+
+pub fn view_environment_selector(environments: &EnvironmentStore) -> Element<'_, Message> {
+    let mut options = vec![NO_ENVIRONMENT.to_string()];
+    options.extend(environments.get_items().iter().map(|e| e.name.clone()));
+
+    let selected = environments
+        .active()
+        .map(|e| e.name.clone())
+        .unwrap_or_else(|| NO_ENVIRONMENT.to_string());
+
+    row![
+        text("🌐").size(14),
+        pick_list(options, Some(selected), Message::EnvironmentSelected).width(200),
+        button(text("Manage").size(14))
+            .on_press(Message::ToggleEnvironmentEditor)
+            .style(button::secondary),
+    ]
+    .spacing(8)
+    .align_y(iced::alignment::Vertical::Center)
+    .into()
+}
+
+pub fn view_environment_editor(environments: &EnvironmentStore) -> Element<'_, Message> {
+    let header = row![
+        text("Environments").size(16),
+        iced::widget::space::horizontal(),
+        button(text("+ New Environment").size(13))
+            .on_press(Message::AddEnvironment)
+            .style(button::secondary),
+    ]
+    .spacing(8)
+    .align_y(iced::alignment::Vertical::Center);
+
+    let content: Element<'_, Message> = match environments.active() {
+        Some(env) => column![
+            row![
+                text_input("Environment name", &env.name)
+                    .on_input(Message::EnvironmentNameChanged)
+                    .width(Length::Fill),
+                button(text("Delete"))
+                    .on_press(Message::RemoveEnvironment)
+                    .style(button::danger),
+            ]
+            .spacing(8),
+            view_key_value_list(
+                &env.variables,
+                "variable",
+                "value",
+                Message::EnvironmentVarKeyChanged,
+                Message::EnvironmentVarValueChanged,
+                Message::EnvironmentVarToggled,
+                Message::RemoveEnvironmentVar,
+                Message::AddEnvironmentVar,
+                "+ Add Variable",
+            ),
+        ]
+        .spacing(8)
+        .into(),
+        None => text("Select or create an environment to edit its variables")
+            .size(13)
+            .color(iced::Color::from_rgb(0.5, 0.5, 0.5))
+            .into(),
+    };
+
+    container(column![header, content].spacing(12))
+        .padding(16)
+        .style(styles::config_card)
+        .into()
+}
+
+/// Destaca as variáveis `{{nome}}` sem valor no ambiente ativo
+pub fn view_unresolved_variables(names: &[String]) -> Element<'static, Message> {
+    let list = names
+        .iter()
+        .map(|n| format!("{{{{{}}}}}", n))
+        .collect::<Vec<_>>()
+        .join("  ");
+
+    container(
+        row![
+            text("Unresolved:").size(13),
+            text(list)
+                .size(13)
+                .font(iced::Font::MONOSPACE)
+                .color(iced::Color::from_rgb(0.9, 0.6, 0.0)),
+        ]
+        .spacing(8),
+    )
+    .padding([8, 12])
+    .style(styles::status_badge(iced::Color::from_rgb(0.9, 0.6, 0.0)))
+    .into()
+}
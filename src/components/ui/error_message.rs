@@ -10,6 +10,13 @@ pub fn view_error_message(error: &str) -> Element<'_, Message> {
         .into()
 }
 
+pub fn view_notice_message(notice: &str) -> Element<'_, Message> {
+    container(row![text("ℹ ").size(16), text(notice),].spacing(8))
+        .padding([12, 16])
+        .style(styles::notice_card)
+        .into()
+}
+
 pub fn view_empty_error() -> Element<'static, Message> {
     Element::from(container(text("")).height(0))
 }
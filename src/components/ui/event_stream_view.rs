@@ -0,0 +1,105 @@
+use crate::components::enums::Message;
+use crate::components::sse::{SseEvent, SseSession};
+use crate::components::styles;
+use crate::components::utils::json_formatter;
+use iced::widget::{Column, button, column, container, row, scrollable, text};
+use iced::{Element, Length};
+
+/// Eventos SSE recebidos, com pausa, parada e reconexão
+pub fn view_event_stream(session: &SseSession) -> Element<'_, Message> {
+    let mut events = Column::new().spacing(8);
+    for event in &session.events {
+        events = events.push(view_event(event));
+    }
+    if session.events.is_empty() {
+        events = events.push(
+            text("Waiting for events…")
+                .size(13)
+                .color(iced::Color::from_rgb(0.5, 0.5, 0.5)),
+        );
+    }
+
+    column![
+        view_toolbar(session),
+        container(scrollable(events).anchor_bottom().height(300)).padding(10),
+    ]
+    .spacing(8)
+    .into()
+}
+
+fn view_toolbar(session: &SseSession) -> Element<'_, Message> {
+    let status = match (&session.error, session.connected, session.paused) {
+        (Some(error), _, _) => text(format!("✗ {}", error)).style(text::danger),
+        (None, true, true) => text(format!("⏸ Paused ({} new events)", session.pending.len())),
+        (None, true, false) => text("● Connected").color(iced::Color::from_rgb(0.0, 0.7, 0.0)),
+        (None, false, _) if session.running => text("… Connecting"),
+        (None, false, _) => text("○ Disconnected").color(iced::Color::from_rgb(0.5, 0.5, 0.5)),
+    };
+
+    let mut details = vec![format!("{} events", session.events.len())];
+    if !session.last_event_id.is_empty() {
+        details.push(format!("last id: {}", session.last_event_id));
+    }
+    if let Some(retry_ms) = session.retry_ms {
+        details.push(format!("retry: {} ms", retry_ms));
+    }
+
+    let mut toolbar = row![
+        status.size(13),
+        text(details.join(" · "))
+            .size(12)
+            .color(iced::Color::from_rgb(0.5, 0.5, 0.5)),
+        iced::widget::space::horizontal(),
+        button(text(if session.paused { "Resume" } else { "Pause" }).size(13))
+            .on_press(Message::ToggleSsePause)
+            .style(button::secondary),
+    ]
+    .spacing(12)
+    .align_y(iced::alignment::Vertical::Center);
+
+    toolbar = if session.running {
+        toolbar.push(
+            button(text("Stop").size(13))
+                .on_press(Message::StopSse)
+                .style(button::danger),
+        )
+    } else {
+        toolbar.push(
+            button(text("Reconnect").size(13))
+                .on_press(Message::ReconnectSse)
+                .style(button::primary),
+        )
+    };
+
+    toolbar.into()
+}
+
+/// Horário, tipo e id do evento, com o `data` formatado quando é JSON
+fn view_event(event: &SseEvent) -> Element<'_, Message> {
+    let mut header = row![
+        text(event.received_at.format("%H:%M:%S%.3f").to_string())
+            .size(12)
+            .font(iced::Font::MONOSPACE),
+        text(&event.event).size(13),
+    ]
+    .spacing(12);
+    if !event.id.is_empty() {
+        header = header.push(
+            text(format!("id: {}", event.id))
+                .size(12)
+                .color(iced::Color::from_rgb(0.5, 0.5, 0.5)),
+        );
+    }
+
+    let data = if json_formatter::is_valid_json(&event.data) {
+        json_formatter::format(&event.data).unwrap_or_else(|_| event.data.clone())
+    } else {
+        event.data.clone()
+    };
+
+    container(column![header, text(data).size(13).font(iced::Font::MONOSPACE)].spacing(4))
+        .padding([6, 10])
+        .width(Length::Fill)
+        .style(styles::timing_card)
+        .into()
+}
@@ -0,0 +1,94 @@
+use crate::components::enums::{FormPart, FormPartKind, Message};
+use iced::Length::Fill;
+use iced::widget::{button, checkbox, column, container, pick_list, row, text, text_input};
+use iced::{Element, Length};
+
+/// Lista de partes do multipart/form-data: campos de texto e arquivos
+pub fn view_form_data_editor(parts: &[FormPart]) -> Element<'_, Message> {
+    let mut col = column![].spacing(8);
+
+    if !parts.is_empty() {
+        col = col.push(view_header_row());
+    }
+
+    for (index, part) in parts.iter().enumerate() {
+        col = col.push(view_part_row(index, part));
+    }
+
+    col.push(
+        button(text("+ Add Field"))
+            .on_press(Message::AddFormPart)
+            .style(button::secondary),
+    )
+    .into()
+}
+
+fn view_header_row<'a>() -> Element<'a, Message> {
+    row![
+        container(text("")).width(40),
+        container(text("Key").size(12)).width(180),
+        container(text("Type").size(12)).width(90),
+        container(text("Value").size(12)).width(Length::Fill),
+        container(text("Content-Type").size(12)).width(160),
+        container(text("Filename").size(12)).width(140),
+        container(text("")).width(80), // Espaço para botão delete
+    ]
+    .spacing(8)
+    .into()
+}
+
+fn view_part_row(index: usize, part: &FormPart) -> Element<'_, Message> {
+    let value: Element<'_, Message> = match part.kind {
+        FormPartKind::Text => text_input("value", &part.value)
+            .on_input(move |v| Message::FormPartValueChanged(index, v))
+            .width(Length::Fill)
+            .into(),
+        FormPartKind::File => row![
+            text_input("/path/to/file", &part.value)
+                .on_input(move |v| Message::FormPartValueChanged(index, v))
+                .width(Length::Fill),
+            button(text("Choose…"))
+                .on_press(Message::PickFormPartFile(index))
+                .style(button::secondary),
+        ]
+        .spacing(4)
+        .width(Length::Fill)
+        .into(),
+    };
+
+    let content_type_placeholder = match part.kind {
+        FormPartKind::Text => "auto",
+        FormPartKind::File => "application/octet-stream",
+    };
+
+    let file_name: Element<'_, Message> = match part.kind {
+        FormPartKind::Text => container(text("")).width(140).into(),
+        FormPartKind::File => text_input(&part.upload_file_name(), &part.file_name)
+            .on_input(move |v| Message::FormPartFileNameChanged(index, v))
+            .width(140)
+            .into(),
+    };
+
+    row![
+        container(checkbox(part.enabled).on_toggle(move |_| Message::FormPartToggled(index)))
+            .width(40),
+        text_input("key", &part.key)
+            .on_input(move |v| Message::FormPartKeyChanged(index, v))
+            .width(180),
+        pick_list(&FormPartKind::ALL[..], Some(part.kind), move |kind| {
+            Message::FormPartKindSelected(index, kind)
+        })
+        .width(90),
+        value,
+        text_input(content_type_placeholder, &part.content_type)
+            .on_input(move |v| Message::FormPartContentTypeChanged(index, v))
+            .width(160),
+        file_name,
+        button(text("Remove"))
+            .on_press(Message::RemoveFormPart(index))
+            .style(button::danger)
+            .height(Fill),
+    ]
+    .spacing(8)
+    .into()
+}
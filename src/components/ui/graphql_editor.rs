@@ -0,0 +1,144 @@
+use crate::components::enums::Message;
+use crate::components::graphql::GraphQlSchema;
+use crate::components::styles;
+use iced::widget::{
+    Column, button, column, container, pick_list, row, scrollable, text, text_editor, text_input,
+};
+use iced::{Element, Length};
+
+/// Editores e schema exibidos no modo GraphQL
+pub struct GraphQlEditor<'a> {
+    pub query: &'a text_editor::Content,
+    pub variables: &'a text_editor::Content,
+    /// Operações nomeadas do documento; a seleção só aparece com mais de uma
+    pub operations: Vec<String>,
+    pub operation_name: &'a str,
+    /// Schema em cache para a URL atual
+    pub schema: Option<&'a GraphQlSchema>,
+    pub schema_filter: &'a str,
+    pub fetching_schema: bool,
+}
+
+pub fn view_graphql_editor(editor: GraphQlEditor<'_>) -> Element<'_, Message> {
+    let mut toolbar = row![text("Query").size(14)]
+        .spacing(8)
+        .align_y(iced::alignment::Vertical::Center);
+
+    if editor.operations.len() > 1 {
+        let selected = editor
+            .operations
+            .iter()
+            .find(|name| *name == editor.operation_name)
+            .cloned();
+        toolbar = toolbar.push(
+            pick_list(
+                editor.operations,
+                selected,
+                Message::GraphQlOperationSelected,
+            )
+            .placeholder("Operation to run")
+            .width(220),
+        );
+    }
+
+    let fetch_label = match (editor.fetching_schema, editor.schema.is_some()) {
+        (true, _) => "Fetching schema…",
+        (false, true) => "Refresh schema",
+        (false, false) => "Fetch schema",
+    };
+    toolbar = toolbar.push(iced::widget::space::horizontal()).push(
+        button(text(fetch_label).size(13))
+            .on_press_maybe((!editor.fetching_schema).then_some(Message::FetchGraphQlSchema))
+            .style(button::secondary),
+    );
+
+    let editors = column![
+        container(
+            text_editor(editor.query)
+                .on_action(Message::GraphQlQueryAction)
+                .placeholder("query { ... }")
+                .font(iced::Font::MONOSPACE)
+                .height(220),
+        )
+        .padding(12)
+        .style(styles::body_input_border),
+        text("Variables (JSON)").size(14),
+        container(
+            text_editor(editor.variables)
+                .on_action(Message::GraphQlVariablesAction)
+                .placeholder("{\"id\": 1}")
+                .font(iced::Font::MONOSPACE)
+                .height(100),
+        )
+        .padding(12)
+        .style(styles::body_input_border),
+    ]
+    .spacing(8)
+    .width(Length::FillPortion(2));
+
+    column![
+        toolbar,
+        row![editors, view_schema(editor.schema, editor.schema_filter)].spacing(12),
+    ]
+    .spacing(8)
+    .into()
+}
+
+/// Tipos e campos do schema, filtrados por nome de tipo ou de campo
+fn view_schema<'a>(schema: Option<&'a GraphQlSchema>, filter: &'a str) -> Element<'a, Message> {
+    let Some(schema) = schema else {
+        return container(
+            text("No schema loaded. Fetch it to list the types and fields of this endpoint.")
+                .size(12)
+                .color(iced::Color::from_rgb(0.5, 0.5, 0.5)),
+        )
+        .padding(12)
+        .width(Length::FillPortion(1))
+        .into();
+    };
+
+    let filter_lower = filter.trim().to_lowercase();
+    let mut types = Column::new().spacing(10);
+    for schema_type in schema.sorted_types() {
+        let type_matches = schema_type.name.to_lowercase().contains(&filter_lower);
+        let fields: Vec<&String> = schema_type
+            .fields
+            .iter()
+            .filter(|field| type_matches || field.to_lowercase().contains(&filter_lower))
+            .collect();
+        if !type_matches && fields.is_empty() {
+            continue;
+        }
+
+        let mut entry = column![
+            text(format!(
+                "{} ({})",
+                schema_type.name,
+                schema_type.kind.to_lowercase()
+            ))
+            .size(13)
+        ]
+        .spacing(2);
+        for field in fields {
+            entry = entry.push(
+                text(format!("  {}", field))
+                    .size(12)
+                    .font(iced::Font::MONOSPACE),
+            );
+        }
+        types = types.push(entry);
+    }
+
+    column![
+        text_input("Filter types and fields", filter)
+            .on_input(Message::GraphQlSchemaFilterChanged)
+            .size(13),
+        container(scrollable(types).height(300))
+            .padding(8)
+            .width(Length::Fill)
+            .style(styles::body_input_border),
+    ]
+    .spacing(8)
+    .width(Length::FillPortion(1))
+    .into()
+}
@@ -1,27 +1,42 @@
 use crate::components::enums::{HTTPMethod, Message};
+use crate::components::environment::EnvironmentStore;
 use crate::components::pick_list::pick_list_view;
 use crate::components::styles;
-use iced::widget::{button, column, container, row, text, text_input};
+use crate::components::ui::view_environment_selector;
+use iced::widget::{button, column, combo_box, container, row, text, text_input};
 use iced::{Element, Length};
 
-pub fn view_header<'a>(method: HTTPMethod, url: &'a str, is_loading: bool) -> Element<'a, Message> {
+pub fn view_header<'a>(
+    methods: &'a combo_box::State<HTTPMethod>,
+    method: &HTTPMethod,
+    url: &'a str,
+    is_loading: bool,
+    websocket: Option<bool>,
+    environments: &'a EnvironmentStore,
+) -> Element<'a, Message> {
     container(
         column![
-            text("HTTP Client")
-                .size(24)
-                .color(iced::Color::from_rgb(0.9, 0.9, 0.9)),
             row![
-                pick_list_view(Some(method)),
+                text("HTTP Client")
+                    .size(24)
+                    .color(iced::Color::from_rgb(0.9, 0.9, 0.9)),
+                iced::widget::space::horizontal(),
+                view_environment_selector(environments),
+            ]
+            .align_y(iced::alignment::Vertical::Center),
+            row![
+                pick_list_view(methods, method),
                 text_input("https://api.example.com/endpoint", url)
                     .on_input(Message::UrlChanged)
                     .width(Length::Fill),
-                if is_loading {
-                    button(text("Sending...").size(14)).height(Length::Fill)
-                } else {
-                    button(text("Send").size(14))
-                        .on_press(Message::Submit)
-                        .height(Length::Fill)
-                }
+                match websocket {
+                    Some(connected) => view_websocket_controls(is_loading, connected),
+                    None => view_send_controls(is_loading),
+                },
+                button(text("</> Code").size(14))
+                    .on_press(Message::ToggleCodePanel)
+                    .style(button::secondary)
+                    .height(Length::Fill),
             ]
             .spacing(10)
             .align_y(iced::alignment::Vertical::Center),
@@ -32,3 +47,67 @@ pub fn view_header<'a>(method: HTTPMethod, url: &'a str, is_loading: bool) -> El
     .style(styles::header_container)
     .into()
 }
+
+fn view_send_controls(is_loading: bool) -> Element<'static, Message> {
+    if is_loading {
+        row![
+            button(text("Sending...").size(14)).height(Length::Fill),
+            button(text("Cancel").size(14))
+                .on_press(Message::CancelRequest)
+                .style(button::danger)
+                .height(Length::Fill),
+        ]
+        .spacing(10)
+        .into()
+    } else {
+        button(text("Send").size(14))
+            .on_press(Message::Submit)
+            .height(Length::Fill)
+            .into()
+    }
+}
+
+/// Conectar/desconectar quando a URL é `ws://` ou `wss://`
+fn view_websocket_controls(is_loading: bool, connected: bool) -> Element<'static, Message> {
+    if is_loading {
+        row![
+            button(text("Connecting...").size(14)).height(Length::Fill),
+            button(text("Cancel").size(14))
+                .on_press(Message::CancelRequest)
+                .style(button::danger)
+                .height(Length::Fill),
+        ]
+        .spacing(10)
+        .into()
+    } else if connected {
+        button(text("Disconnect").size(14))
+            .on_press(Message::CloseWebSocket)
+            .style(button::danger)
+            .height(Length::Fill)
+            .into()
+    } else {
+        button(text("Connect").size(14))
+            .on_press(Message::Submit)
+            .height(Length::Fill)
+            .into()
+    }
+}
+
+/// Oferece importar o comando curl colado no campo de URL
+pub fn view_curl_import_offer() -> Element<'static, Message> {
+    container(
+        row![
+            text("📋 This looks like a curl command.").size(14),
+            iced::widget::space::horizontal(),
+            button(text("Import").size(13)).on_press(Message::ImportCurl),
+            button(text("Dismiss").size(13))
+                .on_press(Message::DismissCurlImport)
+                .style(button::secondary),
+        ]
+        .spacing(10)
+        .align_y(iced::alignment::Vertical::Center),
+    )
+    .padding([10, 16])
+    .style(styles::notice_card)
+    .into()
+}
@@ -1,17 +1,19 @@
-use crate::components::enums::{HistoryItem, Message};
+use crate::components::auth;
+use crate::components::enums::{HTTPMethod, HistoryItem, Message};
 use crate::components::history::RequestHistory;
 use crate::components::styles;
-use iced::widget::{button, column, container, row, text};
+use iced::widget::{button, checkbox, column, container, row, text};
 use iced::{Element, Length};
 
 pub fn view_history(history: &RequestHistory) -> Element<'_, Message> {
-    let mut history_column = column![view_history_header(),].spacing(10);
+    let mut history_column = column![view_history_header(history)].spacing(10);
 
     if history.is_empty() {
         history_column = history_column.push(view_empty_history());
     } else {
         for (index, item) in history.get_items().iter().enumerate() {
-            history_column = history_column.push(view_history_item(index, item));
+            history_column =
+                history_column.push(view_history_item(index, item, history.is_selected(index)));
         }
     }
 
@@ -23,21 +25,51 @@ pub fn view_history(history: &RequestHistory) -> Element<'_, Message> {
         .into()
 }
 
-fn view_history_header() -> Element<'static, Message> {
+fn view_history_header(history: &RequestHistory) -> Element<'static, Message> {
+    let has_items = !history.is_empty();
+    let export_scope = match history.selected_count() {
+        0 => "Exports include the whole history".to_string(),
+        count => format!("Exports include {} selected", count),
+    };
+
     column![
         text("📜 History")
             .size(18)
             .color(iced::Color::from_rgb(0.9, 0.9, 0.9)),
-        button(text("Clear").size(12))
-            .on_press(Message::ClearHistory)
-            .padding([6, 12])
-            .width(Length::Fill)
-            .style(button::danger)
+        row![
+            header_button("Import HAR", Some(Message::ImportHar)),
+            header_button("Export HAR", has_items.then_some(Message::ExportHistoryHar)),
+        ]
+        .spacing(6),
+        row![
+            header_button(
+                "Export Postman",
+                has_items.then_some(Message::ExportHistoryPostman)
+            ),
+            button(text("Clear").size(12))
+                .on_press(Message::ClearHistory)
+                .padding([6, 12])
+                .width(Length::Fill)
+                .style(button::danger),
+        ]
+        .spacing(6),
+        text(export_scope)
+            .size(11)
+            .color(iced::Color::from_rgb(0.55, 0.55, 0.55)),
     ]
     .spacing(10)
     .into()
 }
 
+fn header_button(label: &'static str, on_press: Option<Message>) -> Element<'static, Message> {
+    button(text(label).size(12))
+        .on_press_maybe(on_press)
+        .padding([6, 12])
+        .width(Length::Fill)
+        .style(button::secondary)
+        .into()
+}
+
 fn view_empty_history() -> Element<'static, Message> {
     container(
         text("No requests yet")
@@ -49,54 +81,91 @@ fn view_empty_history() -> Element<'static, Message> {
     .into()
 }
 
-fn view_history_item(index: usize, item: &HistoryItem) -> Element<'_, Message> {
+fn view_history_item(index: usize, item: &HistoryItem, selected: bool) -> Element<'_, Message> {
     let formatted_time = RequestHistory::format_timestamp(item.timestamp);
-    let status_color = get_status_color(item.response.status);
-    let duration_ms = item.response.duration_ms;
+    let details = match (&item.websocket, &item.response) {
+        (Some(messages), _) => format!("{} • {} messages", formatted_time, messages.len()),
+        (None, Some(response)) => format!("{} • {}ms", formatted_time, response.duration_ms),
+        (None, None) => format!("{} • cancelled", formatted_time),
+    };
 
     container(
-        button(
-            column![
-                view_history_item_main(item, status_color),
-                text(format!("{} • {}ms", formatted_time, duration_ms))
-                    .size(11)
-                    .color(iced::Color::from_rgb(0.55, 0.55, 0.55)),
-            ]
-            .spacing(6),
-        )
-        .on_press(Message::LoadFromHistory(index))
-        .padding(10)
-        .width(Length::Fill)
-        .style(button::secondary),
+        row![
+            checkbox(selected).on_toggle(move |_| Message::HistorySelectionToggled(index)),
+            button(
+                column![
+                    view_history_item_main(item),
+                    text(details)
+                        .size(11)
+                        .color(iced::Color::from_rgb(0.55, 0.55, 0.55)),
+                ]
+                .spacing(6),
+            )
+            .on_press(Message::LoadFromHistory(index))
+            .padding(10)
+            .width(Length::Fill)
+            .style(button::secondary),
+        ]
+        .spacing(6)
+        .align_y(iced::alignment::Vertical::Center),
     )
     .style(styles::history_item)
     .into()
 }
 
-fn view_history_item_main(item: &HistoryItem, status_color: iced::Color) -> Element<'_, Message> {
-    column![
+fn view_history_item_main(item: &HistoryItem) -> Element<'_, Message> {
+    let status_badge = match &item.response {
+        Some(response) => view_status_badge(
+            format!("{}", response.status),
+            get_status_color(response.status),
+        ),
+        None => view_status_badge(
+            "Cancelled".to_string(),
+            iced::Color::from_rgb(0.5, 0.5, 0.5),
+        ),
+    };
+
+    let mut content = column![
         row![
-            view_method_badge(&item.request.method),
-            view_status_badge(item.response.status, status_color),
+            if item.websocket.is_some() {
+                view_method_label("WS")
+            } else {
+                view_method_badge(&item.request.method)
+            },
+            status_badge,
         ]
         .spacing(8),
         text(&item.request.url)
             .size(12)
             .color(iced::Color::from_rgb(0.8, 0.8, 0.8)),
     ]
-    .spacing(6)
-    .into()
+    .spacing(6);
+
+    // Credenciais nunca aparecem em claro na lista
+    if let Some(summary) = auth::masked_summary(&item.request.auth) {
+        content = content.push(
+            text(format!("🔒 {}", summary))
+                .size(11)
+                .color(iced::Color::from_rgb(0.6, 0.6, 0.6)),
+        );
+    }
+
+    content.into()
+}
+
+fn view_method_badge(method: &HTTPMethod) -> Element<'_, Message> {
+    view_method_label(method.to_string())
 }
 
-fn view_method_badge(method: &crate::components::enums::HTTPMethod) -> Element<'_, Message> {
-    container(text(format!("{}", method)).size(12))
+fn view_method_label<'a>(label: impl text::IntoFragment<'a>) -> Element<'a, Message> {
+    container(text(label).size(12))
         .padding([4, 8])
         .style(styles::method_badge)
         .into()
 }
 
-fn view_status_badge(status: u16, color: iced::Color) -> Element<'static, Message> {
-    container(text(format!("{}", status)).size(12).color(color))
+fn view_status_badge(label: String, color: iced::Color) -> Element<'static, Message> {
+    container(text(label).size(12).color(color))
         .padding([4, 8])
         .style(styles::status_badge_border(color))
         .into()
@@ -0,0 +1,111 @@
+use crate::components::enums::Message;
+use crate::components::formats::http_file::{HttpFileEntry, OpenedHttpFile};
+use crate::components::styles;
+use iced::widget::{button, column, container, row, text};
+use iced::{Element, Length};
+
+pub fn view_http_file(
+    opened: Option<&OpenedHttpFile>,
+    selection: Option<usize>,
+) -> Element<'static, Message> {
+    let mut content = column![view_http_file_header(opened, selection)].spacing(6);
+
+    match opened {
+        Some(opened) => {
+            let entries = opened.file.entries();
+            if entries.is_empty() {
+                content = content.push(view_empty_message("No requests in this file"));
+            }
+            for (index, entry) in entries.into_iter().enumerate() {
+                content = content.push(view_entry(index, entry, selection == Some(index)));
+            }
+        }
+        None => {
+            content = content.push(view_empty_message(
+                "Open a .http or .rest file to run its requests",
+            ))
+        }
+    }
+
+    container(content)
+        .padding(16)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .style(styles::history_container)
+        .into()
+}
+
+fn view_http_file_header(
+    opened: Option<&OpenedHttpFile>,
+    selection: Option<usize>,
+) -> Element<'static, Message> {
+    let is_open = opened.is_some();
+    let title = opened.map_or_else(|| ".http file".to_string(), OpenedHttpFile::file_name);
+
+    column![
+        text(format!("📄 {}", title))
+            .size(18)
+            .color(iced::Color::from_rgb(0.9, 0.9, 0.9)),
+        row![
+            action_button("Open", Some(Message::OpenHttpFile)),
+            action_button("Save", selection.is_some().then_some(Message::SaveHttpFile)),
+            action_button("+ Add", is_open.then_some(Message::AddToHttpFile)),
+            action_button("Close", is_open.then_some(Message::CloseHttpFile)),
+        ]
+        .spacing(6),
+    ]
+    .spacing(8)
+    .into()
+}
+
+fn action_button(label: &'static str, on_press: Option<Message>) -> Element<'static, Message> {
+    button(text(label).size(12))
+        .on_press_maybe(on_press)
+        .padding([6, 8])
+        .width(Length::Fill)
+        .style(button::secondary)
+        .into()
+}
+
+fn view_empty_message(message: &'static str) -> Element<'static, Message> {
+    container(
+        text(message)
+            .size(13)
+            .color(iced::Color::from_rgb(0.5, 0.5, 0.5)),
+    )
+    .padding(12)
+    .center(Length::Fill)
+    .into()
+}
+
+fn view_entry(index: usize, entry: HttpFileEntry, is_selected: bool) -> Element<'static, Message> {
+    let label = row![
+        container(text(entry.request.method.to_string()).size(11))
+            .padding([2, 6])
+            .style(styles::method_badge),
+        text(entry.name).size(13),
+    ]
+    .spacing(6)
+    .align_y(iced::alignment::Vertical::Center);
+
+    let entry_button = button(label)
+        .on_press(Message::SelectHttpFileEntry(index))
+        .padding([6, 8])
+        .width(Length::Fill);
+
+    let entry_button = if is_selected {
+        entry_button.style(button::primary)
+    } else {
+        entry_button.style(button::secondary)
+    };
+
+    row![
+        entry_button,
+        button(text("▶").size(12))
+            .on_press(Message::RunHttpFileEntry(index))
+            .padding([6, 10])
+            .style(button::success),
+    ]
+    .spacing(6)
+    .into()
+}
@@ -1,7 +1,7 @@
 use crate::components::enums::{KeyValue, Message};
 use iced::Length::Fill;
 use iced::widget::{button, checkbox, column, container, row, text, text_input};
-use iced::{Element, Length};
+use iced::{Element, Length, Padding};
 
 #[allow(clippy::too_many_arguments)]
 pub fn view_key_value_list<'a>(
@@ -81,3 +81,27 @@ fn view_key_value_row<'a>(
     .spacing(8)
     .into()
 }
+
+/// Header gerado automaticamente: esmaecido e sem edição; "Override" cria uma linha editável
+pub fn view_implicit_header<'a>(
+    key: &'a str,
+    value: &str,
+    on_override: Message,
+) -> Element<'a, Message> {
+    let muted = iced::Color::from_rgb(0.5, 0.5, 0.5);
+
+    container(
+        row![
+            container(checkbox(true)).width(40),
+            container(text(key).color(muted)).width(250),
+            container(text(format!("{} (auto)", value)).color(muted)).width(Length::Fill),
+            button(text("Override"))
+                .on_press(on_override)
+                .style(button::secondary),
+        ]
+        .spacing(8)
+        .align_y(iced::Alignment::Center),
+    )
+    .padding(Padding::new(16.0).bottom(0))
+    .into()
+}
@@ -1,17 +1,37 @@
+pub mod auth_editor;
 pub mod body_editor;
+pub mod code_view;
+pub mod collections_view;
+pub mod cookie_jar_view;
+pub mod environment_view;
 pub mod error_message;
+pub mod event_stream_view;
+pub mod form_data_editor;
+pub mod graphql_editor;
 pub mod header;
 pub mod history_view;
+pub mod http_file_view;
 pub mod key_value_list;
 pub mod request_tabs;
 pub mod response_view;
 pub mod timeout_config;
+pub mod websocket_view;
 
+pub use auth_editor::{OAuth2Status, view_auth_editor};
 pub use body_editor::view_body_editor;
-pub use error_message::{view_empty_error, view_error_message};
-pub use header::view_header;
+pub use code_view::view_code_panel;
+pub use collections_view::view_collections;
+pub use cookie_jar_view::view_cookie_jar;
+pub use environment_view::{
+    view_environment_editor, view_environment_selector, view_unresolved_variables,
+};
+pub use error_message::{view_empty_error, view_error_message, view_notice_message};
+pub use graphql_editor::GraphQlEditor;
+pub use header::{view_curl_import_offer, view_header};
 pub use history_view::view_history;
-pub use key_value_list::view_key_value_list;
-pub use request_tabs::view_request_tabs;
-pub use response_view::{view_no_response, view_response};
+pub use http_file_view::view_http_file;
+pub use key_value_list::{view_implicit_header, view_key_value_list};
+pub use request_tabs::{view_request_tabs, view_sidebar_tabs};
+pub use response_view::{view_download_progress, view_no_response, view_response};
 pub use timeout_config::view_timeout_config;
+pub use websocket_view::{view_websocket_composer, view_websocket_session};
@@ -1,14 +1,20 @@
-use crate::components::enums::{Message, RequestTab};
+use crate::components::enums::{Message, RequestTab, SidebarTab};
 use iced::Element;
 use iced::Length::Fill;
 use iced::widget::{Button, button, container, row, text};
 
-pub fn view_request_tabs(active_tab: RequestTab) -> Element<'static, Message> {
+/// Em modo WebSocket a aba Body vira o compositor de mensagens
+pub fn view_request_tabs(active_tab: RequestTab, websocket: bool) -> Element<'static, Message> {
     container(
         row![
             tab_button("Query Params", RequestTab::QueryParams, active_tab),
             tab_button("Headers", RequestTab::Headers, active_tab),
-            tab_button("Body", RequestTab::Body, active_tab),
+            tab_button("Auth", RequestTab::Auth, active_tab),
+            tab_button(
+                if websocket { "Message" } else { "Body" },
+                RequestTab::Body,
+                active_tab
+            ),
         ]
         .spacing(8),
     )
@@ -32,3 +38,30 @@ fn tab_button(
         btn.style(button::secondary)
     }
 }
+
+pub fn view_sidebar_tabs(active_tab: SidebarTab) -> Element<'static, Message> {
+    let sidebar_button = |label: &'static str, tab: SidebarTab| {
+        let btn = button(text(label).size(13))
+            .on_press(Message::SidebarTabChanged(tab))
+            .padding([6, 10])
+            .width(Fill);
+
+        if active_tab == tab {
+            btn.style(button::primary)
+        } else {
+            btn.style(button::secondary)
+        }
+    };
+
+    container(
+        row![
+            sidebar_button("History", SidebarTab::History),
+            sidebar_button("Collections", SidebarTab::Collections),
+            sidebar_button(".http", SidebarTab::HttpFile),
+            sidebar_button("Cookies", SidebarTab::Cookies),
+        ]
+        .spacing(6),
+    )
+    .padding([10, 16])
+    .into()
+}
@@ -1,23 +1,66 @@
-use crate::components::enums::{HttpResponse, Message, ResponseTab};
+use super::cookie_jar_view::cookie_details;
+use super::event_stream_view::view_event_stream;
+use crate::components::cookies;
+use crate::components::enums::{DownloadProgress, HttpResponse, Message, ResponseTab};
+use crate::components::http_client::PREVIEW_BYTES;
+use crate::components::sse::SseSession;
 use crate::components::styles;
 use crate::components::utils::{json_formatter, text_formatter};
-use iced::Element;
-use iced::widget::{Button, button, column, container, row, scrollable, text};
+use iced::widget::{
+    Button, Column, button, column, container, image, progress_bar, row, scrollable, text,
+};
+use iced::{Element, Length};
 
+/// Bytes exibidos no dump hexadecimal de bodies binários
+const HEX_DUMP_LIMIT: usize = 16 * 1024;
+
+const GRAPHQL_ERROR_COLOR: iced::Color = iced::Color::from_rgb(0.85, 0.45, 0.0);
+
+/// `image` é o handle da imagem já decodificável, criado uma vez por resposta.
+///
+/// `graphql_errors` são os `errors` de uma resposta GraphQL: mesmo com status 2xx
+/// a resposta não é exibida como sucesso.
+///
+/// `sse` é a sessão de eventos quando a resposta é um `text/event-stream`.
 pub fn view_response<'a>(
     response: &'a HttpResponse,
     response_tab: ResponseTab,
+    image: Option<&'a image::Handle>,
+    graphql_errors: Vec<String>,
+    sse: Option<&'a SseSession>,
 ) -> Element<'a, Message> {
-    let (status_color, status_icon) = get_status_info(response.status);
+    let (status_color, status_icon) = if graphql_errors.is_empty() {
+        get_status_info(response.status)
+    } else {
+        (GRAPHQL_ERROR_COLOR, "⚠")
+    };
+
+    let mut content = column![
+        text("Response").size(20),
+        view_response_info(response, status_color, status_icon),
+    ]
+    .spacing(16);
+
+    if !graphql_errors.is_empty() {
+        content = content.push(view_graphql_errors(graphql_errors));
+    }
+
+    if let Some(full_size) = response.full_size {
+        content = content.push(view_truncated_notice(response, full_size));
+    }
 
     container(
-        column![
-            text("Response").size(20),
-            view_response_info(response, status_color, status_icon),
-            view_response_tabs(response_tab),
-            view_response_content(response, response_tab),
-        ]
-        .spacing(16),
+        content
+            .push(view_response_tabs(
+                response_tab,
+                sse.map(|session| session.events.len()),
+                response.redirects.len(),
+                response.cookies.len(),
+            ))
+            .push(match (response_tab, sse) {
+                (ResponseTab::Events, Some(session)) => view_event_stream(session),
+                _ => view_response_content(response, response_tab, image),
+            }),
     )
     .padding(20)
     .style(styles::response_container)
@@ -38,6 +81,67 @@ pub fn view_no_response() -> Element<'static, Message> {
     .into()
 }
 
+/// Bytes recebidos até agora e o início do body, antes do fim do download
+pub fn view_download_progress(download: &DownloadProgress) -> Element<'_, Message> {
+    let received = text_formatter::format_bytes(download.received as usize);
+    let mut content = column![text("Response").size(20)].spacing(16);
+
+    content = match download.total {
+        Some(total) => content.push(
+            column![
+                text(format!(
+                    "⬇ Receiving body: {} of {}",
+                    received,
+                    text_formatter::format_bytes(total as usize)
+                ))
+                .size(14),
+                progress_bar(0.0..=total.max(1) as f32, download.received as f32).girth(8),
+            ]
+            .spacing(8),
+        ),
+        None => content.push(text(format!("⬇ Receiving body: {}", received)).size(14)),
+    };
+
+    if let Some(preview) = &download.preview {
+        content = content.push(
+            text(format!(
+                "Preview (first {})",
+                text_formatter::format_bytes(PREVIEW_BYTES)
+            ))
+            .size(12)
+            .color(iced::Color::from_rgb(0.5, 0.5, 0.5)),
+        );
+        content = content.push(
+            container(scrollable(text(preview).font(iced::Font::MONOSPACE)).height(300))
+                .padding(10),
+        );
+    }
+
+    container(content)
+        .padding(20)
+        .style(styles::response_container)
+        .into()
+}
+
+/// Body maior que o limite de memória: só o início é exibido
+fn view_truncated_notice(response: &HttpResponse, full_size: u64) -> Element<'_, Message> {
+    let shown = format!(
+        "Body truncated: showing the first {} of {}.",
+        text_formatter::format_bytes(response.raw_body().len()),
+        text_formatter::format_bytes(full_size as usize)
+    );
+    let hint = if response.spill_file.is_some() {
+        "Use Save full response to keep the whole body."
+    } else {
+        "The rest of the body was not kept."
+    };
+
+    text(format!("{} {}", shown, hint))
+        .size(13)
+        .color(iced::Color::from_rgb(0.7, 0.5, 0.0))
+        .into()
+}
+
 fn get_status_info(status: u16) -> (iced::Color, &'static str) {
     if (200..300).contains(&status) {
         (iced::Color::from_rgb(0.0, 0.7, 0.0), "✓")
@@ -48,6 +152,26 @@ fn get_status_info(status: u16) -> (iced::Color, &'static str) {
     }
 }
 
+/// Lista dos erros GraphQL retornados junto com `data`
+fn view_graphql_errors<'a>(errors: Vec<String>) -> Element<'a, Message> {
+    let mut list = column![
+        text(format!("GraphQL errors ({})", errors.len()))
+            .size(14)
+            .color(GRAPHQL_ERROR_COLOR),
+    ]
+    .spacing(4);
+
+    for error in errors {
+        list = list.push(text(format!("• {}", error)).size(13));
+    }
+
+    container(list)
+        .padding([8, 12])
+        .width(Length::Fill)
+        .style(styles::status_badge(GRAPHQL_ERROR_COLOR))
+        .into()
+}
+
 fn view_response_info<'a>(
     response: &'a HttpResponse,
     status_color: iced::Color,
@@ -56,6 +180,17 @@ fn view_response_info<'a>(
     row![
         view_status_badge(response, status_color, status_icon),
         view_timing_badge(response),
+        container(text("")).width(Length::Fill),
+        button(
+            text(if response.spill_file.is_some() {
+                "Save full response"
+            } else {
+                "Save to file"
+            })
+            .size(14)
+        )
+        .on_press(Message::SaveResponse)
+        .style(button::secondary),
     ]
     .spacing(12)
     .into()
@@ -96,17 +231,49 @@ fn view_timing_badge(response: &HttpResponse) -> Element<'_, Message> {
     .into()
 }
 
-fn view_response_tabs(response_tab: ResponseTab) -> Element<'static, Message> {
-    row![
-        response_tab_button("Body", ResponseTab::Body, response_tab),
-        response_tab_button("Headers", ResponseTab::Headers, response_tab),
-    ]
-    .spacing(8)
-    .into()
+/// A aba de eventos só aparece para respostas `text/event-stream`; as de
+/// redirects e cookies quando algum foi seguido ou recebido
+fn view_response_tabs(
+    response_tab: ResponseTab,
+    event_count: Option<usize>,
+    redirect_count: usize,
+    cookie_count: usize,
+) -> Element<'static, Message> {
+    let mut tabs = row![].spacing(8);
+    if let Some(count) = event_count {
+        tabs = tabs.push(response_tab_button(
+            format!("Events ({})", count),
+            ResponseTab::Events,
+            response_tab,
+        ));
+    }
+
+    tabs = tabs
+        .push(response_tab_button("Body", ResponseTab::Body, response_tab))
+        .push(response_tab_button(
+            "Headers",
+            ResponseTab::Headers,
+            response_tab,
+        ));
+    if redirect_count > 0 {
+        tabs = tabs.push(response_tab_button(
+            format!("Redirects ({})", redirect_count),
+            ResponseTab::Redirects,
+            response_tab,
+        ));
+    }
+    if cookie_count > 0 {
+        tabs = tabs.push(response_tab_button(
+            format!("Cookies ({})", cookie_count),
+            ResponseTab::Cookies,
+            response_tab,
+        ));
+    }
+    tabs.into()
 }
 
 fn response_tab_button(
-    label: &'static str,
+    label: impl text::IntoFragment<'static>,
     tab: ResponseTab,
     active_tab: ResponseTab,
 ) -> Button<'static, Message> {
@@ -124,11 +291,155 @@ fn response_tab_button(
 fn view_response_content<'a>(
     response: &'a HttpResponse,
     response_tab: ResponseTab,
+    image: Option<&'a image::Handle>,
 ) -> Element<'a, Message> {
     match response_tab {
-        ResponseTab::Body => view_response_body(&response.body),
+        ResponseTab::Body => match (&response.body, image) {
+            (Some(body), _) => view_response_body(body),
+            (None, Some(handle)) => view_response_image(response, handle),
+            (None, None) => view_response_hex(response),
+        },
         ResponseTab::Headers => view_response_headers(&response.headers),
+        ResponseTab::Events => view_response_body(response.text()),
+        ResponseTab::Redirects => view_redirect_chain(response),
+        ResponseTab::Cookies => view_received_cookies(response),
+    }
+}
+
+/// `Set-Cookie` recebidos e o que aconteceu com cada um no jar
+fn view_received_cookies(response: &HttpResponse) -> Element<'_, Message> {
+    let now = cookies::now();
+    let mut list = Column::new().spacing(8);
+
+    for received in &response.cookies {
+        let (status, color, details) = match (&received.cookie, &received.error) {
+            (Some(cookie), _) if cookie.is_expired(now) => (
+                "Deleted".to_string(),
+                iced::Color::from_rgb(0.5, 0.5, 0.5),
+                format!("{} · {}", cookie.domain, cookie_details(cookie, now)),
+            ),
+            (Some(cookie), _) => (
+                "Stored".to_string(),
+                iced::Color::from_rgb(0.0, 0.7, 0.0),
+                format!("{} · {}", cookie.domain, cookie_details(cookie, now)),
+            ),
+            (None, error) => (
+                format!("Rejected: {}", error.as_deref().unwrap_or_default()),
+                iced::Color::from_rgb(0.85, 0.3, 0.3),
+                String::new(),
+            ),
+        };
+
+        let mut card = column![
+            text(&received.header).size(13).font(iced::Font::MONOSPACE),
+            text(status).size(12).color(color),
+        ]
+        .spacing(4);
+        if !details.is_empty() {
+            card = card.push(
+                text(details)
+                    .size(12)
+                    .color(iced::Color::from_rgb(0.5, 0.5, 0.5)),
+            );
+        }
+
+        list = list.push(
+            container(card)
+                .padding([6, 10])
+                .width(Length::Fill)
+                .style(styles::timing_card),
+        );
+    }
+
+    container(scrollable(list).height(300)).padding(10).into()
+}
+
+/// Linha do tempo: cada salto com método, URL, status, `Location`, headers e tempo,
+/// terminando na resposta final
+fn view_redirect_chain(response: &HttpResponse) -> Element<'_, Message> {
+    let mut timeline = Column::new().spacing(8);
+
+    for (index, hop) in response.redirects.iter().enumerate() {
+        let (color, _) = get_status_info(hop.status);
+        let headers = hop
+            .headers
+            .iter()
+            .map(|(key, value)| format!("{}: {}", key, value))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        timeline = timeline.push(
+            container(
+                column![
+                    row![
+                        text(format!("{}.", index + 1)).size(13),
+                        text(format!("{} {}", hop.method, hop.url)).size(13),
+                    ]
+                    .spacing(8),
+                    row![
+                        text(format!("{} {}", hop.status, hop.status_text))
+                            .size(13)
+                            .color(color),
+                        text(format!("{} ms", hop.duration_ms))
+                            .size(12)
+                            .color(iced::Color::from_rgb(0.5, 0.5, 0.5)),
+                    ]
+                    .spacing(12),
+                    text(format!("→ {}", hop.location)).size(13),
+                    text(headers).size(12).font(iced::Font::MONOSPACE),
+                ]
+                .spacing(4),
+            )
+            .padding([6, 10])
+            .width(Length::Fill)
+            .style(styles::timing_card),
+        );
     }
+
+    let (color, _) = get_status_info(response.status);
+    let mut last = column![
+        row![
+            text(format!("{}.", response.redirects.len() + 1)).size(13),
+            text(
+                response
+                    .redirects
+                    .last()
+                    .map(|hop| hop.location.as_str())
+                    .unwrap_or_default()
+            )
+            .size(13),
+        ]
+        .spacing(8),
+        text(format!("{} {}", response.status, response.status_text))
+            .size(13)
+            .color(color),
+    ]
+    .spacing(4);
+
+    // Redirect não seguido: desligado ou limite de saltos atingido
+    if (300..400).contains(&response.status)
+        && let Some((_, location)) = response
+            .headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case("location"))
+    {
+        last = last.push(
+            text(format!("Not followed: {}", location))
+                .size(12)
+                .color(iced::Color::from_rgb(0.85, 0.45, 0.0)),
+        );
+    }
+
+    timeline = timeline.push(
+        container(last)
+            .padding([6, 10])
+            .width(Length::Fill)
+            .style(styles::timing_card),
+    );
+
+    container(scrollable(timeline).height(300))
+        .padding(10)
+        .into()
 }
 
 fn view_response_body(body: &str) -> Element<'_, Message> {
@@ -143,6 +454,56 @@ fn view_response_body(body: &str) -> Element<'_, Message> {
         .into()
 }
 
+fn view_response_image<'a>(
+    response: &'a HttpResponse,
+    handle: &'a image::Handle,
+) -> Element<'a, Message> {
+    column![
+        view_binary_caption(response),
+        container(scrollable(image(handle.clone())).height(300)).padding(10),
+    ]
+    .spacing(8)
+    .into()
+}
+
+/// Conteúdo binário sem visualizador próprio: dump hexadecimal do início do body
+fn view_response_hex(response: &HttpResponse) -> Element<'_, Message> {
+    let bytes = response.raw_body();
+    if bytes.is_empty() {
+        return container(
+            text("Binary body is not kept in history")
+                .size(14)
+                .color(iced::Color::from_rgb(0.5, 0.5, 0.5)),
+        )
+        .padding(10)
+        .into();
+    }
+
+    column![
+        view_binary_caption(response),
+        container(
+            scrollable(
+                text(text_formatter::hex_dump(bytes, HEX_DUMP_LIMIT)).font(iced::Font::MONOSPACE)
+            )
+            .height(300)
+        )
+        .padding(10),
+    ]
+    .spacing(8)
+    .into()
+}
+
+fn view_binary_caption(response: &HttpResponse) -> Element<'_, Message> {
+    text(format!(
+        "{} · {}",
+        response.content_type().unwrap_or("Binary data"),
+        text_formatter::format_bytes(response.raw_body().len())
+    ))
+    .size(12)
+    .color(iced::Color::from_rgb(0.5, 0.5, 0.5))
+    .into()
+}
+
 fn view_response_headers(
     headers: &std::collections::HashMap<String, String>,
 ) -> Element<'_, Message> {
@@ -1,20 +1,62 @@
-use crate::components::enums::Message;
+use crate::components::enums::{Message, RedirectPolicy};
 use crate::components::styles;
 use iced::Element;
 use iced::Length::Fill;
-use iced::widget::{container, row, text, text_input};
+use iced::widget::{checkbox, column, container, row, text, text_input};
 
-pub fn view_timeout_config(timeout_ms: &str) -> Element<'_, Message> {
+pub fn view_timeout_config<'a>(
+    timeout_ms: &'a str,
+    response_limit_mb: &'a str,
+    redirects: RedirectPolicy,
+    max_redirects: &'a str,
+    send_cookies: bool,
+) -> Element<'a, Message> {
     container(
-        row![
-            text("⏱ Timeout (ms):").size(14),
-            text_input("30000", timeout_ms)
-                .on_input(Message::TimeoutChanged)
-                .width(Fill),
+        column![
+            row![
+                text("⏱ Timeout (ms):").size(14),
+                text_input("30000", timeout_ms)
+                    .on_input(Message::TimeoutChanged)
+                    .width(Fill),
+                text("Max in memory (MB):").size(14),
+                text_input("10", response_limit_mb)
+                    .on_input(Message::ResponseLimitChanged)
+                    .width(80),
+                checkbox(send_cookies)
+                    .label("Send cookies")
+                    .on_toggle(Message::SendCookiesToggled),
+            ]
+            .spacing(12)
+            .align_y(iced::alignment::Vertical::Center),
+            view_redirect_config(redirects, max_redirects),
         ]
-        .spacing(12),
+        .spacing(10),
     )
     .padding(16)
     .style(styles::config_card)
     .into()
 }
+
+/// Sem seguir redirects, as demais opções ficam desabilitadas
+fn view_redirect_config(redirects: RedirectPolicy, max_redirects: &str) -> Element<'_, Message> {
+    let follow = redirects.follow;
+
+    row![
+        checkbox(follow)
+            .label("Follow redirects")
+            .on_toggle(Message::FollowRedirectsToggled),
+        text("Max:").size(14),
+        text_input("10", max_redirects)
+            .on_input_maybe(follow.then_some(Message::MaxRedirectsChanged))
+            .width(60),
+        checkbox(redirects.keep_method)
+            .label("Keep method on 307/308")
+            .on_toggle_maybe(follow.then_some(Message::KeepRedirectMethodToggled)),
+        checkbox(redirects.strip_auth_cross_origin)
+            .label("Strip auth on cross-origin")
+            .on_toggle_maybe(follow.then_some(Message::StripRedirectAuthToggled)),
+    ]
+    .spacing(12)
+    .align_y(iced::alignment::Vertical::Center)
+    .into()
+}
@@ -0,0 +1,174 @@
+use crate::components::enums::Message;
+use crate::components::styles;
+use crate::components::utils::{json_formatter, text_formatter};
+use crate::components::websocket::{
+    WsDirection, WsFormat, WsKind, WsMessage, WsSession, close_code_name,
+};
+use iced::widget::{Column, button, column, container, row, scrollable, text, text_editor};
+use iced::{Element, Length};
+
+/// Bytes exibidos no dump hexadecimal de mensagens binárias
+const HEX_DUMP_LIMIT: usize = 4 * 1024;
+
+/// Compositor de mensagens: formato, texto, Send e Ping
+pub fn view_websocket_composer<'a>(
+    format: WsFormat,
+    content: &'a text_editor::Content,
+    error: Option<&'a str>,
+    connected: bool,
+) -> Element<'a, Message> {
+    let mut formats = row![text("Format:").size(14)]
+        .spacing(8)
+        .align_y(iced::alignment::Vertical::Center);
+    for option in WsFormat::ALL {
+        let btn = button(text(option.to_string()).size(13))
+            .on_press(Message::WsFormatSelected(option))
+            .padding([8, 14]);
+        formats = formats.push(if option == format {
+            btn.style(button::primary)
+        } else {
+            btn.style(button::secondary)
+        });
+    }
+
+    let placeholder = match format {
+        WsFormat::Text => "Enter message...",
+        WsFormat::Json => "Enter JSON: {\"key\": \"value\"}",
+        WsFormat::Binary => "Enter bytes in hex: 01 ff 7a",
+    };
+
+    let mut content = column![
+        formats,
+        container(
+            text_editor(content)
+                .on_action(Message::WsMessageAction)
+                .placeholder(placeholder)
+                .height(160),
+        )
+        .padding(12)
+        .style(styles::body_input_border),
+    ]
+    .spacing(12)
+    .padding(16);
+
+    if let Some(error) = error {
+        content = content.push(text(error).size(12).style(text::danger));
+    }
+
+    let actions = if connected {
+        row![
+            button(text("Send").size(13))
+                .on_press(Message::SendWs)
+                .padding([8, 14]),
+            button(text("Ping").size(13))
+                .on_press(Message::SendWsPing)
+                .padding([8, 14])
+                .style(button::secondary),
+        ]
+    } else {
+        row![
+            text("Connect to send messages")
+                .size(12)
+                .color(iced::Color::from_rgb(0.5, 0.5, 0.5))
+        ]
+    };
+
+    content.push(actions.spacing(8)).into()
+}
+
+/// Log da sessão: mensagens enviadas e recebidas, da mais antiga para a mais recente
+pub fn view_websocket_session(session: &WsSession) -> Element<'_, Message> {
+    let mut messages = Column::new().spacing(8);
+    for message in &session.messages {
+        messages = messages.push(view_message(message));
+    }
+    if session.messages.is_empty() {
+        messages = messages.push(
+            text("No messages yet")
+                .size(13)
+                .color(iced::Color::from_rgb(0.5, 0.5, 0.5)),
+        );
+    }
+
+    container(
+        column![
+            view_status(session),
+            scrollable(messages).anchor_bottom().height(400),
+        ]
+        .spacing(12),
+    )
+    .padding(20)
+    .width(Length::Fill)
+    .style(styles::response_container)
+    .into()
+}
+
+fn view_status(session: &WsSession) -> Element<'_, Message> {
+    let status = match (&session.error, session.is_connected()) {
+        (Some(error), _) => text(format!("✗ {}", error)).style(text::danger),
+        (None, true) => text("● Connected").color(iced::Color::from_rgb(0.0, 0.7, 0.0)),
+        (None, false) if session.running => text("… Connecting"),
+        (None, false) => text("○ Closed").color(iced::Color::from_rgb(0.5, 0.5, 0.5)),
+    };
+
+    let mut details = vec![format!("{} messages", session.messages.len())];
+    if let Some(handshake) = &session.handshake {
+        details.push(format!(
+            "handshake: {} in {} ms",
+            handshake.status, handshake.duration_ms
+        ));
+    }
+
+    row![
+        status.size(13),
+        text(details.join(" · "))
+            .size(12)
+            .color(iced::Color::from_rgb(0.5, 0.5, 0.5)),
+    ]
+    .spacing(12)
+    .align_y(iced::alignment::Vertical::Center)
+    .into()
+}
+
+/// Horário, direção e tipo, com o conteúdo formatado conforme o tipo
+fn view_message(message: &WsMessage) -> Element<'_, Message> {
+    let (arrow, color) = match message.direction {
+        WsDirection::Sent => ("↑", iced::Color::from_rgb(0.3, 0.6, 1.0)),
+        WsDirection::Received => ("↓", iced::Color::from_rgb(0.0, 0.7, 0.0)),
+    };
+
+    let header = row![
+        text(message.time()).size(12).font(iced::Font::MONOSPACE),
+        text(arrow).size(13).color(color),
+        text(message.kind.to_string()).size(13),
+    ]
+    .spacing(12);
+
+    let data = match message.kind {
+        WsKind::Text if json_formatter::is_valid_json(&message.data) => {
+            json_formatter::format(&message.data).unwrap_or_else(|_| message.data.clone())
+        }
+        WsKind::Text => message.data.clone(),
+        WsKind::Close => match message.close_code {
+            Some(code) if message.data.is_empty() => {
+                format!("{} {}", code, close_code_name(code))
+            }
+            Some(code) => format!("{} {}: {}", code, close_code_name(code), message.data),
+            None => "No status code".to_string(),
+        },
+        WsKind::Binary | WsKind::Ping | WsKind::Pong => {
+            text_formatter::hex_dump(&message.bytes(), HEX_DUMP_LIMIT)
+        }
+    };
+
+    let mut content = column![header].spacing(4);
+    if !data.is_empty() {
+        content = content.push(text(data).size(13).font(iced::Font::MONOSPACE));
+    }
+
+    container(content)
+        .padding([6, 10])
+        .width(Length::Fill)
+        .style(styles::timing_card)
+        .into()
+}
@@ -2,6 +2,9 @@
 pub mod url_validator {
     use url::Url;
 
+    /// Esquemas aceitos; URLs sem nenhum deles recebem https://
+    const SCHEMES: [&str; 4] = ["http://", "https://", "ws://", "wss://"];
+
     /// Valida e normaliza uma URL, adicionando https:// se necessário
     pub fn validate_and_normalize(input: &str) -> Result<String, String> {
         let trimmed = input.trim();
@@ -10,10 +13,10 @@ pub mod url_validator {
             return Err("URL cannot be empty".to_string());
         }
 
-        let normalized = if !trimmed.starts_with("http://") && !trimmed.starts_with("https://") {
-            format!("https://{}", trimmed)
-        } else {
+        let normalized = if has_scheme(trimmed) {
             trimmed.to_string()
+        } else {
+            format!("https://{}", trimmed)
         };
 
         match Url::parse(&normalized) {
@@ -22,6 +25,18 @@ pub mod url_validator {
         }
     }
 
+    /// Retorna true se a URL já começa com um esquema aceito
+    pub fn has_scheme(url: &str) -> bool {
+        let lower = url.trim().to_ascii_lowercase();
+        SCHEMES.iter().any(|scheme| lower.starts_with(scheme))
+    }
+
+    /// URL de WebSocket (`ws://` ou `wss://`)
+    pub fn is_websocket(url: &str) -> bool {
+        let lower = url.trim().to_ascii_lowercase();
+        lower.starts_with("ws://") || lower.starts_with("wss://")
+    }
+
     /// Verifica se uma URL é válida
     #[allow(dead_code)]
     pub fn is_valid(url: &str) -> bool {
@@ -48,13 +63,19 @@ pub mod json_formatter {
             .map_err(|e| format!("Invalid JSON: {}", e))
     }
 
+    /// Valida JSON; o erro indica linha e coluna
+    pub fn validate(json_str: &str) -> Result<(), String> {
+        serde_json::from_str::<Value>(json_str)
+            .map(|_| ())
+            .map_err(|e| format!("Invalid JSON: {}", e))
+    }
+
     /// Verifica se uma string é JSON válido
     pub fn is_valid_json(json_str: &str) -> bool {
         serde_json::from_str::<Value>(json_str).is_ok()
     }
 
     /// Minifica JSON
-    #[allow(dead_code)]
     pub fn minify(json_str: &str) -> Result<String, String> {
         serde_json::from_str::<Value>(json_str)
             .map(|v| serde_json::to_string(&v).unwrap_or_else(|_| json_str.to_string()))
@@ -62,6 +83,42 @@ pub mod json_formatter {
     }
 }
 
+/// Utilitários para formatação de YAML (um ou mais documentos separados por `---`)
+pub mod yaml_formatter {
+    use serde::Deserialize;
+    use serde_norway::{Deserializer, Value};
+
+    fn documents(yaml_str: &str) -> Result<Vec<Value>, String> {
+        Deserializer::from_str(yaml_str)
+            .map(Value::deserialize)
+            .collect::<Result<_, _>>()
+            .map_err(|e| format!("Invalid YAML: {}", e))
+    }
+
+    /// Valida YAML; o erro indica linha e coluna
+    pub fn validate(yaml_str: &str) -> Result<(), String> {
+        documents(yaml_str).map(|_| ())
+    }
+
+    /// Reescreve o YAML em estilo de bloco. Comentários não são preservados.
+    pub fn format(yaml_str: &str) -> Result<String, String> {
+        let documents = documents(yaml_str)?
+            .iter()
+            .map(|doc| serde_norway::to_string(doc).map_err(|e| e.to_string()))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(documents.join("---\n").trim_end().to_string())
+    }
+
+    /// Reescreve cada documento em uma linha, no estilo de fluxo (JSON é YAML válido)
+    pub fn minify(yaml_str: &str) -> Result<String, String> {
+        let documents = documents(yaml_str)?
+            .iter()
+            .map(|doc| serde_json::to_string(doc).map_err(|e| format!("Cannot minify YAML: {}", e)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(documents.join("\n---\n"))
+    }
+}
+
 /// Utilitários para formatação de texto
 pub mod text_formatter {
     /// Trunca texto se for muito longo
@@ -89,7 +146,6 @@ pub mod text_formatter {
     }
 
     /// Formata tamanho de bytes de forma legível
-    #[allow(dead_code)]
     pub fn format_bytes(bytes: usize) -> String {
         const KB: f64 = 1024.0;
         const MB: f64 = KB * 1024.0;
@@ -107,31 +163,206 @@ pub mod text_formatter {
             format!("{:.2} GB", bytes_f64 / GB)
         }
     }
+
+    /// Dump hexadecimal (offset, 16 bytes por linha e coluna ASCII) dos primeiros `limit` bytes
+    pub fn hex_dump(bytes: &[u8], limit: usize) -> String {
+        let mut lines: Vec<String> = bytes[..bytes.len().min(limit)]
+            .chunks(16)
+            .enumerate()
+            .map(|(index, chunk)| {
+                let hex: Vec<String> = chunk.iter().map(|b| format!("{:02x}", b)).collect();
+                let ascii: String = chunk
+                    .iter()
+                    .map(|&b| {
+                        if b.is_ascii_graphic() || b == b' ' {
+                            b as char
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect();
+                format!("{:08x}  {:<47}  |{}|", index * 16, hex.join(" "), ascii)
+            })
+            .collect();
+
+        if bytes.len() > limit {
+            lines.push(format!("... {} more bytes", bytes.len() - limit));
+        }
+        lines.join("\n")
+    }
 }
 
-/// Utilitários para exportação
-pub mod export {
-    use super::super::enums::{HttpRequest, KeyValue};
+/// Tipos de conteúdo (MIME) das respostas
+pub mod mime {
+    /// Tipo sem parâmetros e em minúsculas (`text/html; charset=utf-8` → `text/html`)
+    pub fn essence(content_type: &str) -> String {
+        content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase()
+    }
 
-    /// Exporta uma requisição como comando curl
-    #[allow(dead_code)]
-    pub fn to_curl(request: &HttpRequest, full_url: &str) -> String {
-        let mut curl = format!("curl -X {} '{}'", request.method, full_url);
+    /// Retorna true para tipos que são texto mesmo fora de `text/*` (JSON, XML, JS...)
+    pub fn is_text(essence: &str) -> bool {
+        essence.starts_with("text/")
+            || essence.ends_with("json")
+            || essence.ends_with("xml")
+            || essence.ends_with("yaml")
+            || essence.ends_with("javascript")
+            || essence.ends_with("graphql")
+            || essence == "application/x-www-form-urlencoded"
+    }
 
-        for header in &request.headers {
-            if header.enabled && !header.key.is_empty() {
-                curl.push_str(&format!(" \\\n  -H '{}: {}'", header.key, header.value));
-            }
+    /// Retorna true para tipos sabidamente binários (imagens, áudio, arquivos compactados...)
+    pub fn is_binary(essence: &str) -> bool {
+        ["image/", "audio/", "video/", "font/"]
+            .iter()
+            .any(|prefix| essence.starts_with(prefix))
+            || matches!(
+                essence,
+                "application/octet-stream"
+                    | "application/pdf"
+                    | "application/zip"
+                    | "application/gzip"
+                    | "application/x-gzip"
+                    | "application/wasm"
+                    | "application/protobuf"
+                    | "application/x-protobuf"
+                    | "application/vnd.google.protobuf"
+            )
+    }
+
+    /// Decodifica o body como texto conforme o `Content-Type`.
+    ///
+    /// Sem um tipo conhecido, só é texto se for UTF-8 válido e sem bytes nulos.
+    pub fn decode_text(content_type: Option<&str>, bytes: &[u8]) -> Option<String> {
+        let essence = content_type.map(essence).unwrap_or_default();
+        if is_text(&essence) {
+            Some(String::from_utf8_lossy(bytes).into_owned())
+        } else if is_binary(&essence) && !essence.ends_with("+xml") {
+            None
+        } else {
+            std::str::from_utf8(bytes)
+                .ok()
+                .filter(|text| !text.contains('\0'))
+                .map(str::to_string)
+        }
+    }
+
+    /// Tipo de um arquivo pela extensão, para bodies binários
+    pub fn from_path(path: &str) -> &'static str {
+        let extension = std::path::Path::new(path.trim())
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_ascii_lowercase)
+            .unwrap_or_default();
+
+        match extension.as_str() {
+            "json" => "application/json",
+            "xml" => "application/xml",
+            "html" | "htm" => "text/html",
+            "txt" => "text/plain",
+            "csv" => "text/csv",
+            "css" => "text/css",
+            "js" => "application/javascript",
+            "yaml" | "yml" => "application/yaml",
+            "png" => "image/png",
+            "jpg" | "jpeg" => "image/jpeg",
+            "gif" => "image/gif",
+            "webp" => "image/webp",
+            "svg" => "image/svg+xml",
+            "pdf" => "application/pdf",
+            "zip" => "application/zip",
+            "gz" => "application/gzip",
+            "wasm" => "application/wasm",
+            "mp3" => "audio/mpeg",
+            "mp4" => "video/mp4",
+            _ => "application/octet-stream",
         }
+    }
+
+    /// Extensão sugerida ao salvar um body do tipo informado
+    pub fn extension(essence: &str) -> &'static str {
+        match essence {
+            "application/json" => "json",
+            "text/html" => "html",
+            "application/xml" | "text/xml" => "xml",
+            "text/plain" => "txt",
+            "text/css" => "css",
+            "text/csv" => "csv",
+            "application/javascript" | "text/javascript" => "js",
+            "image/png" => "png",
+            "image/jpeg" => "jpg",
+            "image/gif" => "gif",
+            "image/webp" => "webp",
+            "image/svg+xml" => "svg",
+            "application/pdf" => "pdf",
+            "application/zip" => "zip",
+            "application/gzip" | "application/x-gzip" => "gz",
+            _ if essence.ends_with("+json") => "json",
+            _ if essence.ends_with("+xml") => "xml",
+            _ => "bin",
+        }
+    }
+}
 
-        if !request.body.is_empty() && request.body_type != super::super::enums::BodyType::None {
-            let escaped_body = request.body.replace('\'', "'\\''");
-            curl.push_str(&format!(" \\\n  -d '{}'", escaped_body));
+/// Interpolação de variáveis `{{nome}}`
+pub mod template {
+    use std::collections::HashMap;
+
+    /// Substitui as variáveis conhecidas e retorna o texto junto com os nomes não resolvidos
+    pub fn substitute(text: &str, variables: &HashMap<String, String>) -> (String, Vec<String>) {
+        let mut output = String::with_capacity(text.len());
+        let mut unresolved = Vec::new();
+        let mut rest = text;
+
+        while let Some(start) = rest.find("{{") {
+            output.push_str(&rest[..start]);
+            let after_open = &rest[start + 2..];
+
+            let Some(end) = after_open.find("}}") else {
+                output.push_str(&rest[start..]);
+                return (output, unresolved);
+            };
+
+            let raw = &after_open[..end];
+            let name = raw.trim();
+
+            if is_variable_name(name) {
+                match variables.get(name) {
+                    Some(value) => output.push_str(value),
+                    None => {
+                        if !unresolved.iter().any(|u| u == name) {
+                            unresolved.push(name.to_string());
+                        }
+                        output.push_str(&rest[start..start + 2 + end + 2]);
+                    }
+                }
+            } else {
+                output.push_str(&rest[start..start + 2 + end + 2]);
+            }
+
+            rest = &after_open[end + 2..];
         }
 
-        curl
+        output.push_str(rest);
+        (output, unresolved)
     }
 
+    fn is_variable_name(name: &str) -> bool {
+        !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | '$'))
+    }
+}
+
+/// Utilitários para exportação
+pub mod export {
+    use super::super::enums::KeyValue;
+
     /// Exporta headers como string formatada
     #[allow(dead_code)]
     pub fn headers_to_string(headers: &[KeyValue]) -> String {
@@ -153,6 +384,12 @@ mod tests {
         assert!(url_validator::is_valid("https://example.com"));
         assert!(!url_validator::is_valid(""));
         assert!(!url_validator::is_valid("not a url"));
+        assert_eq!(
+            url_validator::validate_and_normalize("wss://echo.example.com/socket").unwrap(),
+            "wss://echo.example.com/socket"
+        );
+        assert!(url_validator::is_websocket(" WS://localhost:8080"));
+        assert!(!url_validator::is_websocket("https://example.com/ws"));
     }
 
     #[test]
@@ -162,9 +399,83 @@ mod tests {
         assert!(json_formatter::format(json).is_ok());
     }
 
+    #[test]
+    fn test_yaml_formatter() {
+        let yaml = "name:   api\nports: [80,   443]\n---\nenabled: true";
+        assert_eq!(
+            yaml_formatter::format(yaml).unwrap(),
+            "name: api\nports:\n- 80\n- 443\n---\nenabled: true"
+        );
+        assert_eq!(
+            yaml_formatter::minify(yaml).unwrap(),
+            "{\"name\":\"api\",\"ports\":[80,443]}\n---\n{\"enabled\":true}"
+        );
+        assert_eq!(
+            yaml_formatter::validate("key: value\n  bad: indent").unwrap_err(),
+            "Invalid YAML: mapping values are not allowed in this context at line 2 column 6"
+        );
+    }
+
+    #[test]
+    fn test_template_substitution() {
+        let variables = std::collections::HashMap::from([
+            ("host".to_string(), "api.dev".to_string()),
+            ("token".to_string(), "abc".to_string()),
+        ]);
+
+        let (text, unresolved) = template::substitute(
+            "https://{{host}}/users?t={{ token }}&x={{missing}}",
+            &variables,
+        );
+        assert_eq!(text, "https://api.dev/users?t=abc&x={{missing}}");
+        assert_eq!(unresolved, vec!["missing".to_string()]);
+
+        let (text, unresolved) = template::substitute("{{ not a var }} {{open", &variables);
+        assert_eq!(text, "{{ not a var }} {{open");
+        assert!(unresolved.is_empty());
+    }
+
     #[test]
     fn test_duration_formatter() {
         assert_eq!(text_formatter::format_duration(500), "500ms");
         assert_eq!(text_formatter::format_duration(1500), "1.50s");
     }
+
+    #[test]
+    fn test_hex_dump() {
+        let dump = text_formatter::hex_dump(b"\x89PNG\r\n\x1a\n0123456789abcdef", 20);
+        assert_eq!(
+            dump,
+            "00000000  89 50 4e 47 0d 0a 1a 0a 30 31 32 33 34 35 36 37  |.PNG....01234567|\n\
+             00000010  38 39 61 62                                      |89ab|\n\
+             ... 4 more bytes"
+        );
+    }
+
+    #[test]
+    fn test_mime_from_path() {
+        assert_eq!(mime::from_path("/tmp/Logo.PNG"), "image/png");
+        assert_eq!(mime::from_path("data.json"), "application/json");
+        assert_eq!(mime::from_path("blob"), "application/octet-stream");
+    }
+
+    #[test]
+    fn test_decode_text_by_content_type() {
+        let png = b"\x89PNG\r\n\x1a\n";
+        assert_eq!(mime::decode_text(Some("image/png"), png), None);
+        assert_eq!(mime::decode_text(None, png), None);
+        assert_eq!(
+            mime::decode_text(Some("application/problem+json; charset=utf-8"), b"{}"),
+            Some("{}".to_string())
+        );
+        assert_eq!(
+            mime::decode_text(Some("image/svg+xml"), b"<svg/>"),
+            Some("<svg/>".to_string())
+        );
+        // Tipo desconhecido: texto se for UTF-8 válido
+        assert_eq!(
+            mime::decode_text(Some("application/x-custom"), "olá".as_bytes()),
+            Some("olá".to_string())
+        );
+    }
 }
@@ -0,0 +1,552 @@
+//! Sessões WebSocket: handshake com os headers da requisição, log das mensagens
+//! enviadas e recebidas e a subscription que mantém a conexão aberta
+
+use super::auth;
+use super::enums::{HttpRequest, HttpResponse};
+use super::http_client::HttpClient;
+use super::utils::json_formatter;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use chrono::{Local, TimeZone, Utc};
+use iced::futures::channel::mpsc;
+use iced::futures::{SinkExt, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::hash::{Hash, Hasher};
+use std::time::{Duration, Instant};
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::{HeaderName, HeaderValue};
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+use tokio_tungstenite::tungstenite::{self, Bytes};
+
+/// Mensagens mantidas na sessão; as mais antigas são descartadas
+const MAX_MESSAGES: usize = 1000;
+
+/// Espera pelo close do servidor depois de pedir o fechamento
+const CLOSE_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WsDirection {
+    Sent,
+    Received,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WsKind {
+    Text,
+    Binary,
+    Ping,
+    Pong,
+    Close,
+}
+
+impl std::fmt::Display for WsKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            WsKind::Text => "text",
+            WsKind::Binary => "binary",
+            WsKind::Ping => "ping",
+            WsKind::Pong => "pong",
+            WsKind::Close => "close",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Mensagem do log da sessão, gravada no histórico
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WsMessage {
+    pub direction: WsDirection,
+    pub kind: WsKind,
+    /// Texto, motivo do fechamento, ou base64 para binário, ping e pong
+    pub data: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub close_code: Option<u16>,
+    /// Milissegundos desde a época Unix
+    pub timestamp_ms: i64,
+}
+
+impl WsMessage {
+    fn new(direction: WsDirection, message: &tungstenite::Message) -> Option<Self> {
+        let (kind, data, close_code) = match message {
+            tungstenite::Message::Text(text) => (WsKind::Text, text.to_string(), None),
+            tungstenite::Message::Binary(bytes) => (WsKind::Binary, STANDARD.encode(bytes), None),
+            tungstenite::Message::Ping(bytes) => (WsKind::Ping, STANDARD.encode(bytes), None),
+            tungstenite::Message::Pong(bytes) => (WsKind::Pong, STANDARD.encode(bytes), None),
+            tungstenite::Message::Close(frame) => (
+                WsKind::Close,
+                frame
+                    .as_ref()
+                    .map(|f| f.reason.to_string())
+                    .unwrap_or_default(),
+                frame.as_ref().map(|f| u16::from(f.code)),
+            ),
+            tungstenite::Message::Frame(_) => return None,
+        };
+
+        Some(Self {
+            direction,
+            kind,
+            data,
+            close_code,
+            timestamp_ms: Utc::now().timestamp_millis(),
+        })
+    }
+
+    /// Payload de binário, ping e pong
+    pub fn bytes(&self) -> Vec<u8> {
+        STANDARD.decode(&self.data).unwrap_or_default()
+    }
+
+    /// Horário local com milissegundos
+    pub fn time(&self) -> String {
+        Local
+            .timestamp_millis_opt(self.timestamp_ms)
+            .single()
+            .map(|time| time.format("%H:%M:%S%.3f").to_string())
+            .unwrap_or_default()
+    }
+}
+
+/// Nome do código de fechamento (RFC 6455, seção 7.4.1)
+pub fn close_code_name(code: u16) -> &'static str {
+    match code {
+        1000 => "Normal closure",
+        1001 => "Going away",
+        1002 => "Protocol error",
+        1003 => "Unsupported data",
+        1005 => "No status received",
+        1006 => "Abnormal closure",
+        1007 => "Invalid payload",
+        1008 => "Policy violation",
+        1009 => "Message too big",
+        1010 => "Missing extension",
+        1011 => "Internal error",
+        1012 => "Service restart",
+        1013 => "Try again later",
+        1015 => "TLS handshake failure",
+        3000..=3999 => "Registered",
+        4000..=4999 => "Application",
+        _ => "Unknown",
+    }
+}
+
+/// Formato do texto digitado no compositor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WsFormat {
+    #[default]
+    Text,
+    Json,
+    /// Bytes em hexadecimal (`01 ff 7a`)
+    Binary,
+}
+
+impl WsFormat {
+    pub const ALL: [WsFormat; 3] = [WsFormat::Text, WsFormat::Json, WsFormat::Binary];
+
+    /// Converte o texto do compositor na mensagem enviada
+    pub fn encode(self, input: &str) -> Result<tungstenite::Message, String> {
+        match self {
+            WsFormat::Text => Ok(tungstenite::Message::text(input)),
+            WsFormat::Json => {
+                json_formatter::validate(input)?;
+                Ok(tungstenite::Message::text(input.trim()))
+            }
+            WsFormat::Binary => parse_hex(input).map(tungstenite::Message::binary),
+        }
+    }
+}
+
+impl std::fmt::Display for WsFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            WsFormat::Text => "Text",
+            WsFormat::Json => "JSON",
+            WsFormat::Binary => "Binary (hex)",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Bytes em hexadecimal, com espaços opcionais entre eles
+pub fn parse_hex(input: &str) -> Result<Vec<u8>, String> {
+    let digits: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+    if !digits.len().is_multiple_of(2) || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err("Binary messages are written as hex bytes, e.g. 01 ff 7a".to_string());
+    }
+
+    (0..digits.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&digits[i..i + 2], 16).map_err(|e| e.to_string()))
+        .collect()
+}
+
+/// Comando da UI para a conexão aberta
+#[derive(Debug, Clone)]
+pub enum WsCommand {
+    Send(tungstenite::Message),
+    Close,
+}
+
+/// Canal para enviar comandos à conexão, recebido quando o handshake termina
+#[derive(Debug, Clone)]
+pub struct WsSender(mpsc::Sender<WsCommand>);
+
+impl WsSender {
+    fn send(&mut self, command: WsCommand) -> Result<(), String> {
+        self.0
+            .try_send(command)
+            .map_err(|_| "The WebSocket connection is closed".to_string())
+    }
+}
+
+/// Mensagens da subscription para a sessão
+#[derive(Debug, Clone)]
+pub enum WsEvent {
+    /// Handshake concluído, com a resposta `101 Switching Protocols`
+    Connected(WsSender, Box<HttpResponse>),
+    Message(WsMessage),
+    /// Conexão encerrada: `None` quando fechou normalmente
+    Closed(Option<String>),
+}
+
+/// Sessão exibida na área de resposta enquanto a URL é `ws://` ou `wss://`
+#[derive(Debug)]
+pub struct WsSession {
+    pub id: u64,
+    /// Requisição como editada, com as variáveis `{{nome}}` intactas
+    pub request: HttpRequest,
+    /// Requisição usada no handshake
+    pub resolved: HttpRequest,
+    sender: Option<WsSender>,
+    pub handshake: Option<HttpResponse>,
+    pub messages: Vec<WsMessage>,
+    /// Subscription ativa (conectando ou conectada)
+    pub running: bool,
+    pub error: Option<String>,
+}
+
+impl WsSession {
+    pub fn new(id: u64, request: HttpRequest, resolved: HttpRequest) -> Self {
+        Self {
+            id,
+            request,
+            resolved,
+            sender: None,
+            handshake: None,
+            messages: Vec::new(),
+            running: true,
+            error: None,
+        }
+    }
+
+    /// Sessão gravada no histórico, exibida só para leitura
+    pub fn from_history(
+        request: HttpRequest,
+        handshake: Option<HttpResponse>,
+        messages: Vec<WsMessage>,
+    ) -> Self {
+        Self {
+            handshake,
+            messages,
+            running: false,
+            ..Self::new(0, request.clone(), request)
+        }
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.sender.is_some()
+    }
+
+    /// Aplica um evento da conexão. Retorna true quando uma conexão aberta terminou
+    /// e a sessão deve ir para o histórico.
+    pub fn apply(&mut self, event: WsEvent) -> bool {
+        match event {
+            WsEvent::Connected(sender, handshake) => {
+                self.sender = Some(sender);
+                self.handshake = Some(*handshake);
+                false
+            }
+            WsEvent::Message(message) => {
+                self.messages.push(message);
+                if self.messages.len() > MAX_MESSAGES {
+                    self.messages.drain(..self.messages.len() - MAX_MESSAGES);
+                }
+                false
+            }
+            WsEvent::Closed(error) => {
+                self.error = error;
+                self.finish()
+            }
+        }
+    }
+
+    pub fn send(&mut self, message: tungstenite::Message) -> Result<(), String> {
+        match &mut self.sender {
+            Some(sender) => sender.send(WsCommand::Send(message)),
+            None => Err("The WebSocket connection is closed".to_string()),
+        }
+    }
+
+    /// Pede o fechamento (código 1000); a sessão termina com o close do servidor
+    pub fn close(&mut self) {
+        if let Some(sender) = &mut self.sender {
+            let _ = sender.send(WsCommand::Close);
+        }
+    }
+
+    /// Descarta a subscription, fechando a conexão sem o handshake de fechamento.
+    /// Retorna true se a conexão estava aberta.
+    pub fn finish(&mut self) -> bool {
+        let was_open = self.running && self.handshake.is_some();
+        self.running = false;
+        self.sender = None;
+        was_open
+    }
+
+    /// Conexão lida pela subscription enquanto a sessão está ativa
+    pub fn connection(&self) -> Option<WsConnection> {
+        self.running.then(|| WsConnection {
+            session: self.id,
+            request: self.resolved.clone(),
+        })
+    }
+}
+
+/// Identidade da subscription: uma por sessão
+pub struct WsConnection {
+    session: u64,
+    request: HttpRequest,
+}
+
+impl Hash for WsConnection {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.session.hash(state);
+    }
+}
+
+type Socket =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+/// Faz o handshake com a URL, query params, headers e autenticação da requisição
+async fn open(request: &HttpRequest) -> Result<(Socket, HttpResponse), String> {
+    let request = auth::apply(request);
+    let url = HttpClient::full_url(&request)?;
+
+    let mut handshake = url
+        .as_str()
+        .into_client_request()
+        .map_err(|e| format!("Invalid URL: {}", e))?;
+    for header in request
+        .headers
+        .iter()
+        .filter(|h| h.enabled && !h.key.trim().is_empty())
+    {
+        let name = HeaderName::from_bytes(header.key.trim().as_bytes())
+            .map_err(|_| format!("Invalid header name: {}", header.key))?;
+        let value = HeaderValue::from_str(&header.value)
+            .map_err(|_| format!("Invalid value for header {}", header.key))?;
+        handshake.headers_mut().append(name, value);
+    }
+
+    let start = Instant::now();
+    let (socket, response) = tokio::time::timeout(
+        Duration::from_millis(request.timeout_ms),
+        tokio_tungstenite::connect_async(handshake),
+    )
+    .await
+    .map_err(|_| "Handshake timeout - the server took too long to respond".to_string())?
+    .map_err(|error| match error {
+        tungstenite::Error::Http(response) => format!(
+            "Handshake rejected: {} {}",
+            response.status().as_u16(),
+            response.status().canonical_reason().unwrap_or("Unknown")
+        ),
+        error => format!("WebSocket error: {}", error),
+    })?;
+
+    let headers = response
+        .headers()
+        .iter()
+        .filter_map(|(key, value)| {
+            value
+                .to_str()
+                .ok()
+                .map(|v| (key.to_string(), v.to_string()))
+        })
+        .collect();
+
+    Ok((
+        socket,
+        HttpResponse::new(
+            response.status().as_u16(),
+            response
+                .status()
+                .canonical_reason()
+                .unwrap_or("Unknown")
+                .to_string(),
+            headers,
+            Bytes::new(),
+            start.elapsed().as_millis(),
+        ),
+    ))
+}
+
+/// Abre a conexão e repassa as mensagens nos dois sentidos até ela fechar
+pub fn connect(connection: &WsConnection) -> impl Stream<Item = WsEvent> + use<> {
+    let request = connection.request.clone();
+
+    iced::stream::channel(100, async move |mut output: mpsc::Sender<WsEvent>| {
+        let (mut socket, handshake) = match open(&request).await {
+            Ok(opened) => opened,
+            Err(error) => {
+                let _ = output.send(WsEvent::Closed(Some(error))).await;
+                return;
+            }
+        };
+
+        let (sender, mut commands) = mpsc::channel(32);
+        let _ = output
+            .send(WsEvent::Connected(WsSender(sender), Box::new(handshake)))
+            .await;
+
+        let mut closing: Option<tokio::time::Instant> = None;
+        let error = loop {
+            tokio::select! {
+                Some(command) = commands.next() => {
+                    let message = match command {
+                        WsCommand::Send(message) => message,
+                        WsCommand::Close => {
+                            closing = Some(tokio::time::Instant::now() + CLOSE_TIMEOUT);
+                            tungstenite::Message::Close(Some(CloseFrame {
+                                code: CloseCode::Normal,
+                                reason: "".into(),
+                            }))
+                        }
+                    };
+                    let logged = WsMessage::new(WsDirection::Sent, &message);
+                    if let Err(e) = socket.send(message).await {
+                        break Some(format!("WebSocket error: {}", e));
+                    }
+                    if let Some(logged) = logged {
+                        let _ = output.send(WsEvent::Message(logged)).await;
+                    }
+                }
+                frame = socket.next() => match frame {
+                    Some(Ok(message)) => {
+                        if let Some(logged) = WsMessage::new(WsDirection::Received, &message) {
+                            let _ = output.send(WsEvent::Message(logged)).await;
+                        }
+                    }
+                    Some(Err(
+                        tungstenite::Error::ConnectionClosed | tungstenite::Error::AlreadyClosed,
+                    ))
+                    | None => break None,
+                    Some(Err(e)) => break Some(format!("WebSocket error: {}", e)),
+                },
+                _ = tokio::time::sleep_until(closing.unwrap_or_else(tokio::time::Instant::now)),
+                    if closing.is_some() => break None,
+            }
+        };
+
+        let _ = output.send(WsEvent::Closed(error)).await;
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::enums::KeyValue;
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+
+    #[test]
+    fn test_composer_formats() {
+        assert_eq!(parse_hex("01 ff\n7A").unwrap(), vec![0x01, 0xff, 0x7a]);
+        assert!(parse_hex("abc").is_err());
+        assert!(parse_hex("zz").is_err());
+
+        assert!(WsFormat::Json.encode("{\"a\": 1").is_err());
+        assert_eq!(
+            WsFormat::Json.encode(" {\"a\": 1}\n").unwrap(),
+            tungstenite::Message::text("{\"a\": 1}")
+        );
+    }
+
+    /// Servidor que confere o header do handshake e devolve as mensagens recebidas
+    async fn echo_server() -> String {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await.unwrap();
+        let url = format!("ws://{}/socket", listener.local_addr().unwrap());
+
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            // A assinatura do callback é definida pelo tungstenite
+            #[allow(clippy::result_large_err)]
+            let check = |request: &Request, response: Response| {
+                assert_eq!(request.headers()["x-token"], "abc");
+                assert_eq!(request.uri().query(), Some("room=1"));
+                Ok(response)
+            };
+            let mut socket = tokio_tungstenite::accept_hdr_async(stream, check)
+                .await
+                .unwrap();
+            while let Some(Ok(message)) = socket.next().await {
+                if message.is_text() || message.is_binary() {
+                    socket.send(message).await.unwrap();
+                }
+            }
+        });
+
+        url
+    }
+
+    #[tokio::test]
+    async fn test_session_round_trip() {
+        let request = HttpRequest {
+            url: echo_server().await,
+            headers: vec![KeyValue::new("X-Token".into(), "abc".into())],
+            query_params: vec![KeyValue::new("room".into(), "1".into())],
+            ..HttpRequest::default()
+        };
+        let mut session = WsSession::new(1, request.clone(), request);
+        let mut events = Box::pin(connect(&session.connection().unwrap()));
+
+        let Some(WsEvent::Connected(sender, handshake)) = events.next().await else {
+            panic!("handshake failed");
+        };
+        assert_eq!(handshake.status, 101);
+        session.apply(WsEvent::Connected(sender, handshake));
+
+        session
+            .send(WsFormat::Text.encode("hello").unwrap())
+            .unwrap();
+        for _ in 0..2 {
+            let event = events.next().await.unwrap();
+            session.apply(event);
+        }
+        session.close();
+        let mut saved = false;
+        while let Some(event) = events.next().await {
+            saved = session.apply(event);
+        }
+
+        assert!(saved);
+        assert_eq!(session.error, None);
+        let log: Vec<_> = session
+            .messages
+            .iter()
+            .map(|m| (m.direction, m.kind, m.data.as_str(), m.close_code))
+            .collect();
+        assert_eq!(
+            log,
+            vec![
+                (WsDirection::Sent, WsKind::Text, "hello", None),
+                (WsDirection::Received, WsKind::Text, "hello", None),
+                (WsDirection::Sent, WsKind::Close, "", Some(1000)),
+                (WsDirection::Received, WsKind::Close, "", Some(1000)),
+            ]
+        );
+    }
+}
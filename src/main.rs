@@ -1,77 +1,255 @@
 use iced::Length::Fill;
 use iced::Theme;
-use iced::widget::{column, container, row, scrollable, text_editor};
-use iced::{Element, Length, Task};
+use iced::futures::SinkExt;
+use iced::task;
+use iced::widget::{column, combo_box, container, image, row, scrollable, text_editor};
+use iced::{Element, Length, Subscription, Task};
 
 mod components;
 use components::{
+    collections::{self, CollectionNode, NodePath, RequestCollections},
+    cookies::CookieDraft,
     enums::{
-        BodyType, DEFAULT_TIMEOUT_MS, HTTPMethod, HttpRequest, HttpResponse, KeyValue, Message,
-        RequestTab, ResponseTab,
+        Auth, BodyType, DEFAULT_MAX_REDIRECTS, DEFAULT_RESPONSE_LIMIT_MB, DEFAULT_TIMEOUT_MS,
+        DownloadProgress, FormPart, FormPartKind, GraphQlBody, HTTPMethod, HttpRequest,
+        HttpResponse, KeyValue, Message, OAuth2Config, RedirectPolicy, RequestTab, ResponseTab,
+        SidebarTab,
     },
+    environment::{self, EnvironmentStore},
+    file_dialog::{self, FileFilter},
+    formats::{
+        codegen, curl, har,
+        http_file::{self, HttpFile, OpenedHttpFile},
+        openapi, postman,
+    },
+    graphql::{self, GraphQlSchema, SchemaCache},
     history::RequestHistory,
     http_client::HttpClient,
+    oauth2,
+    sse::{self, SseSession},
     styles, ui,
-    utils::url_validator,
+    utils::{mime, url_validator},
+    websocket::{self, WsEvent, WsFormat, WsSession},
 };
 
 struct App {
     method: HTTPMethod,
+    method_options: combo_box::State<HTTPMethod>,
+    /// Verbo digitado no seletor, aplicado só ao fechar o combo
+    method_input: String,
     url: String,
     headers: Vec<KeyValue>,
     query_params: Vec<KeyValue>,
     body: String,
     body_content: text_editor::Content,
     body_type: BodyType,
+    /// Erro de sintaxe do body conforme o `body_type`, exibido abaixo dos tipos
+    body_error: Option<String>,
+    form_data: Vec<FormPart>,
+    form_urlencoded: Vec<KeyValue>,
+    binary_file: String,
+    graphql: GraphQlBody,
+    graphql_query: text_editor::Content,
+    graphql_variables: text_editor::Content,
+    /// Schemas da introspecção, por URL (como digitada no editor)
+    graphql_schemas: SchemaCache,
+    graphql_schema_filter: String,
+    graphql_fetching: bool,
+    force_body: bool,
     timeout_ms: String,
+    response_limit_mb: String,
+    redirects: RedirectPolicy,
+    /// Texto do campo "Max redirects"; `redirects.max_hops` é lido dele no envio
+    max_redirects: String,
+    send_cookies: bool,
+    /// Formulário de novo cookie no gerenciador
+    cookie_draft: CookieDraft,
+    auth: Auth,
+    oauth2_fetching: bool,
+    curl_import_offered: bool,
+    show_code_panel: bool,
+    code_target: codegen::CodeTarget,
     active_tab: RequestTab,
     response_tab: ResponseTab,
     is_loading: bool,
+    /// Andamento do body da resposta em download
+    download: Option<DownloadProgress>,
+    /// Viewer de eventos da última resposta `text/event-stream`
+    sse: Option<SseSession>,
+    /// Sessão WebSocket aberta (ou carregada do histórico) para URLs `ws://`/`wss://`
+    websocket: Option<WsSession>,
+    ws_format: WsFormat,
+    ws_message: text_editor::Content,
+    ws_error: Option<String>,
     error_message: Option<String>,
+    notice_message: Option<String>,
     response: Option<HttpResponse>,
+    /// Handle da imagem da resposta, criado uma vez para não decodificar a cada render
+    response_image: Option<image::Handle>,
     history: RequestHistory,
+    collections: RequestCollections,
+    collection_selection: Option<NodePath>,
+    collection_name: String,
+    sidebar_tab: SidebarTab,
+    http_file: Option<OpenedHttpFile>,
+    /// Entrada do arquivo `.http` carregada no editor; suas variáveis `@var` valem no envio
+    http_file_selection: Option<usize>,
+    environments: EnvironmentStore,
+    /// Variáveis sem valor no ambiente ativo, recalculadas em `update`
+    unresolved: Vec<String>,
+    /// Código do painel aberto, recalculado em `update`
+    generated_code: Option<Result<String, String>>,
+    /// Chave da configuração OAuth 2.0 resolvida, recalculada em `update`
+    oauth2_key: Option<String>,
+    /// Content-Type implícito do corpo, recalculado em `update`
+    implicit_content_type: Option<String>,
+    show_environment_editor: bool,
     http_client: HttpClient,
+    next_request_id: u64,
+    active_request: Option<ActiveRequest>,
+}
+
+/// Requisição em andamento, identificada para descartar respostas obsoletas
+struct ActiveRequest {
+    id: u64,
+    request: HttpRequest,
+    resolved: HttpRequest,
+    handle: task::Handle,
 }
 
 impl Default for App {
     fn default() -> Self {
         Self {
             method: HTTPMethod::GET,
+            method_options: combo_box::State::new(HTTPMethod::all()),
+            method_input: String::new(),
             url: String::new(),
-            headers: vec![KeyValue::new(
-                "Content-Type".to_string(),
-                "application/json".to_string(),
-            )],
+            headers: Vec::new(),
             query_params: Vec::new(),
             body: String::new(),
             body_content: text_editor::Content::new(),
             body_type: BodyType::Json,
+            body_error: None,
+            form_data: Vec::new(),
+            form_urlencoded: Vec::new(),
+            binary_file: String::new(),
+            graphql: GraphQlBody::default(),
+            graphql_query: text_editor::Content::new(),
+            graphql_variables: text_editor::Content::new(),
+            graphql_schemas: SchemaCache::new(),
+            graphql_schema_filter: String::new(),
+            graphql_fetching: false,
+            force_body: false,
             timeout_ms: DEFAULT_TIMEOUT_MS.to_string(),
+            response_limit_mb: DEFAULT_RESPONSE_LIMIT_MB.to_string(),
+            redirects: RedirectPolicy::default(),
+            max_redirects: DEFAULT_MAX_REDIRECTS.to_string(),
+            send_cookies: true,
+            cookie_draft: CookieDraft::default(),
+            auth: Auth::None,
+            oauth2_fetching: false,
+            curl_import_offered: false,
+            show_code_panel: false,
+            code_target: codegen::CodeTarget::default(),
             active_tab: RequestTab::QueryParams,
             response_tab: ResponseTab::Body,
             is_loading: false,
+            download: None,
+            sse: None,
+            websocket: None,
+            ws_format: WsFormat::default(),
+            ws_message: text_editor::Content::new(),
+            ws_error: None,
             error_message: None,
+            notice_message: None,
             response: None,
+            response_image: None,
             history: RequestHistory::new(),
+            collections: RequestCollections::new(),
+            collection_selection: None,
+            collection_name: String::new(),
+            sidebar_tab: SidebarTab::History,
+            http_file: None,
+            http_file_selection: None,
+            environments: EnvironmentStore::new(),
+            unresolved: Vec::new(),
+            generated_code: None,
+            oauth2_key: None,
+            implicit_content_type: None,
+            show_environment_editor: false,
             http_client: HttpClient::new(),
+            next_request_id: 0,
+            active_request: None,
         }
     }
 }
 
 impl App {
     fn update(&mut self, message: Message) -> Task<Message> {
+        // Eventos de streaming e troca de abas não alteram a requisição nem o ambiente
+        let refresh = !matches!(
+            message,
+            Message::ResponseProgress(..)
+                | Message::SseUpdate(..)
+                | Message::WebSocketEvent(..)
+                | Message::WsMessageAction(_)
+                | Message::TabChanged(_)
+                | Message::ResponseTabChanged(_)
+        );
+
+        let task = self.handle_message(message);
+        if refresh {
+            self.refresh_derived();
+        }
+        task
+    }
+
+    /// Recalcula o que a `view` exibe a partir da requisição, evitando resolvê-la a cada quadro
+    fn refresh_derived(&mut self) {
+        let request = self.build_request();
+        let variables = self.file_variables();
+        self.unresolved = self.environments.unresolved(&request, &variables);
+        self.implicit_content_type = request.implicit_content_type();
+
+        let needs_resolve = self.show_code_panel || matches!(request.auth, Auth::OAuth2(_));
+        let resolved = needs_resolve
+            .then(|| self.environments.resolve(&request, &variables).ok())
+            .flatten();
+
+        self.oauth2_key = match &resolved {
+            Some(HttpRequest {
+                auth: Auth::OAuth2(config),
+                ..
+            }) => Some(oauth2::cache_key(config)),
+            _ => None,
+        };
+
+        self.generated_code = self.show_code_panel.then(|| {
+            let request = self.code_request(resolved.unwrap_or(request));
+            codegen::generate(self.code_target, &request)
+        });
+    }
+
+    fn handle_message(&mut self, message: Message) -> Task<Message> {
         use Message::*;
 
         match message {
             HTTPSelected(method) => {
-                self.method = method;
-                if method == HTTPMethod::GET {
-                    self.body_type = BodyType::None;
-                    self.body.clear();
-                    self.body_content = text_editor::Content::new();
+                self.method_input.clear();
+                self.select_method(method);
+            }
+            MethodInputChanged(input) => self.method_input = input,
+            MethodInputClosed => {
+                // Um verbo pela metade nunca é aplicado, para não descartar o body
+                if let Some(method) =
+                    HTTPMethod::from_input(&std::mem::take(&mut self.method_input))
+                    && method != self.method
+                {
+                    self.select_method(method);
                 }
             }
             UrlChanged(url) => {
+                self.curl_import_offered = curl::looks_like_curl(&url);
                 self.url = url;
                 self.error_message = None;
             }
@@ -88,6 +266,12 @@ impl App {
             RemoveHeader(i) => {
                 self.headers.remove(i);
             }
+            OverrideContentType => {
+                if let Some(content_type) = self.build_request().implicit_content_type() {
+                    self.headers
+                        .push(KeyValue::new("Content-Type".to_string(), content_type));
+                }
+            }
             QueryParamKeyChanged(i, key) => {
                 Self::update_list_item(&mut self.query_params, i, |p| p.key = key)
             }
@@ -103,23 +287,556 @@ impl App {
             }
             BodyChanged(body) => self.body = body,
             BodyEditorAction(action) => {
+                let is_edit = action.is_edit();
                 self.body_content.perform(action);
-                self.body = self.body_content.text();
+                if is_edit {
+                    self.body = self.body_content.text();
+                    self.validate_body();
+                }
+            }
+            BodyTypeChanged(body_type) => {
+                self.body_type = body_type;
+                self.validate_body();
+            }
+            FormatBody => self.reformat_body(BodyType::format),
+            MinifyBody => self.reformat_body(BodyType::minify),
+            GraphQlQueryAction(action) => {
+                let is_edit = action.is_edit();
+                self.graphql_query.perform(action);
+                if is_edit {
+                    self.graphql.query = self.graphql_query.text();
+                    self.validate_body();
+                }
+            }
+            GraphQlVariablesAction(action) => {
+                let is_edit = action.is_edit();
+                self.graphql_variables.perform(action);
+                if is_edit {
+                    self.graphql.variables = self.graphql_variables.text();
+                    self.validate_body();
+                }
+            }
+            GraphQlOperationSelected(name) => {
+                self.graphql.operation_name = name;
+                self.validate_body();
+            }
+            GraphQlSchemaFilterChanged(filter) => self.graphql_schema_filter = filter,
+            FetchGraphQlSchema => return self.fetch_graphql_schema(),
+            GraphQlSchemaFetched(url, result) => {
+                self.graphql_fetching = false;
+                match result.and_then(|schema| {
+                    let count = schema.types.len();
+                    self.graphql_schemas.insert(&url, schema).map(|_| count)
+                }) {
+                    Ok(count) => {
+                        self.notice_message =
+                            Some(format!("GraphQL schema loaded: {} types", count))
+                    }
+                    Err(e) => self.error_message = Some(e),
+                }
+            }
+            FormPartKeyChanged(i, key) => {
+                Self::update_list_item(&mut self.form_data, i, |p| p.key = key)
+            }
+            FormPartValueChanged(i, value) => {
+                Self::update_list_item(&mut self.form_data, i, |p| p.value = value)
+            }
+            FormPartKindSelected(i, kind) => Self::update_list_item(&mut self.form_data, i, |p| {
+                if p.kind != kind {
+                    p.kind = kind;
+                    p.value.clear();
+                    p.file_name.clear();
+                }
+            }),
+            FormPartContentTypeChanged(i, content_type) => {
+                Self::update_list_item(&mut self.form_data, i, |p| p.content_type = content_type)
+            }
+            FormPartFileNameChanged(i, file_name) => {
+                Self::update_list_item(&mut self.form_data, i, |p| p.file_name = file_name)
+            }
+            FormPartToggled(i) => {
+                Self::update_list_item(&mut self.form_data, i, |p| p.enabled = !p.enabled)
+            }
+            AddFormPart => self.form_data.push(FormPart::default()),
+            RemoveFormPart(i) => {
+                self.form_data.remove(i);
+            }
+            PickFormPartFile(i) => {
+                return Task::perform(
+                    async move {
+                        file_dialog::pick_path("Choose file")
+                            .await
+                            .map(|path| path.display().to_string())
+                    },
+                    move |path| FormPartFilePicked(i, path),
+                );
+            }
+            FormPartFilePicked(i, Some(path)) => {
+                Self::update_list_item(&mut self.form_data, i, |p| {
+                    p.kind = FormPartKind::File;
+                    p.value = path;
+                })
+            }
+            FormPartFilePicked(_, None) => {}
+            BinaryFileChanged(path) => self.binary_file = path,
+            PickBinaryFile => {
+                return Task::perform(
+                    async {
+                        file_dialog::pick_path("Choose file")
+                            .await
+                            .map(|path| path.display().to_string())
+                    },
+                    BinaryFilePicked,
+                );
             }
-            BodyTypeChanged(body_type) => self.body_type = body_type,
+            BinaryFilePicked(Some(path)) => self.binary_file = path,
+            BinaryFilePicked(None) => {}
+            UrlEncodedFieldKeyChanged(i, key) => {
+                Self::update_list_item(&mut self.form_urlencoded, i, |f| f.key = key)
+            }
+            UrlEncodedFieldValueChanged(i, value) => {
+                Self::update_list_item(&mut self.form_urlencoded, i, |f| f.value = value)
+            }
+            UrlEncodedFieldEnabledToggled(i) => {
+                Self::update_list_item(&mut self.form_urlencoded, i, |f| f.enabled = !f.enabled)
+            }
+            AddUrlEncodedField => self.form_urlencoded.push(KeyValue::empty()),
+            RemoveUrlEncodedField(i) => {
+                self.form_urlencoded.remove(i);
+            }
+            ForceBodyToggled(force_body) => self.force_body = force_body,
             TimeoutChanged(timeout) => self.timeout_ms = timeout,
+            ResponseLimitChanged(limit) => self.response_limit_mb = limit,
+            FollowRedirectsToggled(follow) => self.redirects.follow = follow,
+            MaxRedirectsChanged(max) => self.max_redirects = max,
+            KeepRedirectMethodToggled(keep) => self.redirects.keep_method = keep,
+            StripRedirectAuthToggled(strip) => self.redirects.strip_auth_cross_origin = strip,
+            SendCookiesToggled(send) => self.send_cookies = send,
+            CookieValueChanged(key, value) => self.http_client.cookies().set_value(&key, value),
+            DeleteCookie(key) => self.http_client.cookies().remove(&key),
+            ClearCookieDomain(domain) => self.http_client.cookies().clear_domain(&domain),
+            ClearCookies => self.http_client.cookies().clear(),
+            CookieDraftChanged(field, value) => self.cookie_draft.set(field, value),
+            AddCookie => match self.cookie_draft.build() {
+                Ok(cookie) => {
+                    self.http_client.cookies().insert(cookie);
+                    self.cookie_draft = CookieDraft::default();
+                    self.error_message = None;
+                }
+                Err(error) => self.error_message = Some(error),
+            },
+            AuthKindSelected(kind) => self.auth = self.auth.with_kind(kind),
+            AuthFieldChanged(field, value) => self.auth.set_field(field, value),
+            OAuth2GrantSelected(grant) => {
+                if let Auth::OAuth2(config) = &mut self.auth {
+                    config.grant = grant;
+                }
+            }
+            FetchOAuth2Token => return self.fetch_oauth2_token(),
+            OAuth2TokenFetched(result) => {
+                self.oauth2_fetching = false;
+                match result {
+                    Ok(()) => self.notice_message = Some("OAuth2 token acquired".to_string()),
+                    Err(e) => self.error_message = Some(e),
+                }
+            }
+            RemoveOAuth2Token(key) => self.http_client.oauth2_tokens().remove(&key),
+            ClearOAuth2Tokens => self.http_client.oauth2_tokens().clear(),
+            ApiKeyLocationSelected(location) => {
+                if let Auth::ApiKey {
+                    location: current, ..
+                } = &mut self.auth
+                {
+                    *current = location;
+                }
+            }
+            ImportCurl => self.import_curl(),
+            DismissCurlImport => self.curl_import_offered = false,
+            ToggleCodePanel => self.show_code_panel = !self.show_code_panel,
+            CodeTargetSelected(target) => self.code_target = target,
+            CopyCode => {
+                if let Some(Ok(code)) = &self.generated_code {
+                    self.notice_message =
+                        Some(format!("{} snippet copied to clipboard", self.code_target));
+                    return iced::clipboard::write(code.clone());
+                }
+            }
             Submit => return self.submit_request(),
-            RequestCompleted(result) => self.handle_response(result),
-            CancelRequest => self.is_loading = false,
+            ResponseProgress(id, progress) => {
+                if self.active_request.as_ref().is_some_and(|a| a.id == id) {
+                    self.download = Some(progress);
+                }
+            }
+            RequestCompleted(id, result) => self.handle_response(id, result),
+            SseUpdate(id, update) => {
+                if let Some(session) = self.sse.as_mut().filter(|s| s.id == id) {
+                    session.apply(update);
+                }
+            }
+            ToggleSsePause => {
+                if let Some(session) = &mut self.sse {
+                    session.toggle_pause();
+                }
+            }
+            StopSse => {
+                if let Some(session) = &mut self.sse {
+                    session.stop();
+                }
+            }
+            ReconnectSse => {
+                if let Some(session) = &mut self.sse {
+                    session.reconnect();
+                }
+            }
+            WebSocketEvent(id, event) => self.handle_websocket_event(id, event),
+            WsFormatSelected(format) => {
+                self.ws_format = format;
+                self.ws_error = None;
+            }
+            WsMessageAction(action) => self.ws_message.perform(action),
+            SendWs => {
+                let result = self
+                    .ws_format
+                    .encode(&self.ws_message.text())
+                    .and_then(|message| match &mut self.websocket {
+                        Some(session) => session.send(message),
+                        None => Err("The WebSocket connection is closed".to_string()),
+                    });
+                self.ws_error = result.err();
+            }
+            SendWsPing => {
+                if let Some(session) = &mut self.websocket {
+                    self.ws_error = session
+                        .send(tokio_tungstenite::tungstenite::Message::Ping(
+                            Default::default(),
+                        ))
+                        .err();
+                }
+            }
+            CloseWebSocket => {
+                if let Some(session) = &mut self.websocket {
+                    session.close();
+                }
+            }
+            CancelRequest => self.cancel_request(),
+            EnvironmentSelected(name) => {
+                let index = self
+                    .environments
+                    .get_items()
+                    .iter()
+                    .position(|e| e.name == name);
+                self.environments.select(index);
+            }
+            ToggleEnvironmentEditor => self.show_environment_editor = !self.show_environment_editor,
+            AddEnvironment => self.environments.add(),
+            RemoveEnvironment => self.environments.remove_active(),
+            EnvironmentNameChanged(name) => self.environments.update_active(|e| e.name = name),
+            EnvironmentVarKeyChanged(i, key) => self
+                .environments
+                .update_active(|e| Self::update_list_item(&mut e.variables, i, |v| v.key = key)),
+            EnvironmentVarValueChanged(i, val) => self
+                .environments
+                .update_active(|e| Self::update_list_item(&mut e.variables, i, |v| v.value = val)),
+            EnvironmentVarToggled(i) => self.environments.update_active(|e| {
+                Self::update_list_item(&mut e.variables, i, |v| v.enabled = !v.enabled)
+            }),
+            AddEnvironmentVar => self
+                .environments
+                .update_active(|e| e.variables.push(KeyValue::empty())),
+            RemoveEnvironmentVar(i) => self.environments.update_active(|e| {
+                if i < e.variables.len() {
+                    e.variables.remove(i);
+                }
+            }),
+            SidebarTabChanged(tab) => self.sidebar_tab = tab,
+            CollectionNameChanged(name) => self.collection_name = name,
+            SelectCollectionNode(path) => self.select_collection_node(path),
+            SaveRequest => {
+                if let Some(path) = &self.collection_selection {
+                    self.collections.update_request(path, self.build_request());
+                }
+            }
+            SaveRequestAs => {
+                let folder = collections::target_folder(
+                    &self.collections,
+                    self.collection_selection.as_deref(),
+                );
+                let node =
+                    CollectionNode::request(self.collection_entry_name(), self.build_request());
+                if let Some(path) = self.collections.insert(&folder, node) {
+                    self.collection_selection = Some(path);
+                }
+            }
+            AddCollectionFolder => {
+                let folder = collections::target_folder(
+                    &self.collections,
+                    self.collection_selection.as_deref(),
+                );
+                let node = CollectionNode::folder(self.collection_entry_name());
+                if let Some(path) = self.collections.insert(&folder, node) {
+                    self.collection_selection = Some(path);
+                }
+            }
+            RenameCollectionNode => {
+                if let Some(path) = &self.collection_selection
+                    && !self.collection_name.trim().is_empty()
+                {
+                    self.collections
+                        .rename(path, self.collection_name.trim().to_string());
+                }
+            }
+            DuplicateCollectionNode => {
+                if let Some(path) = &self.collection_selection {
+                    self.collection_selection = self.collections.duplicate(path);
+                }
+            }
+            DeleteCollectionNode => {
+                if let Some(path) = self.collection_selection.take() {
+                    self.collections.remove(&path);
+                }
+            }
+            ImportPostman => {
+                return Task::perform(
+                    async {
+                        let Some(file) = file_dialog::open_file(
+                            "Import Postman collection",
+                            postman::FILE_FILTER,
+                        )
+                        .await?
+                        else {
+                            return Ok(None);
+                        };
+                        postman::parse(&file.text()?).map(Some)
+                    },
+                    PostmanImported,
+                );
+            }
+            PostmanImported(Ok(Some(imported))) => self.import_postman(imported),
+            PostmanImported(Ok(None)) => {}
+            PostmanImported(Err(e)) => self.error_message = Some(e),
+            ImportOpenApi => {
+                return Task::perform(
+                    async {
+                        let Some(file) =
+                            file_dialog::open_file("Import OpenAPI spec", openapi::FILE_FILTER)
+                                .await?
+                        else {
+                            return Ok(None);
+                        };
+                        openapi::parse(&file.text()?).map(Some)
+                    },
+                    OpenApiImported,
+                );
+            }
+            OpenApiImported(Ok(Some(imported))) => self.import_openapi(imported),
+            OpenApiImported(Ok(None)) => {}
+            OpenApiImported(Err(e)) => self.error_message = Some(e),
+            ExportCollections => {
+                let (name, nodes) = self.collections_to_export();
+                return Self::export_file(
+                    &format!("{}.postman_collection.json", name),
+                    postman::FILE_FILTER,
+                    postman::export(&name, &nodes),
+                );
+            }
+            ExportHistoryPostman => {
+                let nodes: Vec<CollectionNode> = self
+                    .history
+                    .items_to_export()
+                    .into_iter()
+                    .map(|item| {
+                        let name = format!("{} {}", item.request.method, item.request.url);
+                        CollectionNode::request(name, item.request)
+                    })
+                    .collect();
+                return Self::export_file(
+                    "history.postman_collection.json",
+                    postman::FILE_FILTER,
+                    postman::export("History", &nodes),
+                );
+            }
+            ImportHar => {
+                return Task::perform(
+                    async {
+                        let Some(file) =
+                            file_dialog::open_file("Import HAR file", har::FILE_FILTER).await?
+                        else {
+                            return Ok(None);
+                        };
+                        har::parse(&file.text()?).map(Some)
+                    },
+                    HarImported,
+                );
+            }
+            HarImported(Ok(Some(imported))) => self.import_har(imported),
+            HarImported(Ok(None)) => {}
+            HarImported(Err(e)) => self.error_message = Some(e),
+            ExportHistoryHar => {
+                return Self::export_file(
+                    "history.har",
+                    har::FILE_FILTER,
+                    har::export(&self.history.items_to_export()),
+                );
+            }
+            ExportFinished(Ok(Some(path))) => {
+                self.error_message = None;
+                self.notice_message = Some(format!("Exported to {}", path));
+            }
+            ExportFinished(Ok(None)) => {}
+            ExportFinished(Err(e)) => self.error_message = Some(e),
+            OpenHttpFile => {
+                return Task::perform(
+                    async {
+                        let Some(picked) =
+                            file_dialog::open_file("Open .http file", http_file::FILE_FILTER)
+                                .await?
+                        else {
+                            return Ok(None);
+                        };
+                        Ok(Some(OpenedHttpFile {
+                            file: HttpFile::parse(&picked.text()?),
+                            path: picked.path,
+                        }))
+                    },
+                    HttpFileOpened,
+                );
+            }
+            HttpFileOpened(Ok(Some(opened))) => {
+                self.notice_message = Some(format!(
+                    "Opened {} with {} requests",
+                    opened.file_name(),
+                    opened.file.len()
+                ));
+                self.error_message = None;
+                self.http_file = Some(opened);
+                self.http_file_selection = None;
+                self.sidebar_tab = SidebarTab::HttpFile;
+            }
+            HttpFileOpened(Ok(None)) => {}
+            HttpFileOpened(Err(e)) => self.error_message = Some(e),
+            SelectHttpFileEntry(i) => self.select_http_file_entry(i),
+            RunHttpFileEntry(i) => {
+                self.select_http_file_entry(i);
+                return self.submit_request();
+            }
+            SaveHttpFile => {
+                let request = self.build_request();
+                if let (Some(opened), Some(index)) = (&mut self.http_file, self.http_file_selection)
+                {
+                    opened.file.update_request(index, request);
+                    return Self::write_http_file(opened);
+                }
+            }
+            AddToHttpFile => {
+                let name = self.collection_entry_name();
+                let request = self.build_request();
+                if let Some(opened) = &mut self.http_file {
+                    opened.file.push_request(&name, request);
+                    self.http_file_selection = opened.file.len().checked_sub(1);
+                    return Self::write_http_file(opened);
+                }
+            }
+            CloseHttpFile => {
+                self.http_file = None;
+                self.http_file_selection = None;
+            }
+            HttpFileSaved(Ok(path)) => {
+                self.error_message = None;
+                self.notice_message = Some(match http_file::unsaved_auth(&self.auth) {
+                    Some(kind) => format!("Saved {} ({} is not stored in .http files)", path, kind),
+                    None => format!("Saved {}", path),
+                });
+            }
+            HttpFileSaved(Err(e)) => self.error_message = Some(e),
             LoadFromHistory(i) => self.load_from_history(i),
+            HistorySelectionToggled(i) => self.history.toggle_selected(i),
             ClearHistory => self.history.clear(),
             TabChanged(tab) => self.active_tab = tab,
             ResponseTabChanged(tab) => self.response_tab = tab,
+            SaveResponse => {
+                let Some(response) = &self.response else {
+                    return Task::none();
+                };
+                let extension =
+                    mime::extension(&mime::essence(response.content_type().unwrap_or_default()));
+                let default_name = format!("response.{}", extension);
+
+                // Resposta truncada: o body completo está no arquivo temporário, mantido
+                // pela cópia do guard enquanto é salvo
+                if let Some(spill_file) = response.spill_file.clone() {
+                    return Task::perform(
+                        async move {
+                            file_dialog::save_copy(
+                                "Save response",
+                                &default_name,
+                                ("All files", &["*"]),
+                                spill_file.path().to_path_buf(),
+                            )
+                            .await
+                        },
+                        ResponseSaved,
+                    );
+                }
+
+                let contents = response.raw_body().to_vec();
+                return Task::perform(
+                    async move {
+                        file_dialog::save_file(
+                            "Save response",
+                            &default_name,
+                            ("All files", &["*"]),
+                            contents,
+                        )
+                        .await
+                    },
+                    ResponseSaved,
+                );
+            }
+            ResponseSaved(Ok(Some(path))) => {
+                self.error_message = None;
+                self.notice_message = Some(format!("Response saved to {}", path));
+            }
+            ResponseSaved(Ok(None)) => {}
+            ResponseSaved(Err(e)) => self.error_message = Some(e),
         }
 
         Task::none()
     }
 
+    fn select_method(&mut self, method: HTTPMethod) {
+        // GraphQL via GET continua com a query, enviada nos query params
+        let keeps_body = method.allows_body()
+            || (self.force_body && method.can_force_body())
+            || (method == HTTPMethod::GET && self.body_type == BodyType::GraphQl);
+        self.method = method;
+
+        if !keeps_body {
+            self.body_type = BodyType::None;
+            self.body.clear();
+            self.body_content = text_editor::Content::new();
+            self.body_error = None;
+        }
+    }
+
+    fn validate_body(&mut self) {
+        self.body_error = match self.body_type {
+            BodyType::GraphQl => graphql::validate(&self.graphql).err(),
+            body_type => body_type.validate(&self.body).err(),
+        };
+    }
+
+    /// Substitui o body pela versão formatada ou minificada; se inválido, mostra o erro
+    fn reformat_body(&mut self, reformat: fn(BodyType, &str) -> Result<String, String>) {
+        match reformat(self.body_type, &self.body) {
+            Ok(body) => {
+                self.body_content = text_editor::Content::with_text(&body);
+                self.body = body;
+                self.body_error = None;
+            }
+            Err(e) => self.body_error = Some(e),
+        }
+    }
+
     /// Atualiza um item em uma lista se o índice for válido
     fn update_list_item<T>(list: &mut [T], index: usize, update_fn: impl FnOnce(&mut T)) {
         if let Some(item) = list.get_mut(index) {
@@ -128,44 +845,294 @@ impl App {
     }
 
     fn submit_request(&mut self) -> Task<Message> {
-        if let Err(e) = url_validator::validate_and_normalize(&self.url) {
+        let request = self.build_request();
+
+        let resolved = match self.environments.resolve(&request, &self.file_variables()) {
+            Ok(resolved) => resolved,
+            Err(unresolved) => {
+                self.error_message = Some(environment::unresolved_error(&unresolved));
+                return Task::none();
+            }
+        };
+
+        if let Err(e) = url_validator::validate_and_normalize(&resolved.url) {
             self.error_message = Some(e);
             return Task::none();
         }
 
+        if let Some(active) = self.active_request.take() {
+            active.handle.abort();
+        }
+        if let Some(session) = &mut self.sse {
+            session.stop();
+        }
+
+        if url_validator::is_websocket(&resolved.url) {
+            self.connect_websocket(request, resolved);
+            return Task::none();
+        }
+
         self.is_loading = true;
+        self.download = None;
         self.error_message = None;
+        self.notice_message = None;
+
+        let id = self.next_request_id;
+        self.next_request_id += 1;
 
-        let request = self.build_request();
         let client = self.http_client.clone();
+        let worker_request = resolved.clone();
 
-        Task::perform(
-            async move { client.send_request(request) },
-            Message::RequestCompleted,
-        )
+        let (task, handle) = Task::stream(iced::stream::channel(
+            16,
+            move |mut output: iced::futures::channel::mpsc::Sender<Message>| async move {
+                let mut progress = output.clone();
+                let result = client
+                    .send_with_progress(worker_request, move |download| {
+                        // Canal cheio: o aviso é descartado, o próximo traz o total atualizado
+                        let _ = progress.try_send(Message::ResponseProgress(id, download));
+                    })
+                    .await;
+                let _ = output.send(Message::RequestCompleted(id, result)).await;
+            },
+        ))
+        .abortable();
+
+        self.active_request = Some(ActiveRequest {
+            id,
+            request,
+            resolved,
+            handle,
+        });
+
+        task
+    }
+
+    /// Abre uma sessão WebSocket; a conexão fica com a subscription da sessão
+    fn connect_websocket(&mut self, request: HttpRequest, resolved: HttpRequest) {
+        self.set_response(None);
+        self.is_loading = true;
+        self.error_message = None;
+        self.notice_message = None;
+        self.ws_error = None;
+
+        let id = self.next_request_id;
+        self.next_request_id += 1;
+        self.websocket = Some(WsSession::new(id, request, resolved));
+    }
+
+    fn handle_websocket_event(&mut self, id: u64, event: WsEvent) {
+        let Some(session) = self.websocket.as_mut().filter(|s| s.id == id) else {
+            return;
+        };
+
+        if !matches!(event, WsEvent::Message(_)) {
+            self.is_loading = false;
+        }
+
+        if session.apply(event) {
+            self.history.add_websocket(
+                session.request.clone(),
+                session.resolved.clone(),
+                session.handshake.clone(),
+                session.messages.clone(),
+            );
+        }
+    }
+
+    /// Fecha a sessão WebSocket atual, registrando no histórico se a conexão estava aberta
+    fn end_websocket(&mut self) {
+        if let Some(mut session) = self.websocket.take()
+            && session.finish()
+        {
+            self.history.add_websocket(
+                session.request,
+                session.resolved,
+                session.handshake,
+                session.messages,
+            );
+        }
+    }
+
+    fn cancel_request(&mut self) {
+        self.is_loading = false;
+        self.download = None;
+
+        if let Some(session) = self
+            .websocket
+            .as_mut()
+            .filter(|s| s.running && s.handshake.is_none())
+        {
+            session.finish();
+            self.notice_message = Some("Connection cancelled".to_string());
+        }
+
+        if let Some(active) = self.active_request.take() {
+            active.handle.abort();
+            self.history.add_cancelled(active.request, active.resolved);
+            self.notice_message = Some("Request cancelled".to_string());
+        }
     }
 
     fn build_request(&self) -> HttpRequest {
         HttpRequest {
-            method: self.method,
+            method: self.method.clone(),
             url: self.url.clone(),
             headers: self.headers.clone(),
             query_params: self.query_params.clone(),
             body: self.body.clone(),
             body_type: self.body_type,
+            form_data: self.form_data.clone(),
+            form_urlencoded: self.form_urlencoded.clone(),
+            binary_file: self.binary_file.clone(),
+            graphql: self.graphql.clone(),
+            force_body: self.force_body,
             timeout_ms: self.timeout_ms.parse().unwrap_or(DEFAULT_TIMEOUT_MS),
+            response_limit_mb: self
+                .response_limit_mb
+                .parse()
+                .unwrap_or(DEFAULT_RESPONSE_LIMIT_MB),
+            auth: self.auth.clone(),
+            redirects: RedirectPolicy {
+                max_hops: self.max_redirects.parse().unwrap_or(DEFAULT_MAX_REDIRECTS),
+                ..self.redirects
+            },
+            send_cookies: self.send_cookies,
         }
     }
 
-    fn handle_response(&mut self, result: Result<HttpResponse, String>) {
+    /// Requisição usada na geração de código, já resolvida no ambiente ativo quando possível.
+    ///
+    /// OAuth 2.0 vira Bearer com o token em cache, se houver um válido.
+    fn code_request(&self, mut request: HttpRequest) -> HttpRequest {
+        if let Auth::OAuth2(config) = &request.auth
+            && let Some(token) = self
+                .http_client
+                .oauth2_tokens()
+                .get(&oauth2::cache_key(config))
+                .filter(|token| !token.is_expired(oauth2::now()))
+        {
+            request.auth = Auth::Bearer {
+                token: token.access_token,
+            };
+        }
+
+        request
+    }
+
+    /// Configuração OAuth 2.0 do editor com as variáveis do ambiente resolvidas
+    fn resolved_oauth2_config(&self) -> Result<Option<OAuth2Config>, String> {
+        let resolved = self
+            .environments
+            .resolve(&self.build_request(), &self.file_variables())
+            .map_err(|unresolved| environment::unresolved_error(&unresolved))?;
+
+        Ok(match resolved.auth {
+            Auth::OAuth2(config) => Some(*config),
+            _ => None,
+        })
+    }
+
+    fn oauth2_status(&self) -> ui::OAuth2Status {
+        ui::OAuth2Status {
+            current_key: self.oauth2_key.clone(),
+            tokens: self.http_client.oauth2_tokens().entries(),
+            fetching: self.oauth2_fetching,
+        }
+    }
+
+    fn fetch_oauth2_token(&mut self) -> Task<Message> {
+        let config = match self.resolved_oauth2_config() {
+            Ok(Some(config)) => config,
+            Ok(None) => return Task::none(),
+            Err(e) => {
+                self.error_message = Some(e);
+                return Task::none();
+            }
+        };
+
+        self.oauth2_fetching = true;
+        self.error_message = None;
+
+        let client = self.http_client.clone();
+        Task::perform(
+            async move { client.fetch_oauth2_token(config).await },
+            |result| Message::OAuth2TokenFetched(result.map(|_| ())),
+        )
+    }
+
+    /// Busca o schema do endpoint atual com a query de introspecção (POST, ou GET se for o
+    /// método escolhido), com os headers e a autenticação da requisição
+    fn fetch_graphql_schema(&mut self) -> Task<Message> {
+        let mut request = self.build_request();
+        if request.method != HTTPMethod::GET {
+            request.method = HTTPMethod::POST;
+        }
+        request.body_type = BodyType::GraphQl;
+        request.graphql = GraphQlBody {
+            query: graphql::INTROSPECTION_QUERY.to_string(),
+            ..GraphQlBody::default()
+        };
+
+        let resolved = match self.environments.resolve(&request, &self.file_variables()) {
+            Ok(resolved) => resolved,
+            Err(unresolved) => {
+                self.error_message = Some(environment::unresolved_error(&unresolved));
+                return Task::none();
+            }
+        };
+
+        self.graphql_fetching = true;
+        self.error_message = None;
+
+        let client = self.http_client.clone();
+        let url = self.url.clone();
+        Task::perform(
+            async move {
+                let response = client.send(resolved).await?;
+                GraphQlSchema::from_introspection(response.text())
+            },
+            move |result| Message::GraphQlSchemaFetched(url, result),
+        )
+    }
+
+    /// Erros GraphQL da resposta, quando a requisição no editor é GraphQL
+    fn graphql_errors(&self, response: &HttpResponse) -> Vec<String> {
+        match (&response.body, self.body_type) {
+            (Some(body), BodyType::GraphQl) => graphql::errors(body),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Variáveis `@var` do arquivo `.http`, quando a requisição no editor veio dele
+    fn file_variables(&self) -> Vec<KeyValue> {
+        match (&self.http_file, self.http_file_selection) {
+            (Some(opened), Some(_)) => opened.file.variables(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn handle_response(&mut self, id: u64, result: Result<HttpResponse, String>) {
+        let Some(active) = self.active_request.take_if(|active| active.id == id) else {
+            return;
+        };
+
         self.is_loading = false;
+        self.download = None;
 
         match result {
             Ok(response) => {
+                let event_stream = response.event_stream.clone();
+                let resolved = active.resolved.clone();
                 self.history
-                    .add_item(self.build_request(), response.clone());
-                self.response = Some(response);
+                    .add_item(active.request, active.resolved, response.clone());
+                self.set_response(Some(response));
                 self.error_message = None;
+
+                if let Some(stream) = event_stream {
+                    self.sse = Some(SseSession::new(id, resolved, stream));
+                    self.response_tab = ResponseTab::Events;
+                }
             }
             Err(error) => {
                 self.error_message = Some(error);
@@ -173,50 +1140,340 @@ impl App {
         }
     }
 
+    fn set_response(&mut self, response: Option<HttpResponse>) {
+        self.end_websocket();
+        self.sse = None;
+        // Abas que só existem para algumas respostas voltam para o body
+        let keep_tab = match self.response_tab {
+            ResponseTab::Body | ResponseTab::Headers => true,
+            ResponseTab::Events => false,
+            ResponseTab::Redirects => response
+                .as_ref()
+                .is_some_and(|response| !response.redirects.is_empty()),
+            ResponseTab::Cookies => response
+                .as_ref()
+                .is_some_and(|response| !response.cookies.is_empty()),
+        };
+        if !keep_tab {
+            self.response_tab = ResponseTab::Body;
+        }
+        self.response_image = response
+            .as_ref()
+            .filter(|response| response.is_image())
+            .map(|response| image::Handle::from_bytes(response.bytes.clone()));
+        self.response = response;
+    }
+
     fn load_from_history(&mut self, index: usize) {
-        if let Some(item) = self.history.get_item(index) {
-            self.method = item.request.method;
-            self.url = item.request.url.clone();
-            self.headers = item.request.headers.clone();
-            self.query_params = item.request.query_params.clone();
-            self.body = item.request.body.clone();
-            self.body_content = text_editor::Content::with_text(&item.request.body);
-            self.body_type = item.request.body_type;
-            self.timeout_ms = item.request.timeout_ms.to_string();
-            self.response = Some(item.response.clone());
+        if let Some(item) = self.history.get_item(index).cloned() {
+            self.load_request(&item.request);
+            self.set_response(item.response.clone());
+            if let Some(messages) = item.websocket {
+                self.websocket = Some(WsSession::from_history(
+                    item.request,
+                    item.response,
+                    messages,
+                ));
+            }
         }
     }
 
+    /// Carrega uma requisição nos campos do editor
+    fn load_request(&mut self, request: &HttpRequest) {
+        self.http_file_selection = None;
+        self.method = request.method.clone();
+        self.url = request.url.clone();
+        self.headers = request.headers.clone();
+        self.query_params = request.query_params.clone();
+        self.body = request.body.clone();
+        self.body_content = text_editor::Content::with_text(&request.body);
+        self.body_type = request.body_type;
+        self.graphql = request.graphql.clone();
+        self.graphql_query = text_editor::Content::with_text(&request.graphql.query);
+        self.graphql_variables = text_editor::Content::with_text(&request.graphql.variables);
+        self.validate_body();
+        self.form_data = request.form_data.clone();
+        self.form_urlencoded = request.form_urlencoded.clone();
+        self.binary_file = request.binary_file.clone();
+        self.force_body = request.force_body;
+        self.timeout_ms = request.timeout_ms.to_string();
+        self.response_limit_mb = request.response_limit_mb.to_string();
+        self.auth = request.auth.clone();
+        self.redirects = request.redirects;
+        self.max_redirects = request.redirects.max_hops.to_string();
+        self.send_cookies = request.send_cookies;
+    }
+
+    fn import_curl(&mut self) {
+        self.curl_import_offered = false;
+
+        match curl::parse(&self.url) {
+            Ok(imported) => {
+                self.load_request(&imported.request);
+                self.set_response(None);
+                self.error_message = None;
+                self.notice_message = if imported.warnings.is_empty() {
+                    Some("Imported curl command".to_string())
+                } else {
+                    Some(format!(
+                        "Imported curl command with warnings:\n{}",
+                        imported.warnings.join("\n")
+                    ))
+                };
+            }
+            Err(e) => self.error_message = Some(e),
+        }
+    }
+
+    fn import_postman(&mut self, mut imported: postman::PostmanImport) {
+        if let Some(environment) = &mut imported.environment {
+            environment.name = self.environments.insert(environment.clone());
+        }
+
+        self.notice_message = Some(imported.summary());
+        self.error_message = None;
+        self.collection_selection = self.collections.insert(&[], imported.collection);
+        self.sidebar_tab = SidebarTab::Collections;
+    }
+
+    fn import_openapi(&mut self, mut imported: openapi::OpenApiImport) {
+        // Cada servidor vira um ambiente; o primeiro fica ativo
+        let first_environment = self.environments.get_items().len();
+        for environment in &mut imported.environments {
+            environment.name = self.environments.insert(environment.clone());
+        }
+        if !imported.environments.is_empty() {
+            self.environments.select(Some(first_environment));
+        }
+
+        self.notice_message = Some(imported.summary());
+        self.error_message = None;
+        self.collection_selection = self.collections.insert(&[], imported.collection);
+        self.sidebar_tab = SidebarTab::Collections;
+    }
+
+    fn import_har(&mut self, imported: har::HarImport) {
+        let total = imported.items.len();
+        let kept = self.history.import(imported.items);
+
+        let mut lines = vec![format!("Imported {} HAR entries into history", kept)];
+        if kept < total {
+            lines.push(format!(
+                "{} older entries were dropped: history keeps the most recent requests",
+                total - kept
+            ));
+        }
+        lines.extend(imported.warnings);
+
+        self.notice_message = Some(lines.join("\n"));
+        self.error_message = None;
+        self.sidebar_tab = SidebarTab::History;
+    }
+
+    /// Pasta selecionada (ou todas as coleções) com o nome usado no arquivo exportado
+    fn collections_to_export(&self) -> (String, Vec<CollectionNode>) {
+        match self
+            .collection_selection
+            .as_ref()
+            .and_then(|path| self.collections.get(path))
+        {
+            Some(CollectionNode::Folder { name, children, .. }) => (name.clone(), children.clone()),
+            _ => (
+                "Collections".to_string(),
+                self.collections.get_items().to_vec(),
+            ),
+        }
+    }
+
+    /// Grava o conteúdo exportado em um arquivo escolhido pelo usuário
+    fn export_file(
+        default_name: &str,
+        filter: FileFilter,
+        contents: Result<String, String>,
+    ) -> Task<Message> {
+        let contents = match contents {
+            Ok(contents) => contents,
+            Err(e) => return Task::done(Message::ExportFinished(Err(e))),
+        };
+        let default_name = default_name.to_string();
+
+        Task::perform(
+            async move {
+                file_dialog::save_file("Export", &default_name, filter, contents.into_bytes()).await
+            },
+            Message::ExportFinished,
+        )
+    }
+
+    fn select_http_file_entry(&mut self, index: usize) {
+        let Some(entry) = self
+            .http_file
+            .as_ref()
+            .and_then(|opened| opened.file.entries().into_iter().nth(index))
+        else {
+            return;
+        };
+
+        self.load_request(&entry.request);
+        self.set_response(None);
+        self.http_file_selection = Some(index);
+    }
+
+    fn write_http_file(opened: &OpenedHttpFile) -> Task<Message> {
+        let path = opened.path.clone();
+        let contents = opened.file.to_string().into_bytes();
+
+        Task::perform(
+            file_dialog::write_file(path, contents),
+            Message::HttpFileSaved,
+        )
+    }
+
+    fn select_collection_node(&mut self, path: NodePath) {
+        match self.collections.get(&path).cloned() {
+            Some(CollectionNode::Request { name, request }) => {
+                self.load_request(&request);
+                self.set_response(None);
+                self.collection_name = name;
+            }
+            Some(CollectionNode::Folder { name, .. }) => {
+                if self.collection_selection.as_ref() == Some(&path) {
+                    self.collections.toggle_expanded(&path);
+                }
+                self.collection_name = name;
+            }
+            None => return,
+        }
+
+        self.collection_selection = Some(path);
+    }
+
+    /// Nome digitado no painel de coleções, ou um nome derivado da requisição
+    fn collection_entry_name(&self) -> String {
+        let name = self.collection_name.trim();
+
+        if !name.is_empty() {
+            name.to_string()
+        } else if !self.url.trim().is_empty() {
+            format!("{} {}", self.method, self.url.trim())
+        } else {
+            "New Request".to_string()
+        }
+    }
+
+    /// Mantém abertas a conexão do viewer de eventos SSE e a da sessão WebSocket
+    fn subscription(&self) -> Subscription<Message> {
+        let events = self.sse.as_ref().and_then(|session| {
+            session.connection(&self.http_client).map(|connection| {
+                Subscription::run_with(connection, sse::listen)
+                    .with(session.id)
+                    .map(|(id, update)| Message::SseUpdate(id, update))
+            })
+        });
+
+        let websocket = self.websocket.as_ref().and_then(|session| {
+            session.connection().map(|connection| {
+                Subscription::run_with(connection, websocket::connect)
+                    .with(session.id)
+                    .map(|(id, event)| Message::WebSocketEvent(id, event))
+            })
+        });
+
+        Subscription::batch(events.into_iter().chain(websocket))
+    }
+
     fn view(&self) -> Element<'_, Message> {
-        let history_sidebar = container(scrollable(ui::view_history(&self.history)))
-            .width(300)
-            .height(Length::Fill);
+        let sidebar_content = match self.sidebar_tab {
+            SidebarTab::History => ui::view_history(&self.history),
+            SidebarTab::Collections => ui::view_collections(
+                &self.collections,
+                self.collection_selection.as_deref(),
+                &self.collection_name,
+            ),
+            SidebarTab::HttpFile => {
+                ui::view_http_file(self.http_file.as_ref(), self.http_file_selection)
+            }
+            SidebarTab::Cookies => {
+                ui::view_cookie_jar(self.http_client.cookies().entries(), &self.cookie_draft)
+            }
+        };
+
+        let history_sidebar = container(column![
+            ui::view_sidebar_tabs(self.sidebar_tab),
+            scrollable(sidebar_content).height(Length::Fill),
+        ])
+        .width(300)
+        .height(Length::Fill);
+
+        let mut main_content = column![ui::view_header(
+            &self.method_options,
+            &self.method,
+            &self.url,
+            self.is_loading,
+            url_validator::is_websocket(&self.url)
+                .then(|| self.websocket.as_ref().is_some_and(WsSession::is_connected)),
+            &self.environments,
+        )]
+        .spacing(10)
+        .padding([16, 20]);
 
-        let main_content = column![
-            ui::view_header(self.method, &self.url, self.is_loading),
+        if self.curl_import_offered {
+            main_content = main_content.push(ui::view_curl_import_offer());
+        }
+
+        if self.show_environment_editor {
+            main_content = main_content.push(ui::view_environment_editor(&self.environments));
+        }
+
+        if !self.unresolved.is_empty() {
+            main_content = main_content.push(ui::view_unresolved_variables(&self.unresolved));
+        }
+
+        if let Some(code) = &self.generated_code {
+            main_content = main_content.push(ui::view_code_panel(self.code_target, code));
+        }
+
+        let main_content = main_content.extend([
             if let Some(error) = &self.error_message {
                 ui::view_error_message(error)
+            } else if let Some(notice) = &self.notice_message {
+                ui::view_notice_message(notice)
             } else {
                 ui::view_empty_error()
             },
-            ui::view_timeout_config(&self.timeout_ms),
+            ui::view_timeout_config(
+                &self.timeout_ms,
+                &self.response_limit_mb,
+                self.redirects,
+                &self.max_redirects,
+                self.send_cookies,
+            ),
             container(
                 column![
-                    ui::view_request_tabs(self.active_tab),
+                    ui::view_request_tabs(self.active_tab, url_validator::is_websocket(&self.url)),
                     self.view_active_tab_content(),
                 ]
                 .spacing(0)
-                .width(Fill)
+                .width(Fill),
             )
-            .style(styles::request_container),
-            if let Some(response) = &self.response {
-                ui::view_response(response, self.response_tab)
+            .style(styles::request_container)
+            .into(),
+            if let Some(session) = &self.websocket {
+                ui::view_websocket_session(session)
+            } else if let Some(download) = self.download.as_ref().filter(|_| self.is_loading) {
+                ui::view_download_progress(download)
+            } else if let Some(response) = &self.response {
+                ui::view_response(
+                    response,
+                    self.response_tab,
+                    self.response_image.as_ref(),
+                    self.graphql_errors(response),
+                    self.sse.as_ref(),
+                )
             } else {
                 ui::view_no_response()
             },
-        ]
-        .spacing(10)
-        .padding([16, 20]);
+        ]);
 
         let layout = row![
             history_sidebar,
@@ -234,9 +1491,34 @@ impl App {
         match self.active_tab {
             RequestTab::QueryParams => self.view_query_params(),
             RequestTab::Headers => self.view_headers(),
-            RequestTab::Body => {
-                ui::view_body_editor(self.method, self.body_type, &self.body_content)
+            RequestTab::Auth => ui::view_auth_editor(&self.auth, self.oauth2_status()),
+            RequestTab::Body if url_validator::is_websocket(&self.url) => {
+                ui::view_websocket_composer(
+                    self.ws_format,
+                    &self.ws_message,
+                    self.ws_error.as_deref(),
+                    self.websocket.as_ref().is_some_and(WsSession::is_connected),
+                )
             }
+            RequestTab::Body => ui::view_body_editor(
+                &self.method,
+                self.force_body,
+                self.body_type,
+                &self.body_content,
+                self.body_error.as_deref(),
+                &self.form_data,
+                &self.form_urlencoded,
+                &self.binary_file,
+                (self.body_type == BodyType::GraphQl).then(|| ui::GraphQlEditor {
+                    query: &self.graphql_query,
+                    variables: &self.graphql_variables,
+                    operations: graphql::operations(&self.graphql.query),
+                    operation_name: &self.graphql.operation_name,
+                    schema: self.graphql_schemas.get(&self.url),
+                    schema_filter: &self.graphql_schema_filter,
+                    fetching_schema: self.graphql_fetching,
+                }),
+            ),
         }
     }
 
@@ -255,7 +1537,7 @@ impl App {
     }
 
     fn view_headers(&self) -> Element<'_, Message> {
-        ui::view_key_value_list(
+        let headers = ui::view_key_value_list(
             &self.headers,
             "Header-Name",
             "value",
@@ -265,12 +1547,26 @@ impl App {
             Message::RemoveHeader,
             Message::AddHeader,
             "+ Add Header",
-        )
+        );
+
+        match &self.implicit_content_type {
+            Some(content_type) => column![
+                ui::view_implicit_header(
+                    "Content-Type",
+                    content_type,
+                    Message::OverrideContentType
+                ),
+                headers,
+            ]
+            .into(),
+            None => headers,
+        }
     }
 }
 
 fn main() -> iced::Result {
     iced::application(App::default, App::update, App::view)
+        .subscription(App::subscription)
         .theme(|_state: &App| Theme::Oxocarbon)
         .run()
 }
@@ -0,0 +1,19 @@
+const axios = require("axios");
+
+axios
+  .request({
+    method: "post",
+    url: "https://api.example.com/users?q=a+b%26c",
+    headers: {
+      "Content-Type": "application/json",
+      "X-Note": "it's \"quoted\"",
+    },
+    data: "{\"name\": \"O'Brien\",\n \"city\": \"São Paulo\"}",
+    timeout: 30000,
+    transformResponse: (data) => data,
+  })
+  .then((response) => {
+    console.log(response.status);
+    console.log(response.data);
+  })
+  .catch(console.error);
@@ -0,0 +1,5 @@
+curl -X POST 'https://api.example.com/users?q=a+b%26c' \
+  -H 'Content-Type: application/json' \
+  -H 'X-Note: it'\''s "quoted"' \
+  --data-raw '{"name": "O'\''Brien",
+ "city": "São Paulo"}'
@@ -0,0 +1,13 @@
+fetch("https://api.example.com/users?q=a+b%26c", {
+  method: "POST",
+  headers: {
+    "Content-Type": "application/json",
+    "X-Note": "it's \"quoted\"",
+  },
+  body: "{\"name\": \"O'Brien\",\n \"city\": \"São Paulo\"}",
+})
+  .then(async (response) => {
+    console.log(response.status);
+    console.log(await response.text());
+  })
+  .catch(console.error);
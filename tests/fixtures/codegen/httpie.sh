@@ -0,0 +1,5 @@
+http --ignore-stdin POST 'https://api.example.com/users?q=a+b%26c' \
+  'Content-Type:application/json' \
+  'X-Note:it'\''s "quoted"' \
+  --raw '{"name": "O'\''Brien",
+ "city": "São Paulo"}'
@@ -0,0 +1,14 @@
+$headers = @{
+    'X-Note' = 'it''s "quoted"'
+}
+$body = '{"name": "O''Brien",
+ "city": "São Paulo"}'
+
+$response = Invoke-WebRequest -Uri 'https://api.example.com/users?q=a+b%26c' `
+  -Method 'POST' `
+  -Headers $headers `
+  -ContentType 'application/json' `
+  -Body $body `
+  -TimeoutSec 30
+$response.StatusCode
+$response.Content
@@ -0,0 +1,34 @@
+package main
+
+import (
+	"fmt"
+	"io"
+	"net/http"
+	"strings"
+	"time"
+)
+
+func main() {
+	body := strings.NewReader("{\"name\": \"O'Brien\",\n \"city\": \"São Paulo\"}")
+	req, err := http.NewRequest("POST", "https://api.example.com/users?q=a+b%26c", body)
+	if err != nil {
+		panic(err)
+	}
+	req.Header.Add("Content-Type", "application/json")
+	req.Header.Add("X-Note", "it's \"quoted\"")
+
+	client := &http.Client{Timeout: 30000 * time.Millisecond}
+	res, err := client.Do(req)
+	if err != nil {
+		panic(err)
+	}
+	defer res.Body.Close()
+
+	data, err := io.ReadAll(res.Body)
+	if err != nil {
+		panic(err)
+	}
+
+	fmt.Println(res.Status)
+	fmt.Println(string(data))
+}
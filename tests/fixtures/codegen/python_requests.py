@@ -0,0 +1,13 @@
+import requests
+
+url = "https://api.example.com/users?q=a+b%26c"
+headers = {
+    "Content-Type": "application/json",
+    "X-Note": "it's \"quoted\"",
+}
+data = "{\"name\": \"O'Brien\",\n \"city\": \"São Paulo\"}"
+
+response = requests.request("POST", url, headers=headers, data=data.encode("utf-8"), timeout=30)
+
+print(response.status_code)
+print(response.text)
@@ -0,0 +1,8 @@
+wget --quiet \
+  --method=POST \
+  --header='Content-Type: application/json' \
+  --header='X-Note: it'\''s "quoted"' \
+  --body-data='{"name": "O'\''Brien",
+ "city": "São Paulo"}' \
+  --output-document - \
+  'https://api.example.com/users?q=a+b%26c'